@@ -1,4 +1,4 @@
-use crate::Launchpad;
+use crate::{Launchpad, MainOutput};
 
 /// The primary entry point for launching a Strut application.
 ///
@@ -14,6 +14,10 @@ impl App {
     /// default [`Launchpad`], runs the startup process, executes the provided
     /// `async_main` future, and handles graceful shutdown.
     ///
+    /// The `async_main` future may also output a [`Result`] or an exit code
+    /// (see [`MainOutput`]): if it fails, the process exits with a non-zero
+    /// code after the graceful shutdown.
+    ///
     /// For customization options, see [`App::launchpad`].
     ///
     /// ## Example
@@ -31,7 +35,8 @@ impl App {
     /// ```
     pub fn boot<Main>(async_main: Main)
     where
-        Main: Future<Output = ()>,
+        Main: Future,
+        Main::Output: MainOutput,
     {
         Self::launchpad(async_main).boot()
     }
@@ -69,7 +74,8 @@ impl App {
     /// ```
     pub fn launchpad<Main>(async_main: Main) -> Launchpad<Main>
     where
-        Main: Future<Output = ()>,
+        Main: Future,
+        Main::Output: MainOutput,
     {
         Launchpad::new(async_main)
     }
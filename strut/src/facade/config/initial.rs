@@ -1,10 +1,16 @@
 use crate::facade::config::initial::statics::StaticInitialConfig;
-use crate::AppConfigError;
-use config::{ConfigBuilder, ConfigError};
+use crate::facade::config::registry::ConfigRegistry;
+use crate::facade::config::unknown::UnknownKeys;
+use crate::facade::config::validation::{ConfigValidation, ConfigValidationReport};
+use crate::{AppConfigError, UnknownKeyPolicy};
+use config::{ConfigBuilder, ConfigError, Map, Value};
+use serde::de::value::{Error as ValueError, StrDeserializer};
 use serde::de::{DeserializeOwned, MapAccess, Visitor};
 use serde::{Deserialize, Deserializer};
 use std::fmt::Formatter;
 use std::sync::Arc;
+use strut_config::{INHERITS_KEY, PROFILES_KEY};
+use strut_deserialize::{ConfigSchema, Schema};
 use strut_factory::impl_deserialize_field;
 
 #[cfg(feature = "config-async")]
@@ -37,7 +43,8 @@ pub mod statics;
 /// ```
 ///
 /// For accessing custom configuration sections not managed by Strut, use
-/// [`AppConfig::section`].
+/// [`AppConfig::section`], or [register](AppConfig::register) them to have them
+/// deserialized once, along with the rest of the configuration.
 ///
 /// ## Initial vs. live configuration
 ///
@@ -64,6 +71,8 @@ pub mod statics;
 pub struct AppConfig {
     name: Arc<str>,
 
+    runtime: crate::RuntimeConfig,
+
     #[cfg(feature = "tracing")]
     tracing: strut_tracing::TracingConfig,
 
@@ -79,6 +88,9 @@ pub struct AppConfig {
 
     #[cfg(feature = "rabbitmq")]
     rabbitmq: strut_rabbitmq::RabbitMqConfig,
+
+    #[cfg(feature = "health")]
+    health: crate::HealthConfig,
 }
 
 /// Methods that use [`AppConfig`] as a facade.
@@ -156,6 +168,134 @@ impl AppConfig {
                 _ => Err(AppConfigError::from(error)),
             })
     }
+
+    /// Registers a custom section of the configuration by its `key`, to be
+    /// deserialized as the type `T`.
+    ///
+    /// Unlike [`section`], which deserializes the section on every call, a
+    /// registered section is deserialized only once, when the **initial**
+    /// configuration is seeded, alongside the sections known to Strut (such as
+    /// `tracing` or `database`). The result is then available as a static
+    /// reference via [`registered`]. If the key is not found in the
+    /// configuration, `T::default()` is used.
+    ///
+    /// Registered sections also take part in the eager validation at boot,
+    /// and (with the `config-live` feature) are deserialized on every
+    /// [refresh](crate::AppLiveConfig::refresh) of the live configuration.
+    ///
+    /// Registering the same key as the same type repeatedly has no effect. The
+    /// [`Launchpad`](crate::Launchpad) offers the same functionality via
+    /// [`with_config_section`](crate::Launchpad::with_config_section).
+    ///
+    /// ```
+    /// use serde::Deserialize;
+    /// use strut::{App, AppConfig};
+    ///
+    /// #[derive(Debug, Default, Deserialize)]
+    /// struct BillingConfig {
+    ///     #[serde(default)]
+    ///     currency: String,
+    /// }
+    ///
+    /// fn main() {
+    ///     AppConfig::register::<BillingConfig>("billing");
+    ///
+    ///     App::boot(async_main());
+    /// }
+    ///
+    /// async fn async_main() {
+    ///     let billing: &'static BillingConfig = AppConfig::registered("billing");
+    ///
+    ///     assert_eq!(billing.currency, "");
+    /// }
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if called after the initial configuration has been seeded.
+    ///
+    /// [`section`]: AppConfig::section
+    /// [`registered`]: AppConfig::registered
+    pub fn register<T>(key: impl AsRef<str>)
+    where
+        T: DeserializeOwned + Default + Send + Sync + 'static,
+    {
+        let key = key.as_ref();
+
+        if StaticInitialConfig::is_seeded() {
+            panic!(
+                "the configuration section '{}' should be registered before the initial configuration is seeded",
+                key,
+            );
+        }
+
+        ConfigRegistry::register::<T>(key);
+    }
+
+    /// Returns a static reference to a [registered](AppConfig::register)
+    /// section of the **initial** configuration, deserialized as the type `T`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the section with the given key has not been registered as
+    /// the type `T`, or if called before the configuration has been
+    /// initialized.
+    pub fn registered<T>(key: impl AsRef<str>) -> &'static T
+    where
+        T: Send + Sync + 'static,
+    {
+        let key = key.as_ref();
+
+        ConfigRegistry::lookup::<T>(StaticInitialConfig::sections(), key)
+            .and_then(|section| section.downcast_ref::<T>())
+            .unwrap_or_else(|| panic!("{}", ConfigRegistry::unregistered_message::<T>(key)))
+    }
+
+    /// Chooses how to treat the keys of the **initial** configuration that are
+    /// not recognized by any of its sections (see [`UnknownKeyPolicy`]).
+    ///
+    /// The unknown keys are reported during the eager validation at boot, with
+    /// the full key path, the config file that set the key, and the closest
+    /// known key as a suggestion (e.g., `did you mean `prefetch_count`?`).
+    ///
+    /// The [`Launchpad`](crate::Launchpad) offers the same functionality via
+    /// [`with_unknown_keys`](crate::Launchpad::with_unknown_keys).
+    ///
+    /// # Panics
+    ///
+    /// Panics if called after the initial configuration has been seeded.
+    pub fn set_unknown_keys(policy: UnknownKeyPolicy) {
+        if StaticInitialConfig::is_seeded() {
+            panic!(
+                "the policy for unknown keys should be chosen before the initial configuration is seeded"
+            );
+        }
+
+        UnknownKeys::set_policy(policy);
+    }
+
+    /// Chooses how to treat the unknown keys in the given top-level section of
+    /// the **initial** configuration, overriding the policy chosen via
+    /// [`set_unknown_keys`](AppConfig::set_unknown_keys) for that section.
+    ///
+    /// The [`Launchpad`](crate::Launchpad) offers the same functionality via
+    /// [`with_unknown_keys_in`](crate::Launchpad::with_unknown_keys_in).
+    ///
+    /// # Panics
+    ///
+    /// Panics if called after the initial configuration has been seeded.
+    pub fn set_unknown_keys_in(section: impl AsRef<str>, policy: UnknownKeyPolicy) {
+        let section = section.as_ref();
+
+        if StaticInitialConfig::is_seeded() {
+            panic!(
+                "the policy for unknown keys in the configuration section '{}' should be chosen before the initial configuration is seeded",
+                section,
+            );
+        }
+
+        UnknownKeys::set_section_policy(section, policy);
+    }
 }
 
 #[cfg(not(feature = "config-async"))]
@@ -167,13 +307,35 @@ impl AppConfig {
     ///
     /// # Panics
     ///
-    /// Panics if called more than once.
+    /// Panics if called more than once, or if the configuration is invalid.
+    /// For a less panicky alternative, see [`try_seed`](AppConfig::try_seed).
     pub fn seed(config_builder: ConfigBuilder<DefaultState>) {
+        Self::try_seed(config_builder).unwrap_or_else(|report| panic!("{}", report));
+    }
+
+    /// Validates and seeds the **initial configuration** from a synchronous
+    /// builder.
+    ///
+    /// Before seeding, every section known to [`AppConfig`] and every custom
+    /// section [registered](AppConfig::register) by the
+    /// application is deserialized eagerly. If any of them fails, nothing is
+    /// seeded, and all errors are returned at once.
+    ///
+    /// # Panics
+    ///
+    /// Panics if called more than once.
+    pub fn try_seed(
+        config_builder: ConfigBuilder<DefaultState>,
+    ) -> Result<(), ConfigValidationReport> {
         let proxy_config = config_builder
             .build()
-            .expect("it should be possible to synchronously build the initial proxy configuration");
+            .map_err(ConfigValidationReport::from_error)?;
+
+        ConfigValidation::validate(&proxy_config)?;
 
         StaticInitialConfig::seed(proxy_config);
+
+        Ok(())
     }
 }
 
@@ -186,13 +348,38 @@ impl AppConfig {
     ///
     /// # Panics
     ///
-    /// Panics if called more than once.
+    /// Panics if called more than once, or if the configuration is invalid.
+    /// For a less panicky alternative, see [`try_seed`](AppConfig::try_seed).
     pub async fn seed(config_builder: ConfigBuilder<AsyncState>) {
-        let proxy_config = config_builder.build().await.expect(
-            "it should be possible to asynchronously build the initial proxy configuration",
-        );
+        Self::try_seed(config_builder)
+            .await
+            .unwrap_or_else(|report| panic!("{}", report));
+    }
+
+    /// Validates and seeds the **initial configuration** from an asynchronous
+    /// builder.
+    ///
+    /// Before seeding, every section known to [`AppConfig`] and every custom
+    /// section [registered](AppConfig::register) by the
+    /// application is deserialized eagerly. If any of them fails, nothing is
+    /// seeded, and all errors are returned at once.
+    ///
+    /// # Panics
+    ///
+    /// Panics if called more than once.
+    pub async fn try_seed(
+        config_builder: ConfigBuilder<AsyncState>,
+    ) -> Result<(), ConfigValidationReport> {
+        let proxy_config = config_builder
+            .build()
+            .await
+            .map_err(ConfigValidationReport::from_error)?;
+
+        ConfigValidation::validate(&proxy_config)?;
 
         StaticInitialConfig::seed(proxy_config);
+
+        Ok(())
     }
 }
 
@@ -202,6 +389,11 @@ impl AppConfig {
         &self.name
     }
 
+    /// Returns the configuration for the Tokio runtime.
+    pub fn runtime(&self) -> &crate::RuntimeConfig {
+        &self.runtime
+    }
+
     /// Returns the configuration for the `tracing` (logging) component.
     #[cfg(feature = "tracing")]
     pub fn tracing(&self) -> &strut_tracing::TracingConfig {
@@ -229,6 +421,54 @@ impl AppConfig {
     pub fn rabbitmq(&self) -> &strut_rabbitmq::RabbitMqConfig {
         &self.rabbitmq
     }
+
+    /// Returns the configuration for the built-in health subsystem.
+    #[cfg(feature = "health")]
+    pub fn health(&self) -> &crate::HealthConfig {
+        &self.health
+    }
+}
+
+/// Methods that describe the shape of [`AppConfig`].
+impl AppConfig {
+    /// Returns the [JSON Schema](https://json-schema.org) of the application
+    /// configuration, as a standalone document.
+    ///
+    /// The schema covers every section known to Strut with the enabled crate
+    /// features, including the key aliases, the string choices, and the
+    /// human-readable durations accepted by the configuration. Point an IDE at
+    /// the schema to validate and auto-complete the config files. The same
+    /// document is printed by the `print-schema` subcommand of the
+    /// [command-line interface](crate::Launchpad::with_cli).
+    ///
+    /// Custom sections are not described by the schema, but are allowed.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use strut::AppConfig;
+    ///
+    /// let schema = AppConfig::json_schema();
+    ///
+    /// assert!(schema["properties"]["runtime"].is_object());
+    /// ```
+    pub fn json_schema() -> serde_json::Value {
+        Self::config_schema()
+            .with_title("Strut application configuration")
+            .into_document()
+    }
+
+    /// Returns the [`Schema`] that the environment variables are mapped
+    /// against, when [enabled](crate::Launchpad::with_env_schema): the schema
+    /// of the sections known to Strut, where every
+    /// [registered](AppConfig::register) custom section accepts any value.
+    pub(crate) fn env_schema() -> Schema {
+        ConfigRegistry::keys()
+            .iter()
+            .fold(Self::config_schema(), |schema, key| {
+                schema.with_field(&[key], Schema::any())
+            })
+    }
 }
 
 impl AppConfig {
@@ -261,6 +501,7 @@ const _: () = {
             A: MapAccess<'de>,
         {
             let mut name: Option<String> = None;
+            let mut runtime = None;
 
             #[cfg(feature = "tracing")]
             let mut tracing = None;
@@ -278,9 +519,13 @@ const _: () = {
             #[cfg(feature = "rabbitmq")]
             let mut rabbitmq = None;
 
+            #[cfg(feature = "health")]
+            let mut health = None;
+
             while let Some(key) = map.next_key()? {
                 match key {
                     AppConfigField::name => key.poll(&mut map, &mut name)?,
+                    AppConfigField::runtime => key.poll(&mut map, &mut runtime)?,
 
                     #[cfg(feature = "tracing")]
                     AppConfigField::tracing => key.poll(&mut map, &mut tracing)?,
@@ -310,6 +555,11 @@ const _: () = {
                     #[cfg(not(feature = "rabbitmq"))]
                     AppConfigField::rabbitmq => map.next_value()?,
 
+                    #[cfg(feature = "health")]
+                    AppConfigField::health => key.poll(&mut map, &mut health)?,
+                    #[cfg(not(feature = "health"))]
+                    AppConfigField::health => map.next_value()?,
+
                     AppConfigField::__ignore => map.next_value()?,
                 };
             }
@@ -319,6 +569,8 @@ const _: () = {
             Ok(AppConfig {
                 name,
 
+                runtime: runtime.unwrap_or_default(),
+
                 #[cfg(feature = "tracing")]
                 tracing: tracing.unwrap_or_default(),
 
@@ -334,17 +586,152 @@ const _: () = {
 
                 #[cfg(feature = "rabbitmq")]
                 rabbitmq: rabbitmq.unwrap_or_default(),
+
+                #[cfg(feature = "health")]
+                health: health.unwrap_or_default(),
             })
         }
     }
 
+    impl AppConfig {
+        /// Deserializes every known section of the given top-level table
+        /// individually, returning the errors along with the section keys, in
+        /// the order of keys.
+        pub(crate) fn validate_sections(table: Map<String, Value>) -> Vec<(String, ConfigError)> {
+            let mut sections = table.into_iter().collect::<Vec<_>>();
+            sections.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+            sections
+                .into_iter()
+                .filter_map(|(key, value)| {
+                    let field =
+                        AppConfigField::deserialize(StrDeserializer::<ValueError>::new(&key));
+                    let result = match field.ok()? {
+                        AppConfigField::name => value.try_deserialize::<String>().map(drop),
+                        AppConfigField::runtime => {
+                            value.try_deserialize::<crate::RuntimeConfig>().map(drop)
+                        }
+
+                        #[cfg(feature = "tracing")]
+                        AppConfigField::tracing => value
+                            .try_deserialize::<strut_tracing::TracingConfig>()
+                            .map(drop),
+
+                        #[cfg(feature = "sentry")]
+                        AppConfigField::sentry => value
+                            .try_deserialize::<strut_sentry::SentryConfig>()
+                            .map(drop),
+
+                        #[cfg(any(
+                            feature = "database-mysql",
+                            feature = "database-postgres",
+                            feature = "database-sqlite",
+                        ))]
+                        AppConfigField::database => value
+                            .try_deserialize::<strut_database::DatabaseConfig>()
+                            .map(drop),
+
+                        #[cfg(feature = "rabbitmq")]
+                        AppConfigField::rabbitmq => value
+                            .try_deserialize::<strut_rabbitmq::RabbitMqConfig>()
+                            .map(drop),
+
+                        #[cfg(feature = "health")]
+                        AppConfigField::health => {
+                            value.try_deserialize::<crate::HealthConfig>().map(drop)
+                        }
+
+                        _ => Ok(()),
+                    };
+
+                    result.err().map(|error| (key, error))
+                })
+                .collect()
+        }
+
+        /// Lists the keys (along with the aliases) of every section known to
+        /// [`AppConfig`], including the sections of the disabled features.
+        pub(crate) fn section_keys() -> impl Iterator<Item = &'static str> {
+            [
+                AppConfigField::name,
+                AppConfigField::runtime,
+                AppConfigField::tracing,
+                AppConfigField::sentry,
+                AppConfigField::rabbitmq,
+                AppConfigField::database,
+                AppConfigField::health,
+            ]
+            .into_iter()
+            .flat_map(|field| field.keys().iter().copied())
+        }
+    }
+
     impl_deserialize_field!(
         AppConfigField,
         strut_deserialize::Slug::eq_as_slugs,
         name,
+        runtime,
         tracing,
         sentry,
         rabbitmq,
-        database
+        database,
+        health
     );
+
+    impl ConfigSchema for AppConfig {
+        fn config_schema() -> Schema {
+            let schema = Schema::object()
+                .with_description("The application configuration.")
+                .with_field(
+                    AppConfigField::name.keys(),
+                    String::config_schema().with_default(AppConfig::default_name()),
+                )
+                .with_field(
+                    AppConfigField::runtime.keys(),
+                    crate::RuntimeConfig::config_schema(),
+                );
+
+            #[cfg(feature = "tracing")]
+            let schema = schema.with_field(
+                AppConfigField::tracing.keys(),
+                strut_tracing::TracingConfig::config_schema(),
+            );
+
+            #[cfg(feature = "sentry")]
+            let schema = schema.with_field(
+                AppConfigField::sentry.keys(),
+                strut_sentry::SentryConfig::config_schema(),
+            );
+
+            #[cfg(any(
+                feature = "database-mysql",
+                feature = "database-postgres",
+                feature = "database-sqlite",
+            ))]
+            let schema = schema.with_field(
+                AppConfigField::database.keys(),
+                strut_database::DatabaseConfig::config_schema(),
+            );
+
+            #[cfg(feature = "rabbitmq")]
+            let schema = schema.with_field(
+                AppConfigField::rabbitmq.keys(),
+                strut_rabbitmq::RabbitMqConfig::config_schema(),
+            );
+
+            #[cfg(feature = "health")]
+            let schema = schema.with_field(
+                AppConfigField::health.keys(),
+                crate::HealthConfig::config_schema(),
+            );
+
+            // The inheritance between profiles is declared in the config files
+            // as well, although it is consumed while scanning for them
+            schema.with_field(
+                &[PROFILES_KEY],
+                Schema::map(Schema::object().with_field(&[INHERITS_KEY], String::config_schema()))
+                    .with_description("The inheritance between the application profiles."),
+            )
+        }
+    }
 };
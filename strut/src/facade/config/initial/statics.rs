@@ -1,4 +1,5 @@
 use crate::AppConfig;
+use crate::facade::config::registry::{ConfigRegistry, SectionMap};
 use config::Config as ProxyConfig;
 use std::sync::OnceLock;
 
@@ -8,6 +9,9 @@ static INITIAL_APP_CONFIG: OnceLock<AppConfig> = OnceLock::new();
 /// The statically stored initial [`ProxyConfig`].
 static INITIAL_PROXY_CONFIG: OnceLock<ProxyConfig> = OnceLock::new();
 
+/// The statically stored initial registered custom sections.
+static INITIAL_SECTIONS: OnceLock<SectionMap> = OnceLock::new();
+
 /// An internal facade for working with the statically stored **initial**,
 /// **immutable** application configuration: resolved no more than once,
 /// eagerly, during the application start-up.
@@ -31,8 +35,20 @@ impl StaticInitialConfig {
             .expect("the initial proxy configuration should not be accessed before initialization")
     }
 
-    /// Eagerly deserializes [`AppConfig`] from the given [`ProxyConfig`] and
-    /// stores both statically.
+    /// Returns the statically stored initial registered custom sections.
+    pub(crate) fn sections() -> &'static SectionMap {
+        INITIAL_SECTIONS.get().expect(
+            "the initial configuration sections should not be accessed before initialization",
+        )
+    }
+
+    /// Reports whether the initial configuration has already been seeded.
+    pub(crate) fn is_seeded() -> bool {
+        INITIAL_APP_CONFIG.get().is_some()
+    }
+
+    /// Eagerly deserializes [`AppConfig`] and the registered custom sections
+    /// from the given [`ProxyConfig`] and stores all of them statically.
     pub(crate) fn seed(proxy_config: ProxyConfig) {
         // Clone the given proxy config (we need two copies) and deserialize the clone into app config
         let app_config = proxy_config
@@ -40,6 +56,19 @@ impl StaticInitialConfig {
             .try_deserialize::<AppConfig>()
             .expect("it should be possible to deserialize the initial application configuration");
 
+        // Deserialize the registered custom sections
+        let sections = ConfigRegistry::load(&proxy_config).unwrap_or_else(|errors| {
+            panic!(
+                "it should be possible to deserialize the initial configuration section '{}': {}",
+                errors[0].0, errors[0].1,
+            )
+        });
+
+        // Store the deserialized sections
+        INITIAL_SECTIONS
+            .set(sections)
+            .expect("the initial configuration sections should not be set more than once");
+
         // Store the given proxy config
         INITIAL_PROXY_CONFIG
             .set(proxy_config)
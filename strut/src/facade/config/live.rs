@@ -1,7 +1,12 @@
-use crate::facade::config::live::statics::StaticLiveConfig;
+use crate::facade::config::live::statics::{LiveSnapshot, StaticLiveConfig, Subscriber};
+use crate::facade::config::registry::{ConfigRegistry, SectionMap};
 use crate::{AppConfig, AppConfigError};
-use config::ConfigBuilder;
+use config::{Config as ProxyConfig, ConfigBuilder, ConfigError, Value};
 use serde::de::DeserializeOwned;
+use std::any::TypeId;
+use std::sync::Arc;
+use strut_config::ConfigDiff;
+use tokio::sync::watch::{self, Receiver};
 
 #[cfg(feature = "config-async")]
 use config::builder::AsyncState;
@@ -10,6 +15,9 @@ use config::builder::DefaultState;
 
 pub mod statics;
 
+#[cfg(feature = "config-watch")]
+pub mod watcher;
+
 /// Provides access to the application's **live configuration**.
 ///
 /// This facade complements [`AppConfig`] by offering methods to interact with a
@@ -27,10 +35,11 @@ pub struct AppLiveConfig;
 
 #[cfg(not(feature = "config-async"))]
 impl AppLiveConfig {
-    /// Deserializes and returns the current live `AppConfig`.
+    /// Returns the current live `AppConfig`.
     ///
-    /// This method deserializes the configuration on every call from an internal,
-    /// cached representation. This cache is lazily populated on the first access.
+    /// This method returns a clone of the configuration deserialized from an
+    /// internal, cached representation. This cache is lazily populated on the
+    /// first access.
     ///
     /// To update the configuration from its sources (e.g., files), you must first
     /// call [`refresh`]. Otherwise, this method will return the same cached values.
@@ -62,9 +71,10 @@ impl AppLiveConfig {
     pub fn try_get() -> Result<AppConfig, AppConfigError> {
         StaticLiveConfig::get_config_lock()
             .read()
-            .clone()?
-            .try_deserialize()
-            .map_err(AppConfigError::from)
+            .as_ref()
+            .map_err(AppConfigError::clone)?
+            .app_config
+            .clone()
     }
 
     /// Deserializes a section of the live configuration by its `key`.
@@ -78,7 +88,8 @@ impl AppLiveConfig {
     /// # Panics
     ///
     /// Panics on first access if the configuration fails to load, or if the
-    /// specific section fails to parse. For a non-panicking alternative, see
+    /// specific section fails to parse. Other sections failing to parse do
+    /// not affect this one. For a non-panicking alternative, see
     /// [`try_section`].
     ///
     /// [`get`]: AppLiveConfig::get
@@ -112,10 +123,122 @@ impl AppLiveConfig {
             .read()
             .as_ref()
             .map_err(AppConfigError::clone)?
+            .proxy_config
             .get(key.as_ref())
             .map_err(AppConfigError::from)
     }
 
+    /// Returns a [registered](AppConfig::register) section of the live
+    /// configuration, deserialized as the type `T`.
+    ///
+    /// Unlike [`section`], this does not deserialize the section on every
+    /// call: registered sections are deserialized once per [`refresh`], along
+    /// with the rest of the live configuration.
+    ///
+    /// # Panics
+    ///
+    /// Panics on first access if the configuration fails to load, if the
+    /// section fails to parse, or if the section with the given key has not
+    /// been registered as the type `T`. Other sections failing to parse do not
+    /// affect this one. For a less panicky alternative, see
+    /// [`try_registered`].
+    ///
+    /// [`section`]: AppLiveConfig::section
+    /// [`refresh`]: AppLiveConfig::refresh
+    /// [`try_registered`]: AppLiveConfig::try_registered
+    pub fn registered<T>(key: impl AsRef<str>) -> Arc<T>
+    where
+        T: Send + Sync + 'static,
+    {
+        let key = key.as_ref();
+
+        Self::try_registered(key).unwrap_or_else(|error| {
+            panic!(
+                "failed to load or parse the application’s live configuration section '{}': {}",
+                key, error,
+            );
+        })
+    }
+
+    /// Attempts to return a [registered](AppConfig::register) section of the
+    /// live configuration.
+    ///
+    /// This is the less panicky version of [`registered`]. It returns an
+    /// [`Err`] of [`AppConfigError`] if loading or parsing fails.
+    ///
+    /// # Panics
+    ///
+    /// This method will still panic if the section with the given key has not
+    /// been registered as the type `T`.
+    ///
+    /// [`registered`]: AppLiveConfig::registered
+    pub fn try_registered<T>(key: impl AsRef<str>) -> Result<Arc<T>, AppConfigError>
+    where
+        T: Send + Sync + 'static,
+    {
+        let key = key.as_ref();
+
+        StaticLiveConfig::get_config_lock()
+            .read()
+            .as_ref()
+            .map_err(AppConfigError::clone)
+            .and_then(|snapshot| lookup_registered(snapshot, key))
+    }
+
+    /// Subscribes to the changes of a section of the live configuration by
+    /// its `key`, deserialized as the type `T`.
+    ///
+    /// The returned [`Receiver`] starts with the current value of the section
+    /// and receives a new value whenever a [`refresh`] (manual, or triggered by
+    /// the file watcher of the `config-watch` feature) changes the section. A
+    /// refresh that leaves the section intact does not notify the receiver.
+    /// If the key is not found in the configuration, `T::default()` is used.
+    ///
+    /// If the refreshed section fails to deserialize, the receiver keeps the
+    /// previous value.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the configuration fails to load, or if the section fails to
+    /// parse initially. For a less panicky alternative, see
+    /// [`try_subscribe`].
+    ///
+    /// [`refresh`]: AppLiveConfig::refresh
+    /// [`try_subscribe`]: AppLiveConfig::try_subscribe
+    pub fn subscribe<T>(key: impl AsRef<str>) -> Receiver<Arc<T>>
+    where
+        T: DeserializeOwned + Default + Send + Sync + 'static,
+    {
+        let key = key.as_ref();
+
+        Self::try_subscribe(key).unwrap_or_else(|error| {
+            panic!(
+                "failed to load or parse the application’s live configuration section '{}': {}",
+                key, error,
+            );
+        })
+    }
+
+    /// Attempts to subscribe to the changes of a section of the live
+    /// configuration.
+    ///
+    /// This is the less panicky version of [`subscribe`]. It returns an
+    /// [`Err`] of [`AppConfigError`] if loading or parsing fails initially.
+    ///
+    /// [`subscribe`]: AppLiveConfig::subscribe
+    pub fn try_subscribe<T>(key: impl AsRef<str>) -> Result<Receiver<Arc<T>>, AppConfigError>
+    where
+        T: DeserializeOwned + Default + Send + Sync + 'static,
+    {
+        let guard = StaticLiveConfig::get_config_lock().read();
+        let snapshot = guard.as_ref().map_err(AppConfigError::clone)?;
+
+        let (receiver, subscriber) = make_subscription(snapshot, key.as_ref())?;
+        StaticLiveConfig::subscribe(subscriber);
+
+        Ok(receiver)
+    }
+
     /// Clones and returns the underlying `ConfigBuilder`.
     ///
     /// This allows for creating a customized builder based on the current one,
@@ -152,8 +275,16 @@ impl AppLiveConfig {
     /// After this method completes, subsequent calls to [`get`] or [`section`]
     /// will return the newly refreshed values.
     ///
+    /// The keys that changed are logged (with secret values redacted) and
+    /// sent to the receivers of [`subscribe_changes`]. If the refreshed
+    /// configuration fails to load, the previous one is kept. If only the
+    /// `AppConfig` or some registered sections fail to deserialize, those keep
+    /// their previous values, while the rest of the configuration is
+    /// refreshed.
+    ///
     /// [`get`]: AppLiveConfig::get
     /// [`section`]: AppLiveConfig::section
+    /// [`subscribe_changes`]: AppLiveConfig::subscribe_changes
     pub fn refresh() {
         StaticLiveConfig::refresh_config();
     }
@@ -161,10 +292,11 @@ impl AppLiveConfig {
 
 #[cfg(feature = "config-async")]
 impl AppLiveConfig {
-    /// Deserializes and returns the current live `AppConfig`.
+    /// Returns the current live `AppConfig`.
     ///
-    /// This method deserializes the configuration on every call from an internal,
-    /// cached representation. This cache is lazily populated on the first access.
+    /// This method returns a clone of the configuration deserialized from an
+    /// internal, cached representation. This cache is lazily populated on the
+    /// first access.
     ///
     /// To update the configuration from its sources (e.g., files), you must first
     /// call [`refresh`]. Otherwise, this method will return the same cached values.
@@ -198,9 +330,10 @@ impl AppLiveConfig {
             .await
             .read()
             .await
-            .clone()?
-            .try_deserialize()
-            .map_err(AppConfigError::from)
+            .as_ref()
+            .map_err(AppConfigError::clone)?
+            .app_config
+            .clone()
     }
 
     /// Deserializes a section of the live configuration by its `key`.
@@ -214,7 +347,8 @@ impl AppLiveConfig {
     /// # Panics
     ///
     /// Panics on first access if the configuration fails to load, or if the
-    /// specific section fails to parse. For a non-panicking alternative, see
+    /// specific section fails to parse. Other sections failing to parse do
+    /// not affect this one. For a non-panicking alternative, see
     /// [`try_section`].
     ///
     /// [`get`]: AppLiveConfig::get
@@ -250,10 +384,124 @@ impl AppLiveConfig {
             .await
             .as_ref()
             .map_err(AppConfigError::clone)?
+            .proxy_config
             .get(key.as_ref())
             .map_err(AppConfigError::from)
     }
 
+    /// Returns a [registered](AppConfig::register) section of the live
+    /// configuration, deserialized as the type `T`.
+    ///
+    /// Unlike [`section`], this does not deserialize the section on every
+    /// call: registered sections are deserialized once per [`refresh`], along
+    /// with the rest of the live configuration.
+    ///
+    /// # Panics
+    ///
+    /// Panics on first access if the configuration fails to load, if the
+    /// section fails to parse, or if the section with the given key has not
+    /// been registered as the type `T`. Other sections failing to parse do not
+    /// affect this one. For a less panicky alternative, see
+    /// [`try_registered`].
+    ///
+    /// [`section`]: AppLiveConfig::section
+    /// [`refresh`]: AppLiveConfig::refresh
+    /// [`try_registered`]: AppLiveConfig::try_registered
+    pub async fn registered<T>(key: impl AsRef<str>) -> Arc<T>
+    where
+        T: Send + Sync + 'static,
+    {
+        let key = key.as_ref();
+
+        Self::try_registered(key).await.unwrap_or_else(|error| {
+            panic!(
+                "failed to load or parse the application’s live configuration section '{}': {}",
+                key, error,
+            );
+        })
+    }
+
+    /// Attempts to return a [registered](AppConfig::register) section of the
+    /// live configuration.
+    ///
+    /// This is the less panicky version of [`registered`]. It returns an
+    /// [`Err`] of [`AppConfigError`] if loading or parsing fails.
+    ///
+    /// # Panics
+    ///
+    /// This method will still panic if the section with the given key has not
+    /// been registered as the type `T`.
+    ///
+    /// [`registered`]: AppLiveConfig::registered
+    pub async fn try_registered<T>(key: impl AsRef<str>) -> Result<Arc<T>, AppConfigError>
+    where
+        T: Send + Sync + 'static,
+    {
+        let key = key.as_ref();
+
+        StaticLiveConfig::get_config_lock()
+            .await
+            .read()
+            .await
+            .as_ref()
+            .map_err(AppConfigError::clone)
+            .and_then(|snapshot| lookup_registered(snapshot, key))
+    }
+
+    /// Subscribes to the changes of a section of the live configuration by
+    /// its `key`, deserialized as the type `T`.
+    ///
+    /// The returned [`Receiver`] starts with the current value of the section
+    /// and receives a new value whenever a [`refresh`] (manual, or triggered by
+    /// the file watcher of the `config-watch` feature) changes the section. A
+    /// refresh that leaves the section intact does not notify the receiver.
+    /// If the key is not found in the configuration, `T::default()` is used.
+    ///
+    /// If the refreshed section fails to deserialize, the receiver keeps the
+    /// previous value.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the configuration fails to load, or if the section fails to
+    /// parse initially. For a less panicky alternative, see
+    /// [`try_subscribe`].
+    ///
+    /// [`refresh`]: AppLiveConfig::refresh
+    /// [`try_subscribe`]: AppLiveConfig::try_subscribe
+    pub async fn subscribe<T>(key: impl AsRef<str>) -> Receiver<Arc<T>>
+    where
+        T: DeserializeOwned + Default + Send + Sync + 'static,
+    {
+        let key = key.as_ref();
+
+        Self::try_subscribe(key).await.unwrap_or_else(|error| {
+            panic!(
+                "failed to load or parse the application’s live configuration section '{}': {}",
+                key, error,
+            );
+        })
+    }
+
+    /// Attempts to subscribe to the changes of a section of the live
+    /// configuration.
+    ///
+    /// This is the less panicky version of [`subscribe`]. It returns an
+    /// [`Err`] of [`AppConfigError`] if loading or parsing fails initially.
+    ///
+    /// [`subscribe`]: AppLiveConfig::subscribe
+    pub async fn try_subscribe<T>(key: impl AsRef<str>) -> Result<Receiver<Arc<T>>, AppConfigError>
+    where
+        T: DeserializeOwned + Default + Send + Sync + 'static,
+    {
+        let guard = StaticLiveConfig::get_config_lock().await.read().await;
+        let snapshot = guard.as_ref().map_err(AppConfigError::clone)?;
+
+        let (receiver, subscriber) = make_subscription(snapshot, key.as_ref())?;
+        StaticLiveConfig::subscribe(subscriber);
+
+        Ok(receiver)
+    }
+
     /// Clones and returns the underlying `ConfigBuilder`.
     ///
     /// This allows for creating a customized builder based on the current one,
@@ -290,9 +538,133 @@ impl AppLiveConfig {
     /// After this method completes, subsequent calls to [`get`] or [`section`]
     /// will return the newly refreshed values.
     ///
+    /// The keys that changed are logged (with secret values redacted) and
+    /// sent to the receivers of [`subscribe_changes`]. If the refreshed
+    /// configuration fails to load, the previous one is kept. If only the
+    /// `AppConfig` or some registered sections fail to deserialize, those keep
+    /// their previous values, while the rest of the configuration is
+    /// refreshed.
+    ///
     /// [`get`]: AppLiveConfig::get
     /// [`section`]: AppLiveConfig::section
+    /// [`subscribe_changes`]: AppLiveConfig::subscribe_changes
     pub async fn refresh() {
         StaticLiveConfig::refresh_config().await;
     }
 }
+
+impl AppLiveConfig {
+    /// Returns a [`Receiver`] of the [`ConfigDiff`] produced by every
+    /// [`refresh`] that changed the live configuration.
+    ///
+    /// The receiver starts with an empty diff, or with the most recent one if
+    /// the configuration has been changed already. Secret values in the diff
+    /// are redacted.
+    ///
+    /// [`refresh`]: AppLiveConfig::refresh
+    pub fn subscribe_changes() -> Receiver<Arc<ConfigDiff>> {
+        StaticLiveConfig::subscribe_changes()
+    }
+}
+
+/// Returns the registered section with the given key from the given
+/// [`LiveSnapshot`], downcast to the type `T`, or the error of its
+/// deserialization.
+///
+/// # Panics
+///
+/// Panics if the section with the given key has not been registered as the
+/// type `T`.
+fn lookup_registered<T>(snapshot: &LiveSnapshot, key: &str) -> Result<Arc<T>, AppConfigError>
+where
+    T: Send + Sync + 'static,
+{
+    if let Some(error) = snapshot
+        .failed_sections
+        .get(&(Arc::from(key), TypeId::of::<T>()))
+    {
+        return Err(error.clone());
+    }
+
+    Ok(downcast_registered(&snapshot.sections, key))
+}
+
+/// Downcasts the registered section with the given key to the type `T`.
+///
+/// # Panics
+///
+/// Panics if the section with the given key has not been registered as the
+/// type `T`.
+fn downcast_registered<T>(sections: &SectionMap, key: &str) -> Arc<T>
+where
+    T: Send + Sync + 'static,
+{
+    ConfigRegistry::lookup::<T>(sections, key)
+        .and_then(|section| Arc::clone(section).downcast::<T>().ok())
+        .unwrap_or_else(|| panic!("{}", ConfigRegistry::unregistered_message::<T>(key)))
+}
+
+/// Deserializes the section with the given key from the given [`ProxyConfig`],
+/// falling back to `T::default()` if the key is not found.
+fn deserialize_section<T>(proxy_config: &ProxyConfig, key: &str) -> Result<T, AppConfigError>
+where
+    T: DeserializeOwned + Default,
+{
+    proxy_config.get(key).or_else(|error| match error {
+        ConfigError::NotFound(_) => Ok(T::default()),
+        _ => Err(AppConfigError::from(error)),
+    })
+}
+
+/// Creates a [`Receiver`] of the section with the given key, starting with its
+/// value in the given [`LiveSnapshot`], along with the [`Subscriber`] that
+/// sends every changed value of the section to the receiver.
+fn make_subscription<T>(
+    snapshot: &LiveSnapshot,
+    key: &str,
+) -> Result<(Receiver<Arc<T>>, Subscriber), AppConfigError>
+where
+    T: DeserializeOwned + Default + Send + Sync + 'static,
+{
+    // Changes are detected on the raw value of the section
+    let raw_section = |proxy_config: &ProxyConfig, key: &str| {
+        proxy_config.get::<Value>(key).ok().map(|value| value.kind)
+    };
+
+    let section = deserialize_section::<T>(&snapshot.proxy_config, key)?;
+    let mut last_raw = raw_section(&snapshot.proxy_config, key);
+    let (sender, receiver) = watch::channel(Arc::new(section));
+
+    let key = key.to_string();
+    let subscriber: Subscriber = Box::new(move |snapshot: &LiveSnapshot| {
+        // Forget this subscriber once all receivers are dropped
+        if sender.is_closed() {
+            return false;
+        }
+
+        let raw = raw_section(&snapshot.proxy_config, &key);
+        if raw == last_raw {
+            return true;
+        }
+
+        match deserialize_section::<T>(&snapshot.proxy_config, &key) {
+            Ok(section) => {
+                last_raw = raw;
+                sender.send_replace(Arc::new(section));
+            }
+            Err(_error) => {
+                #[cfg(feature = "tracing")]
+                tracing::error!(
+                    alert = true,
+                    key,
+                    error = %_error,
+                    "Failed to parse the refreshed live configuration section; keeping the previous value",
+                );
+            }
+        }
+
+        true
+    });
+
+    Ok((receiver, subscriber))
+}
@@ -1,7 +1,12 @@
-use crate::AppConfigError;
-use config::{Config as ProxyConfig, ConfigBuilder};
-use parking_lot::{lock_api::RwLockReadGuard, RawRwLock, RwLock as SyncRwLock};
-use std::sync::OnceLock;
+use crate::facade::config::registry::{ConfigRegistry, SectionMap};
+use crate::{AppConfig, AppConfigError};
+use config::{Config as ProxyConfig, ConfigBuilder, ConfigError};
+use parking_lot::{Mutex, RawRwLock, RwLock as SyncRwLock, lock_api::RwLockReadGuard};
+use std::any::TypeId;
+use std::collections::HashMap;
+use std::sync::{Arc, OnceLock};
+use strut_config::ConfigDiff;
+use tokio::sync::watch::{self, Receiver, Sender};
 
 #[cfg(not(feature = "config-async"))]
 use config::builder::DefaultState;
@@ -23,27 +28,201 @@ use {
 /// panicking.
 pub(crate) struct StaticLiveConfig;
 
+/// A single resolution of the live configuration: the [`ProxyConfig`] along
+/// with the [`AppConfig`] and the registered custom sections deserialized from
+/// it.
+///
+/// Each of these is resolved independently: a section that fails to
+/// deserialize only fails the access to that section.
+pub(crate) struct LiveSnapshot {
+    pub(crate) proxy_config: ProxyConfig,
+    pub(crate) app_config: Result<AppConfig, AppConfigError>,
+    pub(crate) sections: SectionMap,
+    pub(crate) failed_sections: HashMap<(Arc<str>, TypeId), AppConfigError>,
+}
+
+/// A type-erased callback that is notified of every successfully refreshed
+/// [`LiveSnapshot`]. Returns `false` once it is no longer interested.
+pub(crate) type Subscriber = Box<dyn FnMut(&LiveSnapshot) -> bool + Send>;
+
+// The callbacks interested in the refreshed live configuration
+static SUBSCRIBERS: Mutex<Vec<Subscriber>> = Mutex::new(Vec::new());
+
+// The most recent non-empty diff of the live configuration
+static CHANGES: OnceLock<Sender<Arc<ConfigDiff>>> = OnceLock::new();
+
+impl StaticLiveConfig {
+    /// Adds the given [`Subscriber`], to be notified on every refresh.
+    pub(crate) fn subscribe(subscriber: Subscriber) {
+        SUBSCRIBERS.lock().push(subscriber);
+    }
+
+    /// Returns a [`Receiver`] of every non-empty [`ConfigDiff`] produced by a
+    /// refresh, starting with an empty one.
+    pub(crate) fn subscribe_changes() -> Receiver<Arc<ConfigDiff>> {
+        Self::changes_sender().subscribe()
+    }
+
+    fn changes_sender() -> &'static Sender<Arc<ConfigDiff>> {
+        CHANGES.get_or_init(|| watch::Sender::new(Arc::new(ConfigDiff::default())))
+    }
+
+    /// Replaces the current live configuration with the given freshly loaded
+    /// one, logging and publishing the [`ConfigDiff`] between the two, and
+    /// notifying every [`Subscriber`].
+    ///
+    /// If the fresh configuration failed to load while the current one is
+    /// valid, the current one is kept instead. Likewise, the [`AppConfig`] or
+    /// a registered section that fails to deserialize keeps its current value,
+    /// without affecting the rest of the fresh configuration.
+    fn apply_loaded(
+        current: &mut Result<LiveSnapshot, AppConfigError>,
+        loaded: Result<LiveSnapshot, AppConfigError>,
+    ) {
+        let diff = match (&*current, loaded) {
+            (Ok(_), Err(_error)) => {
+                #[cfg(feature = "tracing")]
+                tracing::error!(
+                    alert = true,
+                    error = %_error,
+                    "Failed to refresh the live configuration; keeping the previous one",
+                );
+                return;
+            }
+            (Ok(previous), Ok(mut snapshot)) => {
+                snapshot.keep_valid(previous);
+                let diff = ConfigDiff::between(&previous.proxy_config, &snapshot.proxy_config);
+                *current = Ok(snapshot);
+                diff
+            }
+            (Err(_), loaded) => {
+                *current = loaded;
+                ConfigDiff::default()
+            }
+        };
+
+        if let Ok(snapshot) = current {
+            Self::publish_changes(diff);
+            Self::notify_subscribers(snapshot);
+        }
+    }
+
+    /// Logs the given [`ConfigDiff`] and sends it to the receivers of the
+    /// [changes](StaticLiveConfig::subscribe_changes), unless it is empty.
+    fn publish_changes(diff: ConfigDiff) {
+        if diff.is_empty() {
+            return;
+        }
+
+        #[cfg(feature = "tracing")]
+        for change in diff.changes() {
+            tracing::info!(
+                key = change.key(),
+                change = %change.kind(),
+                old = change.old_value(),
+                new = change.new_value(),
+                "Live configuration changed",
+            );
+        }
+
+        Self::changes_sender().send_replace(Arc::new(diff));
+    }
+
+    /// Notifies every [`Subscriber`] of the given refreshed configuration,
+    /// forgetting those that are no longer interested.
+    fn notify_subscribers(snapshot: &LiveSnapshot) {
+        SUBSCRIBERS
+            .lock()
+            .retain_mut(|subscriber| subscriber(snapshot));
+    }
+}
+
+impl LiveSnapshot {
+    /// Deserializes the [`AppConfig`] and the registered custom sections from
+    /// the given [`ProxyConfig`], each independently of the others. Fails only
+    /// if the [`ProxyConfig`] itself failed to load.
+    fn resolve(proxy_config: Result<ProxyConfig, ConfigError>) -> Result<Self, AppConfigError> {
+        let proxy_config = proxy_config?;
+        let app_config = proxy_config
+            .clone()
+            .try_deserialize::<AppConfig>()
+            .map_err(AppConfigError::from);
+
+        let mut sections = SectionMap::new();
+        let mut failed_sections = HashMap::new();
+        for (key, type_id, outcome) in ConfigRegistry::load_each(&proxy_config) {
+            match outcome {
+                Ok(section) => {
+                    sections.insert((key, type_id), section);
+                }
+                Err(error) => {
+                    failed_sections.insert((key, type_id), AppConfigError::from(error));
+                }
+            }
+        }
+
+        Ok(Self {
+            proxy_config,
+            app_config,
+            sections,
+            failed_sections,
+        })
+    }
+
+    /// Replaces the [`AppConfig`] and the registered sections that failed to
+    /// deserialize in this snapshot with their valid values from the given
+    /// previous snapshot, if any, logging every replacement.
+    fn keep_valid(&mut self, previous: &LiveSnapshot) {
+        if let (Err(_error), Ok(app_config)) = (&self.app_config, &previous.app_config) {
+            #[cfg(feature = "tracing")]
+            tracing::error!(
+                alert = true,
+                error = %_error,
+                "Failed to parse the refreshed live application configuration; keeping the previous one",
+            );
+            self.app_config = Ok(app_config.clone());
+        }
+
+        self.failed_sections.retain(|section_key, _error| {
+            let Some(section) = previous.sections.get(section_key) else {
+                return true;
+            };
+
+            #[cfg(feature = "tracing")]
+            tracing::error!(
+                alert = true,
+                key = &*section_key.0,
+                error = %_error,
+                "Failed to parse the refreshed live configuration section; keeping the previous value",
+            );
+            self.sections
+                .insert(section_key.clone(), Arc::clone(section));
+
+            false
+        });
+    }
+}
+
 #[cfg(not(feature = "config-async"))]
 static BUILDER: OnceLock<SyncRwLock<Option<ConfigBuilder<DefaultState>>>> = OnceLock::new();
 
 #[cfg(not(feature = "config-async"))]
 impl StaticLiveConfig {
     pub(crate) fn refresh_config() {
-        *Self::get_config_lock().write() = Self::load_config();
+        let snapshot = Self::load_config();
+        let mut guard = Self::get_config_lock().write();
+
+        Self::apply_loaded(&mut guard, snapshot);
     }
 
-    pub(crate) fn get_config_lock() -> &'static SyncRwLock<Result<ProxyConfig, AppConfigError>> {
-        static CONFIG: OnceLock<SyncRwLock<Result<ProxyConfig, AppConfigError>>> = OnceLock::new();
+    pub(crate) fn get_config_lock() -> &'static SyncRwLock<Result<LiveSnapshot, AppConfigError>> {
+        static CONFIG: OnceLock<SyncRwLock<Result<LiveSnapshot, AppConfigError>>> = OnceLock::new();
 
         CONFIG.get_or_init(|| SyncRwLock::new(Self::load_config()))
     }
 
-    fn load_config() -> Result<ProxyConfig, AppConfigError> {
-        Self::read_builder()
-            .as_ref()
-            .unwrap()
-            .build_cloned()
-            .map_err(AppConfigError::from)
+    fn load_config() -> Result<LiveSnapshot, AppConfigError> {
+        LiveSnapshot::resolve(Self::read_builder().as_ref().unwrap().build_cloned())
     }
 
     pub(crate) fn read_builder()
@@ -78,12 +257,15 @@ static BUILDER: OnceLock<SyncRwLock<Option<ConfigBuilder<AsyncState>>>> = OnceLo
 #[cfg(feature = "config-async")]
 impl StaticLiveConfig {
     pub(crate) async fn refresh_config() {
-        *Self::get_config_lock().await.write().await = Self::load_config().await;
+        let snapshot = Self::load_config().await;
+        let mut guard = Self::get_config_lock().await.write().await;
+
+        Self::apply_loaded(&mut guard, snapshot);
     }
 
     pub(crate) async fn get_config_lock()
-    -> &'static AsyncRwLock<Result<ProxyConfig, AppConfigError>> {
-        static CONFIG: OnceCell<AsyncRwLock<Result<ProxyConfig, AppConfigError>>> =
+    -> &'static AsyncRwLock<Result<LiveSnapshot, AppConfigError>> {
+        static CONFIG: OnceCell<AsyncRwLock<Result<LiveSnapshot, AppConfigError>>> =
             OnceCell::const_new();
 
         CONFIG
@@ -91,13 +273,11 @@ impl StaticLiveConfig {
             .await
     }
 
-    async fn load_config() -> Result<ProxyConfig, AppConfigError> {
-        Self::read_builder()
-            .as_ref()
-            .unwrap()
-            .build_cloned()
-            .await
-            .map_err(AppConfigError::from)
+    async fn load_config() -> Result<LiveSnapshot, AppConfigError> {
+        // Clone the builder to release the lock before awaiting
+        let builder = Self::read_builder().as_ref().unwrap().clone();
+
+        LiveSnapshot::resolve(builder.build().await)
     }
 
     pub(crate) fn read_builder()
@@ -125,3 +305,92 @@ impl StaticLiveConfig {
         BUILDER.get_or_init(|| SyncRwLock::new(None))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use config::{File, FileFormat};
+    use pretty_assertions::assert_eq;
+
+    #[derive(Debug, Default, PartialEq, serde::Deserialize)]
+    struct Carrier {
+        #[serde(default)]
+        name: String,
+    }
+
+    #[derive(Debug, Default, PartialEq, serde::Deserialize)]
+    struct Surcharge {
+        percent: u8,
+    }
+
+    fn resolve(input: &str) -> LiveSnapshot {
+        let proxy_config = ProxyConfig::builder()
+            .add_source(File::from_str(input, FileFormat::Yaml))
+            .build();
+
+        LiveSnapshot::resolve(proxy_config).unwrap()
+    }
+
+    fn registered<'a, T: Send + Sync + 'static>(
+        snapshot: &'a LiveSnapshot,
+        key: &str,
+    ) -> Option<&'a T> {
+        ConfigRegistry::lookup::<T>(&snapshot.sections, key)
+            .and_then(|section| section.downcast_ref::<T>())
+    }
+
+    #[test]
+    fn resolve_isolates_failed_sections() {
+        // Given
+        ConfigRegistry::register::<Carrier>("live_carrier");
+        ConfigRegistry::register::<Surcharge>("live_surcharge");
+
+        // When
+        let snapshot = resolve("live_carrier:\n  name: owl\nlive_surcharge:\n  percent: high\n");
+
+        // Then
+        let failed_keys = snapshot
+            .failed_sections
+            .keys()
+            .map(|(key, _)| &**key)
+            .collect::<Vec<_>>();
+        assert_eq!(failed_keys, vec!["live_surcharge"]);
+        assert_eq!(
+            registered::<Carrier>(&snapshot, "live_carrier"),
+            Some(&Carrier {
+                name: "owl".to_string(),
+            }),
+        );
+        assert!(snapshot.app_config.is_ok());
+    }
+
+    #[test]
+    fn refresh_keeps_previous_failed_sections() {
+        // Given
+        ConfigRegistry::register::<Carrier>("refreshed_carrier");
+        ConfigRegistry::register::<Surcharge>("refreshed_surcharge");
+        let mut current = Ok(resolve(
+            "refreshed_carrier:\n  name: owl\nrefreshed_surcharge:\n  percent: 5\n",
+        ));
+        let loaded = Ok(resolve(
+            "refreshed_carrier:\n  name: raven\nrefreshed_surcharge:\n  percent: high\n",
+        ));
+
+        // When
+        StaticLiveConfig::apply_loaded(&mut current, loaded);
+
+        // Then
+        let snapshot = current.unwrap();
+        assert!(snapshot.failed_sections.is_empty());
+        assert_eq!(
+            registered::<Carrier>(&snapshot, "refreshed_carrier"),
+            Some(&Carrier {
+                name: "raven".to_string(),
+            }),
+        );
+        assert_eq!(
+            registered::<Surcharge>(&snapshot, "refreshed_surcharge"),
+            Some(&Surcharge { percent: 5 }),
+        );
+    }
+}
@@ -0,0 +1,263 @@
+use crate::{AppLiveConfig, DotEnv};
+use notify::{Event, EventKind, RecommendedWatcher, RecursiveMode, Watcher};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+use strut_config::Scanner;
+use strut_core::{AppContext, AppSpindown, AppSpindownToken, Pivot};
+use tokio::runtime::Runtime;
+use tokio::select;
+use tokio::sync::mpsc::{self, UnboundedReceiver};
+
+/// The default quiet period that must follow the last file change before the
+/// live configuration is refreshed.
+pub(crate) const DEFAULT_DEBOUNCE: Duration = Duration::from_millis(500);
+
+/// Watches the configuration directory and the dot-env files, and
+/// [refreshes](AppLiveConfig::refresh) the live configuration whenever any of
+/// them changes.
+///
+/// Bursts of changes (e.g., an editor saving several files, or writing a file
+/// in several steps) are debounced: the refresh happens once no further
+/// changes arrive within the debounce period.
+pub(crate) struct ConfigWatcher {
+    config_dir: PathBuf,
+    dotenv_files: Vec<PathBuf>,
+    debounce: Duration,
+}
+
+/// Describes how a single file change affects the live configuration, ordered
+/// by the extent of the refresh it requires.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum Change {
+    /// The change is not related to the configuration.
+    Irrelevant,
+    /// A config file has changed.
+    ConfigFile,
+    /// A dot-env file has changed.
+    DotEnvFile,
+}
+
+impl ConfigWatcher {
+    /// Creates a new [`ConfigWatcher`] over the configuration directory with
+    /// the given name (as passed to [`Scanner::find_config_files`]), and over
+    /// the [dot-env files](DotEnv::files).
+    pub(crate) fn new(dir_name: Option<&str>, debounce: Duration) -> Self {
+        Self {
+            config_dir: Scanner::resolve_config_dir(dir_name),
+            dotenv_files: DotEnv::files(),
+            debounce,
+        }
+    }
+
+    /// Starts watching the files, registers with [`AppSpindown`], and sends the
+    /// watching loop into background on the given [`Runtime`].
+    ///
+    /// Failing to watch the files does not prevent the application from
+    /// running: the live configuration can still be refreshed manually.
+    pub(crate) fn start(self, runtime: &Runtime) {
+        let (sender, receiver) = mpsc::unbounded_channel();
+
+        // The watcher calls back from its own thread
+        let mut watcher = match notify::recommended_watcher(move |event| {
+            let _ = sender.send(event);
+        }) {
+            Ok(watcher) => watcher,
+            Err(_error) => {
+                #[cfg(feature = "tracing")]
+                tracing::error!(
+                    alert = true,
+                    error = %_error,
+                    "Failed to start watching the configuration files",
+                );
+                return;
+            }
+        };
+
+        // Watch the whole configuration directory, including profile sub-directories
+        if self.config_dir.is_dir() {
+            self.watch_path(&mut watcher, &self.config_dir, RecursiveMode::Recursive);
+        }
+
+        // Watch the pivot directory for the dot-env files (which may not exist yet)
+        self.watch_path(&mut watcher, &Pivot::resolve(), RecursiveMode::NonRecursive);
+
+        let spindown_token = AppSpindown::register("config-watcher");
+
+        runtime.spawn(self.watch(watcher, receiver, spindown_token));
+    }
+
+    /// Starts watching the given path, logging any failure.
+    fn watch_path(&self, watcher: &mut RecommendedWatcher, path: &Path, mode: RecursiveMode) {
+        if let Err(_error) = watcher.watch(path, mode) {
+            #[cfg(feature = "tracing")]
+            tracing::warn!(
+                path = %path.display(),
+                error = %_error,
+                "Failed to watch the configuration files",
+            );
+        }
+    }
+
+    /// Main, long-running function that waits for file changes and refreshes
+    /// the live configuration after each debounced burst of changes, until
+    /// the global [`AppContext`] is terminated.
+    ///
+    /// The given watcher is owned here to keep it alive while watching.
+    async fn watch(
+        self,
+        _watcher: RecommendedWatcher,
+        mut receiver: UnboundedReceiver<notify::Result<Event>>,
+        _spindown_token: AppSpindownToken,
+    ) {
+        loop {
+            // Wait for the first relevant change
+            let mut change = Change::Irrelevant;
+            while change == Change::Irrelevant {
+                select! {
+                    biased;
+                    _ = AppContext::terminated() => return,
+                    event = receiver.recv() => match event {
+                        Some(event) => change = self.classify(event),
+                        None => return,
+                    },
+                }
+            }
+
+            // Wait for the burst of changes to settle
+            let mut dotenv_changed = change == Change::DotEnvFile;
+            loop {
+                select! {
+                    biased;
+                    _ = AppContext::terminated() => return,
+                    event = receiver.recv() => match event {
+                        Some(event) => {
+                            dotenv_changed |= self.classify(event) == Change::DotEnvFile;
+                        }
+                        None => return,
+                    },
+                    _ = tokio::time::sleep(self.debounce) => break,
+                }
+            }
+
+            Self::refresh(dotenv_changed).await;
+        }
+    }
+
+    /// Interprets the given file event.
+    fn classify(&self, event: notify::Result<Event>) -> Change {
+        let event = match event {
+            Ok(event) => event,
+            Err(_error) => {
+                #[cfg(feature = "tracing")]
+                tracing::warn!(
+                    error = %_error,
+                    "Failed to receive a change of the configuration files",
+                );
+                return Change::Irrelevant;
+            }
+        };
+
+        // Reading a file changes nothing
+        if matches!(event.kind, EventKind::Access(_)) {
+            return Change::Irrelevant;
+        }
+
+        event
+            .paths
+            .iter()
+            .map(|path| self.classify_path(path))
+            .max()
+            .unwrap_or(Change::Irrelevant)
+    }
+
+    /// Interprets a change of the given path.
+    fn classify_path(&self, path: &Path) -> Change {
+        if self.dotenv_files.iter().any(|file| file == path) {
+            Change::DotEnvFile
+        } else if path.starts_with(&self.config_dir) {
+            Change::ConfigFile
+        } else {
+            Change::Irrelevant
+        }
+    }
+
+    /// Reloads the dot-env files (if they have changed) and refreshes the live
+    /// configuration.
+    async fn refresh(dotenv_changed: bool) {
+        if dotenv_changed {
+            DotEnv::reload();
+        }
+
+        #[cfg(not(feature = "config-async"))]
+        {
+            // Building the configuration reads files
+            let _ = tokio::task::spawn_blocking(AppLiveConfig::refresh).await;
+        }
+
+        #[cfg(feature = "config-async")]
+        AppLiveConfig::refresh().await;
+
+        #[cfg(feature = "tracing")]
+        tracing::info!(
+            dotenv_changed,
+            "Refreshed the live configuration after the configuration files changed",
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use notify::event::{AccessKind, CreateKind, ModifyKind};
+    use pretty_assertions::assert_eq;
+
+    fn make_watcher() -> ConfigWatcher {
+        ConfigWatcher {
+            config_dir: PathBuf::from("/app/config"),
+            dotenv_files: vec![PathBuf::from("/app/.env.local"), PathBuf::from("/app/.env")],
+            debounce: DEFAULT_DEBOUNCE,
+        }
+    }
+
+    fn make_event(kind: EventKind, paths: &[&str]) -> notify::Result<Event> {
+        let event = paths
+            .iter()
+            .fold(Event::new(kind), |event, path| event.add_path(path.into()));
+
+        Ok(event)
+    }
+
+    #[test]
+    fn classify() {
+        // Given
+        let watcher = make_watcher();
+        let modify = EventKind::Modify(ModifyKind::Any);
+        let create = EventKind::Create(CreateKind::File);
+        let access = EventKind::Access(AccessKind::Any);
+
+        // When
+        let changes = [
+            watcher.classify(make_event(modify, &["/app/config/app.toml"])),
+            watcher.classify(make_event(create, &["/app/config/prod/app.yml"])),
+            watcher.classify(make_event(modify, &["/app/.env"])),
+            watcher.classify(make_event(modify, &["/app/config/app.toml", "/app/.env"])),
+            watcher.classify(make_event(access, &["/app/config/app.toml"])),
+            watcher.classify(make_event(modify, &["/app/.envrc"])),
+            watcher.classify(make_event(modify, &["/app/src/main.rs"])),
+        ];
+
+        // Then
+        assert_eq!(
+            changes,
+            [
+                Change::ConfigFile,
+                Change::ConfigFile,
+                Change::DotEnvFile,
+                Change::DotEnvFile,
+                Change::Irrelevant,
+                Change::Irrelevant,
+                Change::Irrelevant,
+            ],
+        );
+    }
+}
@@ -0,0 +1,215 @@
+use config::{Config as ProxyConfig, ConfigError};
+use parking_lot::Mutex;
+use serde::de::DeserializeOwned;
+use std::any::{Any, TypeId, type_name};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// A type-erased, deserialized custom configuration section.
+pub(crate) type Section = Arc<dyn Any + Send + Sync>;
+
+/// The deserialized custom configuration sections, by key and type.
+pub(crate) type SectionMap = HashMap<(Arc<str>, TypeId), Section>;
+
+/// A type-erased function that deserializes a single custom section of the
+/// given [`ProxyConfig`].
+type SectionLoader = Box<dyn Fn(&ProxyConfig) -> Result<Section, ConfigError> + Send + Sync>;
+
+/// A single custom configuration section registered on the [`ConfigRegistry`].
+struct RegisteredSection {
+    key: Arc<str>,
+    type_id: TypeId,
+    loader: SectionLoader,
+}
+
+// The custom configuration sections registered so far, in order of registration
+static SECTIONS: Mutex<Vec<RegisteredSection>> = Mutex::new(Vec::new());
+
+/// An internal facade for the custom configuration sections that are
+/// [registered](crate::AppConfig::register) by the application.
+///
+/// Registered sections are deserialized once per resolution of the
+/// configuration (at seed time for the initial configuration, and on every
+/// refresh for the live configuration), and are validated eagerly along with
+/// the sections known to [`AppConfig`](crate::AppConfig).
+pub(crate) struct ConfigRegistry;
+
+impl ConfigRegistry {
+    /// Registers the custom section with the given key to be deserialized as
+    /// the type `T`. If the key is not found in the configuration,
+    /// `T::default()` is used.
+    ///
+    /// Registering the same key as the same type repeatedly has no effect.
+    pub(crate) fn register<T>(key: impl AsRef<str>)
+    where
+        T: DeserializeOwned + Default + Send + Sync + 'static,
+    {
+        let key = Arc::<str>::from(key.as_ref());
+        let type_id = TypeId::of::<T>();
+
+        let mut sections = SECTIONS.lock();
+
+        if sections
+            .iter()
+            .any(|section| section.key == key && section.type_id == type_id)
+        {
+            return;
+        }
+
+        let loader: SectionLoader = {
+            let key = Arc::clone(&key);
+            Box::new(
+                move |proxy_config: &ProxyConfig| match proxy_config.get::<T>(&key) {
+                    Ok(section) => Ok(Arc::new(section) as Section),
+                    Err(ConfigError::NotFound(_)) => Ok(Arc::new(T::default()) as Section),
+                    Err(error) => Err(error),
+                },
+            )
+        };
+
+        sections.push(RegisteredSection {
+            key,
+            type_id,
+            loader,
+        });
+    }
+
+    /// Deserializes every registered section from the given [`ProxyConfig`].
+    ///
+    /// If any section fails to deserialize, returns the errors of all failed
+    /// sections, along with their keys, in the order of registration.
+    pub(crate) fn load(
+        proxy_config: &ProxyConfig,
+    ) -> Result<SectionMap, Vec<(Arc<str>, ConfigError)>> {
+        let mut map = SectionMap::new();
+        let mut errors = Vec::new();
+
+        for (key, type_id, outcome) in Self::load_each(proxy_config) {
+            match outcome {
+                Ok(value) => {
+                    map.insert((key, type_id), value);
+                }
+                Err(error) => errors.push((key, error)),
+            }
+        }
+
+        match errors.is_empty() {
+            true => Ok(map),
+            false => Err(errors),
+        }
+    }
+
+    /// Deserializes every registered section from the given [`ProxyConfig`]
+    /// independently of the others, returning the outcome for each section,
+    /// along with its key and type, in the order of registration.
+    pub(crate) fn load_each(
+        proxy_config: &ProxyConfig,
+    ) -> Vec<(Arc<str>, TypeId, Result<Section, ConfigError>)> {
+        SECTIONS
+            .lock()
+            .iter()
+            .map(|section| {
+                (
+                    Arc::clone(&section.key),
+                    section.type_id,
+                    (section.loader)(proxy_config),
+                )
+            })
+            .collect()
+    }
+
+    /// Returns the keys of all registered sections, in the order of
+    /// registration.
+    pub(crate) fn keys() -> Vec<Arc<str>> {
+        SECTIONS
+            .lock()
+            .iter()
+            .map(|section| Arc::clone(&section.key))
+            .collect()
+    }
+
+    /// Looks up the section with the given key, deserialized as the type `T`,
+    /// in the given [`SectionMap`].
+    pub(crate) fn lookup<'a, T>(map: &'a SectionMap, key: &str) -> Option<&'a Section>
+    where
+        T: Send + Sync + 'static,
+    {
+        map.get(&(Arc::from(key), TypeId::of::<T>()))
+    }
+
+    /// Composes the panic message for a section that is looked up before being
+    /// registered.
+    pub(crate) fn unregistered_message<T>(key: &str) -> String {
+        format!(
+            "the configuration section '{}' should be registered as `{}` before the configuration is resolved",
+            key,
+            type_name::<T>(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use config::{File, FileFormat};
+    use pretty_assertions::assert_eq;
+
+    #[derive(Debug, Default, PartialEq, serde::Deserialize)]
+    struct Shipping {
+        #[serde(default)]
+        carrier: String,
+    }
+
+    #[derive(Debug, Default, PartialEq, serde::Deserialize)]
+    struct Tax {
+        rate: u8,
+    }
+
+    fn proxy_config(input: &str) -> ProxyConfig {
+        ProxyConfig::builder()
+            .add_source(File::from_str(input, FileFormat::Yaml))
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn load_and_lookup() {
+        // Given
+        ConfigRegistry::register::<Shipping>("shipping");
+        ConfigRegistry::register::<Shipping>("shipping");
+        ConfigRegistry::register::<Shipping>("returns");
+        let proxy_config = proxy_config("shipping:\n  carrier: owl\n");
+
+        // When
+        let map = ConfigRegistry::load(&proxy_config).unwrap();
+
+        // Then
+        let shipping = ConfigRegistry::lookup::<Shipping>(&map, "shipping")
+            .and_then(|section| section.downcast_ref::<Shipping>());
+        let returns = ConfigRegistry::lookup::<Shipping>(&map, "returns")
+            .and_then(|section| section.downcast_ref::<Shipping>());
+        let mismatched = ConfigRegistry::lookup::<String>(&map, "shipping");
+        assert_eq!(
+            shipping,
+            Some(&Shipping {
+                carrier: "owl".to_string(),
+            }),
+        );
+        assert_eq!(returns, Some(&Shipping::default()));
+        assert!(mismatched.is_none());
+    }
+
+    #[test]
+    fn load_failed() {
+        // Given
+        ConfigRegistry::register::<Tax>("tax");
+        let proxy_config = proxy_config("tax:\n  rate: high\n");
+
+        // When
+        let errors = ConfigRegistry::load(&proxy_config).unwrap_err();
+
+        // Then
+        let keys = errors.iter().map(|(key, _)| &**key).collect::<Vec<_>>();
+        assert_eq!(keys, vec!["tax"]);
+    }
+}
@@ -0,0 +1,579 @@
+use crate::AppLiveConfig;
+use async_trait::async_trait;
+use config::{ConfigError, File, FileFormat, FileStoredFormat, Map, Source, Value};
+use parking_lot::RwLock;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::Duration;
+use strut_core::{AppContext, AppSpindown, AppSpindownToken, Pivot};
+use strut_util::{Backoff, BackoffConfig};
+use tokio::runtime::Runtime;
+use tokio::select;
+
+pub mod http;
+
+/// The default time limit of a single fetch attempt.
+const DEFAULT_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// The default interval between two polls of a remote document.
+const DEFAULT_POLL_INTERVAL: Duration = Duration::from_secs(60);
+
+/// The default directory (relative to the cache directory of the user) of the
+/// cached remote documents.
+const DEFAULT_CACHE_DIR: &str = "strut/remote-config";
+
+/// The error type returned from [`RemoteConfigProvider::fetch`].
+pub type RemoteFetchError = Box<dyn Error + Send + Sync>;
+
+/// Fetches a configuration document from a remote location, such as an HTTP
+/// endpoint (see [`HttpConfigProvider`](crate::HttpConfigProvider)) or a
+/// key-value store.
+///
+/// A provider is registered on the [`Launchpad`](crate::Launchpad) via
+/// [`with_remote_config`](crate::Launchpad::with_remote_config), wrapped in a
+/// [`RemoteConfig`] that adds the timeouts, the retries, the polling, and the
+/// cache on disk.
+#[async_trait]
+pub trait RemoteConfigProvider: Send + Sync + 'static {
+    /// Returns the human-readable name of this provider, which is used in
+    /// log entries and in the name of the cached document.
+    fn name(&self) -> &str;
+
+    /// Returns the format of the fetched documents.
+    fn format(&self) -> FileFormat;
+
+    /// Fetches the current version of the document.
+    async fn fetch(&self) -> Result<String, RemoteFetchError>;
+}
+
+/// Wraps a [`RemoteConfigProvider`] and keeps the last known good document
+/// fetched from it, which is merged into the configuration as a
+/// [literal source](strut_config::AssemblerChoices::literal_sources).
+///
+/// ## Fetching
+///
+/// Every fetch attempt is limited by a [timeout](RemoteConfig::with_timeout),
+/// and failed attempts are retried with a [backoff](RemoteConfig::with_backoff)
+/// until the backoff runs out of its max elapsed time. A fetched document that
+/// fails to parse counts as a failed attempt.
+///
+/// ## Boot
+///
+/// The document is fetched once before the initial configuration is
+/// assembled. Each successfully fetched document is written to the
+/// [cache](RemoteConfig::with_cache_path) on disk, readable only by its
+/// owner. If the document cannot be
+/// fetched on boot, the cached copy is used instead, and if there is none,
+/// the application refuses to start.
+///
+/// ## Polling
+///
+/// While the application runs, the document is fetched again every
+/// [poll interval](RemoteConfig::with_poll_interval). Whenever it changes, the
+/// [live configuration](AppLiveConfig) is refreshed. If the document cannot be
+/// fetched, the last known good document is kept.
+pub struct RemoteConfig {
+    provider: Arc<dyn RemoteConfigProvider>,
+    timeout: Duration,
+    backoff: Backoff,
+    poll_interval: Option<Duration>,
+    cache_path: Option<PathBuf>,
+    document: Arc<RwLock<Option<String>>>,
+}
+
+/// Represents the failure to obtain a remote document on boot, either by
+/// fetching it or from the cache.
+#[derive(Debug)]
+pub struct RemoteConfigError {
+    name: String,
+    error: RemoteFetchError,
+}
+
+/// A configuration [`Source`] that reads the last known good document of a
+/// [`RemoteConfig`].
+#[derive(Debug, Clone)]
+pub(crate) struct RemoteSource {
+    format: FileFormat,
+    document: Arc<RwLock<Option<String>>>,
+}
+
+impl RemoteConfig {
+    /// Wraps the given [`RemoteConfigProvider`] with the default settings.
+    pub fn new<P>(provider: P) -> Self
+    where
+        P: RemoteConfigProvider,
+    {
+        let cache_path = default_cache_dir()
+            .join(DEFAULT_CACHE_DIR)
+            .join(sanitize(&Pivot::resolve().to_string_lossy()))
+            .join(cache_file_name(provider.name(), provider.format()));
+
+        Self {
+            provider: Arc::new(provider),
+            timeout: DEFAULT_TIMEOUT,
+            backoff: default_backoff(),
+            poll_interval: Some(DEFAULT_POLL_INTERVAL),
+            cache_path: Some(cache_path),
+            document: Arc::new(RwLock::new(None)),
+        }
+    }
+
+    /// Specifies the time limit of a single fetch attempt.
+    ///
+    /// Defaults to 5 seconds.
+    pub fn with_timeout(self, timeout: Duration) -> Self {
+        Self { timeout, ..self }
+    }
+
+    /// Specifies the backoff between the retries of failed fetch attempts.
+    /// The attempts are retried until the backoff runs out of its
+    /// [max elapsed time](BackoffConfig::max_elapsed_time), and forever if
+    /// the backoff has none.
+    ///
+    /// Defaults to the intervals between half a second and 5 seconds, for at
+    /// most 15 seconds.
+    pub fn with_backoff(self, backoff: impl AsRef<BackoffConfig>) -> Self {
+        Self {
+            backoff: Backoff::new(backoff),
+            ..self
+        }
+    }
+
+    /// Specifies the interval between two polls of the document, or disables
+    /// polling if `None` is given.
+    ///
+    /// Defaults to one minute.
+    pub fn with_poll_interval(self, poll_interval: Option<Duration>) -> Self {
+        Self {
+            poll_interval,
+            ..self
+        }
+    }
+
+    /// Specifies the path of the cached document, or disables the cache if
+    /// `None` is given. A relative path is resolved against the
+    /// [pivot directory](Pivot::resolve).
+    ///
+    /// Defaults to a file named after the [provider](RemoteConfigProvider::name)
+    /// under `strut/remote-config/<pivot directory>` in the cache directory of
+    /// the user (`$XDG_CACHE_HOME` or `~/.cache`, falling back to the
+    /// temporary directory), so that the cached documents stay out of the
+    /// working tree.
+    pub fn with_cache_path(self, cache_path: Option<impl AsRef<Path>>) -> Self {
+        Self {
+            cache_path: cache_path.map(|path| Pivot::resolve().join(path)),
+            ..self
+        }
+    }
+}
+
+impl RemoteConfig {
+    /// Returns the [`Source`] that reads the last known good document.
+    pub(crate) fn source(&self) -> RemoteSource {
+        RemoteSource {
+            format: self.provider.format(),
+            document: Arc::clone(&self.document),
+        }
+    }
+
+    /// Obtains the document on boot: fetches it, or falls back to the cached
+    /// copy.
+    pub(crate) async fn load(&self) -> Result<(), RemoteConfigError> {
+        let error = match self.fetch().await {
+            Ok(document) => {
+                self.store(document);
+                return Ok(());
+            }
+            Err(error) => error,
+        };
+
+        let Some(document) = self.read_cache() else {
+            return Err(RemoteConfigError {
+                name: self.provider.name().to_string(),
+                error,
+            });
+        };
+
+        #[cfg(feature = "tracing")]
+        tracing::warn!(
+            provider = self.provider.name(),
+            error = %error,
+            "Failed to fetch the remote configuration, using the cached copy",
+        );
+
+        *self.document.write() = Some(document);
+
+        Ok(())
+    }
+
+    /// Registers with [`AppSpindown`] and sends the polling loop into
+    /// background on the given [`Runtime`], unless polling is disabled.
+    pub(crate) fn start(self: Arc<Self>, runtime: &Runtime) {
+        let Some(poll_interval) = self.poll_interval else {
+            return;
+        };
+
+        let spindown_token =
+            AppSpindown::register(format!("remote-config:{}", self.provider.name()));
+
+        runtime.spawn(self.poll(poll_interval, spindown_token));
+    }
+
+    /// Main, long-running function that polls the document and refreshes the
+    /// live configuration whenever it changes, until the global
+    /// [`AppContext`] is terminated.
+    async fn poll(self: Arc<Self>, poll_interval: Duration, _spindown_token: AppSpindownToken) {
+        loop {
+            let fetched = select! {
+                biased;
+                _ = AppContext::terminated() => return,
+                fetched = async {
+                    tokio::time::sleep(poll_interval).await;
+                    self.fetch().await
+                } => fetched,
+            };
+
+            match fetched {
+                Ok(document) if self.document.read().as_ref() != Some(&document) => {
+                    self.store(document);
+                    Self::refresh().await;
+                }
+                Ok(_) => {}
+                Err(_error) => {
+                    #[cfg(feature = "tracing")]
+                    tracing::warn!(
+                        provider = self.provider.name(),
+                        error = %_error,
+                        "Failed to fetch the remote configuration, keeping the last known good copy",
+                    );
+                }
+            }
+        }
+    }
+
+    /// Fetches the document, retrying the failed attempts with the backoff.
+    async fn fetch(&self) -> Result<String, RemoteFetchError> {
+        self.backoff.reset();
+
+        loop {
+            let error = match self.fetch_once().await {
+                Ok(document) => return Ok(document),
+                Err(error) => error,
+            };
+
+            match self.backoff.next() {
+                Some(interval) => tokio::time::sleep(interval).await,
+                None => return Err(error),
+            }
+        }
+    }
+
+    /// Makes a single fetch attempt, within the timeout, and makes sure the
+    /// fetched document parses.
+    async fn fetch_once(&self) -> Result<String, RemoteFetchError> {
+        let document = tokio::time::timeout(self.timeout, self.provider.fetch())
+            .await
+            .map_err(|_| format!("timed out after {:?}", self.timeout))??;
+
+        File::from_str(&document, self.provider.format()).collect()?;
+
+        Ok(document)
+    }
+
+    /// Keeps the given document as the last known good, and writes it to the
+    /// cache.
+    fn store(&self, document: String) {
+        if let Some(cache_path) = &self.cache_path {
+            if let Err(_error) = write_cache(cache_path, &document) {
+                #[cfg(feature = "tracing")]
+                tracing::warn!(
+                    provider = self.provider.name(),
+                    path = %cache_path.display(),
+                    error = %_error,
+                    "Failed to cache the remote configuration",
+                );
+            }
+        }
+
+        *self.document.write() = Some(document);
+    }
+
+    /// Reads the cached document, if any.
+    fn read_cache(&self) -> Option<String> {
+        let document = std::fs::read_to_string(self.cache_path.as_ref()?).ok()?;
+
+        // A corrupted cache is as good as none
+        File::from_str(&document, self.provider.format())
+            .collect()
+            .ok()?;
+
+        Some(document)
+    }
+
+    /// Refreshes the live configuration.
+    async fn refresh() {
+        #[cfg(not(feature = "config-async"))]
+        {
+            // Building the configuration may read files
+            let _ = tokio::task::spawn_blocking(AppLiveConfig::refresh).await;
+        }
+
+        #[cfg(feature = "config-async")]
+        AppLiveConfig::refresh().await;
+    }
+}
+
+/// Makes the default backoff between the fetch attempts.
+fn default_backoff() -> Backoff {
+    Backoff::builder()
+        .with_initial_interval(Duration::from_millis(500))
+        .with_max_interval(Duration::from_secs(5))
+        .with_max_elapsed_time(Some(Duration::from_secs(15)))
+        .build()
+}
+
+/// Returns the cache directory of the current user, or the temporary
+/// directory if there is none.
+fn default_cache_dir() -> PathBuf {
+    if let Some(dir) = std::env::var_os("XDG_CACHE_HOME").filter(|dir| !dir.is_empty()) {
+        return PathBuf::from(dir);
+    }
+
+    match std::env::var_os("HOME").filter(|dir| !dir.is_empty()) {
+        Some(home) => PathBuf::from(home).join(".cache"),
+        None => std::env::temp_dir(),
+    }
+}
+
+/// Names the cached document of the provider with the given name.
+fn cache_file_name(name: &str, format: FileFormat) -> String {
+    let extension = format.file_extensions().first().copied().unwrap_or("txt");
+
+    format!("{}.{}", sanitize(name), extension)
+}
+
+/// Keeps only the characters of the given name that are safe in a file name.
+fn sanitize(name: &str) -> String {
+    name.chars()
+        .map(|c| match c.is_ascii_alphanumeric() || c == '-' {
+            true => c,
+            false => '_',
+        })
+        .collect()
+}
+
+/// Writes the given document to the cache at the given path, readable only by
+/// its owner. The document is written to a temporary file first, and then
+/// renamed over the cache, so that the cache is never left half-written.
+fn write_cache(path: &Path, document: &str) -> std::io::Result<()> {
+    let mut dir_builder = std::fs::DirBuilder::new();
+    dir_builder.recursive(true);
+
+    #[cfg(unix)]
+    std::os::unix::fs::DirBuilderExt::mode(&mut dir_builder, 0o700);
+
+    if let Some(parent) = path.parent() {
+        dir_builder.create(parent)?;
+    }
+
+    let mut temporary_name = path.file_name().unwrap_or_default().to_os_string();
+    temporary_name.push(format!(".{}.tmp", std::process::id()));
+    let temporary_path = path.with_file_name(temporary_name);
+
+    let mut options = std::fs::OpenOptions::new();
+    options.write(true).create(true).truncate(true);
+
+    #[cfg(unix)]
+    std::os::unix::fs::OpenOptionsExt::mode(&mut options, 0o600);
+
+    let written = options.open(&temporary_path).and_then(|mut file| {
+        // A leftover temporary file keeps its permissions when opened
+        #[cfg(unix)]
+        file.set_permissions(std::os::unix::fs::PermissionsExt::from_mode(0o600))?;
+
+        std::io::Write::write_all(&mut file, document.as_bytes())?;
+        file.sync_all()
+    });
+
+    match written.and_then(|_| std::fs::rename(&temporary_path, path)) {
+        Ok(()) => Ok(()),
+        Err(error) => {
+            let _ = std::fs::remove_file(&temporary_path);
+            Err(error)
+        }
+    }
+}
+
+impl Source for RemoteSource {
+    fn clone_into_box(&self) -> Box<dyn Source + Send + Sync> {
+        Box::new(self.clone())
+    }
+
+    fn collect(&self) -> Result<Map<String, Value>, ConfigError> {
+        match self.document.read().as_deref() {
+            Some(document) => File::from_str(document, self.format).collect(),
+            None => Ok(Map::new()),
+        }
+    }
+}
+
+impl RemoteConfigError {
+    /// Returns the name of the [provider](RemoteConfigProvider::name).
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+impl Display for RemoteConfigError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "failed to fetch the remote configuration '{}', and no cached copy is available: {}",
+            self.name, self.error,
+        )
+    }
+}
+
+impl Error for RemoteConfigError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&*self.error)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    struct StubProvider {
+        responses: Vec<Result<&'static str, &'static str>>,
+        calls: AtomicUsize,
+    }
+
+    #[async_trait]
+    impl RemoteConfigProvider for StubProvider {
+        fn name(&self) -> &str {
+            "stub/billing"
+        }
+
+        fn format(&self) -> FileFormat {
+            FileFormat::Toml
+        }
+
+        async fn fetch(&self) -> Result<String, RemoteFetchError> {
+            let call = self.calls.fetch_add(1, Ordering::SeqCst);
+            let response = self.responses[call.min(self.responses.len() - 1)];
+
+            response.map(ToString::to_string).map_err(Into::into)
+        }
+    }
+
+    fn make_remote(
+        responses: Vec<Result<&'static str, &'static str>>,
+        cache: &Path,
+    ) -> RemoteConfig {
+        let provider = StubProvider {
+            responses,
+            calls: AtomicUsize::new(0),
+        };
+        let backoff = Backoff::builder()
+            .with_initial_interval(Duration::from_millis(1))
+            .with_max_interval(Duration::from_millis(1))
+            .with_max_elapsed_time(Some(Duration::from_millis(50)))
+            .build();
+
+        RemoteConfig {
+            backoff,
+            ..RemoteConfig::new(provider).with_cache_path(Some(cache))
+        }
+    }
+
+    fn collect(remote: &RemoteConfig) -> String {
+        remote.source().collect().unwrap()["currency"].to_string()
+    }
+
+    fn block_on<F: Future>(future: F) -> F::Output {
+        tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .unwrap()
+            .block_on(future)
+    }
+
+    #[test]
+    fn cache_file_names() {
+        // Then
+        assert_eq!(
+            cache_file_name("stub/billing", FileFormat::Toml),
+            "stub_billing.toml"
+        );
+        assert_eq!(
+            cache_file_name("config-server", FileFormat::Yaml),
+            "config-server.yaml"
+        );
+    }
+
+    #[test]
+    fn load_retried() {
+        // Given
+        let cache = std::env::temp_dir().join("strut_remote_config_retried.toml");
+        let remote = make_remote(
+            vec![
+                Err("unavailable"),
+                Ok("currency = "),
+                Ok("currency = 'EUR'"),
+            ],
+            &cache,
+        );
+
+        // When
+        block_on(remote.load()).unwrap();
+
+        // Then
+        assert_eq!(collect(&remote), "EUR");
+        assert_eq!(std::fs::read_to_string(&cache).unwrap(), "currency = 'EUR'");
+        #[cfg(unix)]
+        assert_eq!(
+            std::os::unix::fs::PermissionsExt::mode(
+                &std::fs::metadata(&cache).unwrap().permissions()
+            ) & 0o777,
+            0o600,
+        );
+        std::fs::remove_file(&cache).unwrap();
+    }
+
+    #[test]
+    fn load_cached() {
+        // Given
+        let cache = std::env::temp_dir().join("strut_remote_config_cached.toml");
+        std::fs::write(&cache, "currency = 'USD'").unwrap();
+        let remote = make_remote(vec![Err("unavailable")], &cache);
+
+        // When
+        block_on(remote.load()).unwrap();
+
+        // Then
+        assert_eq!(collect(&remote), "USD");
+        std::fs::remove_file(&cache).unwrap();
+    }
+
+    #[test]
+    fn load_failed() {
+        // Given
+        let cache = std::env::temp_dir().join("strut_remote_config_missing.toml");
+        let remote = make_remote(vec![Err("unavailable")], &cache);
+
+        // When
+        let error = block_on(remote.load()).unwrap_err();
+
+        // Then
+        assert_eq!(
+            error.to_string(),
+            "failed to fetch the remote configuration 'stub/billing', and no cached copy is available: unavailable",
+        );
+        assert_eq!(remote.source().collect().unwrap(), Map::new());
+    }
+}
@@ -0,0 +1,454 @@
+use crate::facade::config::registry::ConfigRegistry;
+use crate::facade::config::validation::find_origin;
+use crate::{AppConfig, AppConfigError};
+use config::{Config as ProxyConfig, Value, ValueKind};
+use parking_lot::Mutex;
+use serde_json::{Map as JsonMap, Value as JsonValue};
+use strut_config::PROFILES_KEY;
+use strut_deserialize::{ConfigSchema, Slug};
+
+/// The origin that the [`Environment`](config::Environment) source assigns to
+/// every value it collects.
+const ENV_ORIGIN: &str = "the environment";
+
+/// Defines how to treat the keys of the application configuration that are not
+/// recognized by any of its sections (typically, mistyped keys).
+///
+/// The unknown keys are looked up in the sections known to Strut, using the
+/// same key aliases and the same lenient matching as the deserialization. At
+/// the top level of the configuration, the [registered](AppConfig::register)
+/// custom sections are known as well. The contents of the custom sections are
+/// never checked.
+///
+/// The keys set via environment variables are never reported, since the
+/// separator of environment variables cannot express multi-word keys.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum UnknownKeyPolicy {
+    /// Silently ignores the unknown keys (the default).
+    #[default]
+    Ignore,
+
+    /// Logs a warning (or, without the `tracing` feature, prints it to the
+    /// standard error) for every unknown key, but accepts the configuration.
+    Warn,
+
+    /// Rejects the configuration, reporting every unknown key as a validation
+    /// error.
+    Deny,
+}
+
+/// The [`UnknownKeyPolicy`] chosen for the whole configuration, along with
+/// the overrides for individual top-level sections.
+#[derive(Debug, Clone)]
+pub(crate) struct UnknownKeyPolicies {
+    global: UnknownKeyPolicy,
+    sections: Vec<(String, UnknownKeyPolicy)>,
+}
+
+/// A single unknown key found in the configuration.
+#[derive(Debug, Clone)]
+pub(crate) struct UnknownKey {
+    /// The top-level section where the key was found.
+    pub(crate) section: String,
+    /// The policy that applies to the section.
+    pub(crate) policy: UnknownKeyPolicy,
+    /// The error that describes the key, including its full path, its origin,
+    /// and the suggested replacement, if any.
+    pub(crate) error: AppConfigError,
+}
+
+// The policies chosen by the application so far
+static POLICIES: Mutex<UnknownKeyPolicies> = Mutex::new(UnknownKeyPolicies::new());
+
+/// An internal facade for finding the unknown keys in the application
+/// configuration, according to the [`UnknownKeyPolicy`] chosen by the
+/// application.
+pub(crate) struct UnknownKeys;
+
+impl UnknownKeys {
+    /// Sets the [`UnknownKeyPolicy`] for the whole configuration.
+    pub(crate) fn set_policy(policy: UnknownKeyPolicy) {
+        POLICIES.lock().global = policy;
+    }
+
+    /// Sets the [`UnknownKeyPolicy`] for the given top-level section,
+    /// overriding the policy for the whole configuration.
+    pub(crate) fn set_section_policy(section: impl AsRef<str>, policy: UnknownKeyPolicy) {
+        POLICIES.lock().set_section(section.as_ref(), policy);
+    }
+
+    /// Finds the unknown keys in the given [`ProxyConfig`], skipping the
+    /// sections where they are [ignored](UnknownKeyPolicy::Ignore).
+    pub(crate) fn find(proxy_config: &ProxyConfig) -> Vec<UnknownKey> {
+        let policies = POLICIES.lock().clone();
+
+        Self::find_with(proxy_config, &policies)
+    }
+
+    /// Finds the unknown keys in the given [`ProxyConfig`] according to the
+    /// given [`UnknownKeyPolicies`], in the order of keys.
+    fn find_with(proxy_config: &ProxyConfig, policies: &UnknownKeyPolicies) -> Vec<UnknownKey> {
+        let mut unknown_keys = Vec::new();
+
+        if policies.are_ignored() {
+            return unknown_keys;
+        }
+
+        let ValueKind::Table(ref table) = proxy_config.cache.kind else {
+            return unknown_keys;
+        };
+
+        let schema = JsonValue::from(AppConfig::config_schema());
+        let properties = schema.as_object().and_then(properties_of);
+        let registered = ConfigRegistry::keys();
+        let mut known_sections: Vec<&str> =
+            AppConfig::section_keys().chain([PROFILES_KEY]).collect();
+        known_sections.extend(registered.iter().map(AsRef::as_ref));
+
+        let mut sections = table.iter().collect::<Vec<_>>();
+        sections.sort_by_key(|(section, _)| *section);
+
+        for (section, value) in sections {
+            let policy = policies.of_section(section);
+            if policy == UnknownKeyPolicy::Ignore {
+                continue;
+            }
+
+            let mut found = Vec::new();
+            let section_schema = properties
+                .into_iter()
+                .flat_map(JsonMap::iter)
+                .find(|(name, _)| Slug::eq_as_slugs(name, section))
+                .map(|(_, section_schema)| section_schema);
+
+            if let Some(section_schema) = section_schema {
+                // Look for the unknown keys inside the section
+                collect_unknown(section_schema, value, section, &mut found);
+            } else if !known_sections
+                .iter()
+                .any(|name| Slug::eq_as_slugs(name, section))
+                && !is_from_env(value)
+            {
+                // The section itself is unknown
+                let candidates = known_sections.iter().copied();
+                found.push(unknown_key_error(section, section, value, candidates));
+            }
+
+            unknown_keys.extend(found.into_iter().map(|error| UnknownKey {
+                section: section.to_string(),
+                policy,
+                error,
+            }));
+        }
+
+        unknown_keys
+    }
+}
+
+impl UnknownKeyPolicies {
+    /// Creates the default policies: everything is ignored.
+    const fn new() -> Self {
+        Self {
+            global: UnknownKeyPolicy::Ignore,
+            sections: Vec::new(),
+        }
+    }
+
+    /// Sets the policy for the given top-level section, replacing the previous
+    /// policy for the same section.
+    fn set_section(&mut self, section: &str, policy: UnknownKeyPolicy) {
+        self.sections
+            .retain(|(existing, _)| !Slug::eq_as_slugs(existing, section));
+        self.sections.push((section.to_string(), policy));
+    }
+
+    /// Returns the policy for the given top-level section.
+    fn of_section(&self, section: &str) -> UnknownKeyPolicy {
+        self.sections
+            .iter()
+            .find(|(name, _)| Slug::eq_as_slugs(name, section))
+            .map(|(_, policy)| *policy)
+            .unwrap_or(self.global)
+    }
+
+    /// Reports whether the unknown keys are ignored everywhere.
+    fn are_ignored(&self) -> bool {
+        self.global == UnknownKeyPolicy::Ignore
+            && self
+                .sections
+                .iter()
+                .all(|(_, policy)| *policy == UnknownKeyPolicy::Ignore)
+    }
+}
+
+/// Recursively collects the errors for the unknown keys in the given value,
+/// which is found at the given key path and is expected to match the given
+/// JSON Schema.
+fn collect_unknown(schema: &JsonValue, value: &Value, path: &str, found: &mut Vec<AppConfigError>) {
+    match value.kind {
+        ValueKind::Table(ref table) => {
+            let branches = object_branches(schema);
+            if branches.is_empty() {
+                return;
+            }
+
+            let mut entries = table.iter().collect::<Vec<_>>();
+            entries.sort_by_key(|(key, _)| *key);
+
+            for (key, nested) in entries {
+                let nested_path = format!("{}.{}", path, key);
+
+                // Known field
+                let field_schema = branches
+                    .iter()
+                    .filter_map(|branch| properties_of(branch))
+                    .flat_map(JsonMap::iter)
+                    .find(|(name, _)| Slug::eq_as_slugs(name, key))
+                    .map(|(_, field_schema)| field_schema);
+                if let Some(field_schema) = field_schema {
+                    collect_unknown(field_schema, nested, &nested_path, found);
+                    continue;
+                }
+
+                // Arbitrary key of a map
+                let values_schema = branches
+                    .iter()
+                    .find_map(|branch| branch.get("additionalProperties"));
+                if let Some(values_schema) = values_schema {
+                    collect_unknown(values_schema, nested, &nested_path, found);
+                    continue;
+                }
+
+                // Any key is accepted
+                if branches
+                    .iter()
+                    .any(|branch| properties_of(branch).is_none())
+                {
+                    continue;
+                }
+
+                if !is_from_env(nested) {
+                    let candidates = branches
+                        .iter()
+                        .filter_map(|branch| properties_of(branch))
+                        .flat_map(JsonMap::keys)
+                        .map(String::as_str);
+                    found.push(unknown_key_error(key, &nested_path, nested, candidates));
+                }
+            }
+        }
+        ValueKind::Array(ref items) => {
+            let items_schema = array_branches(schema)
+                .into_iter()
+                .find_map(|branch| branch.get("items"));
+
+            if let Some(items_schema) = items_schema {
+                for (index, item) in items.iter().enumerate() {
+                    let item_path = format!("{}[{}]", path, index);
+                    collect_unknown(items_schema, item, &item_path, found);
+                }
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Returns the alternatives of the given JSON Schema (flattening `anyOf`) that
+/// accept a map.
+fn object_branches(schema: &JsonValue) -> Vec<&JsonMap<String, JsonValue>> {
+    branches_of_type(schema, "object")
+}
+
+/// Returns the alternatives of the given JSON Schema (flattening `anyOf`) that
+/// accept a list.
+fn array_branches(schema: &JsonValue) -> Vec<&JsonMap<String, JsonValue>> {
+    branches_of_type(schema, "array")
+}
+
+/// Returns the alternatives of the given JSON Schema (flattening `anyOf`) that
+/// accept a value of the given JSON type. Alternatives without a declared type
+/// accept any value.
+fn branches_of_type<'a>(schema: &'a JsonValue, name: &str) -> Vec<&'a JsonMap<String, JsonValue>> {
+    let Some(schema) = schema.as_object() else {
+        return Vec::new();
+    };
+
+    if let Some(JsonValue::Array(alternatives)) = schema.get("anyOf") {
+        return alternatives
+            .iter()
+            .flat_map(|alternative| branches_of_type(alternative, name))
+            .collect();
+    }
+
+    match schema.get("type").and_then(JsonValue::as_str) {
+        Some(kind) if kind != name => Vec::new(),
+        _ => vec![schema],
+    }
+}
+
+/// Returns the fixed fields of the given object JSON Schema, if it declares
+/// any.
+fn properties_of(schema: &JsonMap<String, JsonValue>) -> Option<&JsonMap<String, JsonValue>> {
+    schema.get("properties").and_then(JsonValue::as_object)
+}
+
+/// Reports whether the given value was set via environment variables.
+fn is_from_env(value: &Value) -> bool {
+    find_origin(value).as_deref() == Some(ENV_ORIGIN)
+}
+
+/// Composes the error for the given unknown key, found at the given key path,
+/// suggesting the closest of the given known keys, if any is close enough.
+fn unknown_key_error<'a>(
+    key: &str,
+    path: &str,
+    value: &Value,
+    candidates: impl IntoIterator<Item = &'a str>,
+) -> AppConfigError {
+    let error_message = match suggest(key, candidates) {
+        Some(suggestion) => format!("unknown field `{}` (did you mean `{}`?)", key, suggestion),
+        None => format!("unknown field `{}`", key),
+    };
+
+    AppConfigError::At {
+        error_message,
+        origin: find_origin(value),
+        key: Some(path.to_string()),
+    }
+}
+
+/// Picks the known key closest to the given unknown key, unless all known keys
+/// are too far off to be a plausible typo.
+fn suggest<'a>(key: &str, candidates: impl IntoIterator<Item = &'a str>) -> Option<&'a str> {
+    candidates
+        .into_iter()
+        .map(|candidate| (Slug::distance_as_slugs(key, candidate), candidate))
+        .filter(|(distance, candidate)| {
+            let length = candidate
+                .chars()
+                .filter(|c| c.is_ascii_alphanumeric())
+                .count();
+            *distance <= (length / 3).max(2)
+        })
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, candidate)| candidate)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use config::{Environment, File, FileFormat};
+    use pretty_assertions::assert_eq;
+    use std::collections::HashMap;
+
+    fn proxy_config(input: &str) -> ProxyConfig {
+        ProxyConfig::builder()
+            .add_source(File::from_str(input, FileFormat::Yaml))
+            .add_source(
+                Environment::with_prefix("APP")
+                    .separator("_")
+                    .source(Some(HashMap::from([(
+                        "APP_RUNTIME_THREAD_KEEP_ALIVE".to_string(),
+                        "15s".to_string(),
+                    )]))),
+            )
+            .build()
+            .unwrap()
+    }
+
+    fn policies(
+        global: UnknownKeyPolicy,
+        sections: &[(&str, UnknownKeyPolicy)],
+    ) -> UnknownKeyPolicies {
+        let mut policies = UnknownKeyPolicies::new();
+        policies.global = global;
+        for (section, policy) in sections {
+            policies.set_section(section, *policy);
+        }
+
+        policies
+    }
+
+    fn rendered(unknown_keys: &[UnknownKey]) -> Vec<String> {
+        unknown_keys
+            .iter()
+            .map(|unknown_key| unknown_key.error.to_string())
+            .collect()
+    }
+
+    #[test]
+    fn find_ignored() {
+        // Given
+        let proxy_config = proxy_config("databse:\n  url: postgres://localhost\n");
+
+        // When
+        let unknown_keys =
+            UnknownKeys::find_with(&proxy_config, &policies(UnknownKeyPolicy::Ignore, &[]));
+
+        // Then
+        assert!(unknown_keys.is_empty());
+    }
+
+    #[test]
+    fn find_denied() {
+        // Given
+        let proxy_config = proxy_config(
+            "name: billing\ndatabse:\n  url: postgres://localhost\nruntime:\n  Worker-Threads: 2\n  workrs: 2\n  kind: current\nprofiles:\n  canary:\n    inherit: prod\nzzz: 1\n",
+        );
+
+        // When
+        let unknown_keys =
+            UnknownKeys::find_with(&proxy_config, &policies(UnknownKeyPolicy::Deny, &[]));
+
+        // Then
+        assert_eq!(
+            rendered(&unknown_keys),
+            vec![
+                "unknown field `databse` (did you mean `database`?) for key `databse`",
+                "unknown field `inherit` (did you mean `inherits`?) for key `profiles.canary.inherit`",
+                "unknown field `workrs` (did you mean `workers`?) for key `runtime.workrs`",
+                "unknown field `zzz` for key `zzz`",
+            ],
+        );
+        assert!(
+            unknown_keys
+                .iter()
+                .all(|unknown_key| unknown_key.policy == UnknownKeyPolicy::Deny)
+        );
+    }
+
+    #[test]
+    fn find_per_section() {
+        // Given
+        let proxy_config = proxy_config("databse: {}\nruntime:\n  workrs: 2\n");
+
+        // When
+        let unknown_keys = UnknownKeys::find_with(
+            &proxy_config,
+            &policies(
+                UnknownKeyPolicy::Ignore,
+                &[("Runtime", UnknownKeyPolicy::Warn)],
+            ),
+        );
+
+        // Then
+        assert_eq!(unknown_keys.len(), 1);
+        assert_eq!(unknown_keys[0].section, "runtime");
+        assert_eq!(unknown_keys[0].policy, UnknownKeyPolicy::Warn);
+    }
+
+    #[test]
+    fn suggest_closest() {
+        // Then
+        assert_eq!(
+            suggest("prefech_count", ["prefetch", "prefetch_count"]),
+            Some("prefetch_count"),
+        );
+        assert_eq!(
+            suggest("max-conections", ["min_connections", "max_connections"]),
+            Some("max_connections"),
+        );
+        assert_eq!(suggest("colour", ["flavor", "color"]), Some("color"));
+        assert_eq!(suggest("verbose", ["flavor", "color"]), None);
+    }
+}
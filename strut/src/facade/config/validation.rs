@@ -0,0 +1,326 @@
+use crate::facade::config::registry::ConfigRegistry;
+use crate::facade::config::unknown::UnknownKeys;
+use crate::{AppConfig, AppConfigError, UnknownKeyPolicy};
+use config::{Config as ProxyConfig, ConfigError, Map, Value, ValueKind};
+use std::fmt::{Display, Formatter};
+
+/// An internal facade for validating the **initial** application
+/// configuration eagerly, before it is seeded.
+///
+/// Validation deserializes every section known to [`AppConfig`], as well as
+/// every custom section [registered](AppConfig::register) by the
+/// application, looks for the unknown keys according to the chosen
+/// [`UnknownKeyPolicy`], and collects all errors into a single
+/// [`ConfigValidationReport`].
+pub(crate) struct ConfigValidation;
+
+impl ConfigValidation {
+    /// Validates the given [`ProxyConfig`], returning all errors at once.
+    pub(crate) fn validate(proxy_config: &ProxyConfig) -> Result<(), ConfigValidationReport> {
+        let mut failures = Vec::new();
+
+        // Validate the known sections one by one
+        match proxy_config.clone().try_deserialize::<Map<String, Value>>() {
+            Ok(table) => {
+                for (section, error) in AppConfig::validate_sections(table) {
+                    failures.push(ConfigValidationFailure::at(
+                        proxy_config,
+                        Some(&section),
+                        error,
+                    ));
+                }
+            }
+            Err(error) => failures.push(ConfigValidationFailure::at(proxy_config, None, error)),
+        }
+
+        // Validate the registered custom sections
+        if let Err(errors) = ConfigRegistry::load(proxy_config) {
+            for (section, error) in errors {
+                failures.push(ConfigValidationFailure::at(
+                    proxy_config,
+                    Some(&section),
+                    error,
+                ));
+            }
+        }
+
+        // Look for the unknown keys, as requested by the application
+        for unknown_key in UnknownKeys::find(proxy_config) {
+            match unknown_key.policy {
+                UnknownKeyPolicy::Deny => failures.push(ConfigValidationFailure {
+                    section: Some(unknown_key.section),
+                    error: unknown_key.error,
+                }),
+                _ => {
+                    #[cfg(feature = "tracing")]
+                    tracing::warn!(
+                        alert = true,
+                        section = %unknown_key.section,
+                        error = %unknown_key.error,
+                        "Unknown configuration key",
+                    );
+
+                    #[cfg(not(feature = "tracing"))]
+                    eprintln!("warning: {}", unknown_key.error);
+                }
+            }
+        }
+
+        // As a last resort, validate the whole configuration
+        if failures.is_empty() {
+            if let Err(error) = proxy_config.clone().try_deserialize::<AppConfig>() {
+                failures.push(ConfigValidationFailure::at(proxy_config, None, error));
+            }
+        }
+
+        match failures.is_empty() {
+            true => Ok(()),
+            false => Err(ConfigValidationReport { failures }),
+        }
+    }
+}
+
+/// Collects all errors found while validating the initial application
+/// configuration.
+///
+/// The [`Display`] implementation renders a readable summary, with one line
+/// per error.
+#[derive(Debug, Clone)]
+pub struct ConfigValidationReport {
+    failures: Vec<ConfigValidationFailure>,
+}
+
+/// Describes a single error found while validating the initial application
+/// configuration.
+#[derive(Debug, Clone)]
+pub struct ConfigValidationFailure {
+    section: Option<String>,
+    error: AppConfigError,
+}
+
+impl ConfigValidationReport {
+    /// Creates a report with the single given error that is not attributed to
+    /// any particular section (e.g., a failure to parse a config file).
+    pub(crate) fn from_error(error: ConfigError) -> Self {
+        Self {
+            failures: vec![ConfigValidationFailure {
+                section: None,
+                error: AppConfigError::from(error),
+            }],
+        }
+    }
+
+    /// Returns the errors found during validation.
+    pub fn failures(&self) -> &[ConfigValidationFailure] {
+        &self.failures
+    }
+}
+
+impl ConfigValidationFailure {
+    /// Creates a failure for the given error, found in the given section of
+    /// the given [`ProxyConfig`].
+    fn at(proxy_config: &ProxyConfig, section: Option<&str>, error: ConfigError) -> Self {
+        let error = match section {
+            Some(section) => prepend_key(section, AppConfigError::from(error)),
+            None => AppConfigError::from(error),
+        };
+        let error = append_origin(proxy_config, error);
+
+        Self {
+            section: section.map(ToString::to_string),
+            error,
+        }
+    }
+
+    /// Returns the top-level section where the error was found, if the error
+    /// is attributed to a particular section.
+    pub fn section(&self) -> Option<&str> {
+        self.section.as_deref()
+    }
+
+    /// Returns the error, with the key path (if any) starting from the root of
+    /// the configuration.
+    pub fn error(&self) -> &AppConfigError {
+        &self.error
+    }
+}
+
+/// Prepends the given section name to the key path of the given error, so that
+/// the key path starts from the root of the configuration.
+fn prepend_key(section: &str, error: AppConfigError) -> AppConfigError {
+    let full_key = |key: Option<String>| match key {
+        Some(key) if key == section || key.starts_with(&format!("{}.", section)) => Some(key),
+        Some(key) if key.starts_with('[') => Some(format!("{}{}", section, key)),
+        Some(key) => Some(format!("{}.{}", section, key)),
+        None => Some(section.to_string()),
+    };
+
+    match error {
+        AppConfigError::Type {
+            origin,
+            unexpected_content,
+            expected,
+            key,
+        } => AppConfigError::Type {
+            origin,
+            unexpected_content,
+            expected,
+            key: full_key(key),
+        },
+        AppConfigError::At {
+            error_message,
+            origin,
+            key,
+        } => AppConfigError::At {
+            error_message,
+            origin,
+            key: full_key(key),
+        },
+        AppConfigError::Message(error_message) => AppConfigError::At {
+            error_message,
+            origin: None,
+            key: full_key(None),
+        },
+        other => other,
+    }
+}
+
+/// Fills in the origin (typically, the source file) of the given error from
+/// the value found at the error’s key path in the given [`ProxyConfig`], unless
+/// the error already knows its origin.
+fn append_origin(proxy_config: &ProxyConfig, error: AppConfigError) -> AppConfigError {
+    let origin_of = |key: &Option<String>| {
+        let value = key.as_deref()?.split('.').try_fold(
+            &proxy_config.cache,
+            |value, segment| match value.kind {
+                // Array indices are not followed: the origin of the array will do
+                ValueKind::Table(ref table) => table.get(segment.split('[').next()?),
+                _ => None,
+            },
+        )?;
+        find_origin(value)
+    };
+
+    match error {
+        AppConfigError::Type {
+            origin: None,
+            unexpected_content,
+            expected,
+            key,
+        } => AppConfigError::Type {
+            origin: origin_of(&key),
+            unexpected_content,
+            expected,
+            key,
+        },
+        AppConfigError::At {
+            error_message,
+            origin: None,
+            key,
+        } => AppConfigError::At {
+            error_message,
+            origin: origin_of(&key),
+            key,
+        },
+        other => other,
+    }
+}
+
+/// Finds the origin of the given value, looking into the nested values (in the
+/// order of keys) if the value is a table that does not know its own origin.
+pub(crate) fn find_origin(value: &Value) -> Option<String> {
+    if let Some(origin) = value.origin() {
+        return Some(origin.to_string());
+    }
+
+    let ValueKind::Table(ref table) = value.kind else {
+        return None;
+    };
+
+    let mut keys = table.keys().collect::<Vec<_>>();
+    keys.sort();
+
+    keys.into_iter().find_map(|key| find_origin(&table[key]))
+}
+
+impl Display for ConfigValidationReport {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let count = self.failures.len();
+
+        writeln!(
+            f,
+            "The application configuration is invalid ({} {}):",
+            count,
+            if count == 1 { "error" } else { "errors" },
+        )?;
+
+        for failure in &self.failures {
+            writeln!(f, "  - {}", failure.error)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl std::error::Error for ConfigValidationReport {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use config::{File, FileFormat};
+    use pretty_assertions::assert_eq;
+
+    #[derive(Debug, Default, serde::Deserialize)]
+    #[allow(dead_code)]
+    struct Billing {
+        currency: String,
+    }
+
+    fn proxy_config(input: &str) -> ProxyConfig {
+        ProxyConfig::builder()
+            .add_source(File::from_str(input, FileFormat::Yaml))
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn validate_valid() {
+        // Given
+        let proxy_config = proxy_config("name: billing\nruntime:\n  workers: 2\n");
+
+        // When
+        let result = ConfigValidation::validate(&proxy_config);
+
+        // Then
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn validate_aggregated() {
+        // Given
+        ConfigRegistry::register::<Billing>("billing");
+        let proxy_config =
+            proxy_config("name: [billing]\nruntime:\n  workers: 0\nbilling:\n  amount: 3\n");
+
+        // When
+        let report = ConfigValidation::validate(&proxy_config).unwrap_err();
+
+        // Then
+        let sections = report
+            .failures()
+            .iter()
+            .map(ConfigValidationFailure::section)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            sections,
+            vec![Some("name"), Some("runtime"), Some("billing")]
+        );
+
+        let rendered = report.to_string();
+        assert!(rendered.starts_with("The application configuration is invalid (3 errors):\n"));
+        assert!(
+            rendered.contains("the number of worker threads must be positive for key `runtime`")
+        );
+        assert!(rendered.contains("missing configuration field \"currency\" for key `billing`"));
+    }
+}
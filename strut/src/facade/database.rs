@@ -14,7 +14,7 @@ use {
     parking_lot::Mutex as SyncMutex,
     std::collections::HashMap,
     std::sync::OnceLock,
-    strut_database::{sqlx::Pool, Connector},
+    strut_database::{Connector, sqlx::Pool},
 };
 
 /// Provides access to globally shared database connection pools.
@@ -1,20 +1,70 @@
+use parking_lot::Mutex;
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::path::{Path, PathBuf};
 use std::sync::Once;
-use strut_core::Pivot;
+use strut_config::EnvOverlay;
+use strut_core::{AppProfile, Pivot};
 
 const FILE_DOT_ENV_LOCAL: &str = ".env.local";
 const FILE_DOT_ENV_GLOBAL: &str = ".env";
+const ENV_PROFILE: &str = "APP_PROFILE";
+
+// The dot-env files that loaded each environment variable
+static ORIGINS: Mutex<Option<HashMap<String, PathBuf>>> = Mutex::new(None);
+
+// The dot-env files that were loaded most recently
+static REPORT: Mutex<Option<DotEnvReport>> = Mutex::new(None);
+
+// Guards the one-time loading of the dot-env files
+static TAPPED: Once = Once::new();
+
+/// The variables read from the dot-env files, with the file each comes from.
+type Variables = HashMap<String, (String, PathBuf)>;
 
 /// A facade for loading environment variables from `.env` files.
 ///
-/// Strut supports loading variables from `.env.local` and `.env` files located
-/// in the application's [pivot directory][pivot].
+/// Strut supports loading variables from the following files located in the
+/// application's [pivot directory][pivot], in order of precedence:
+///
+/// 1. `.env.{profile}.local`
+/// 2. `.env.local`
+/// 3. `.env.{profile}`
+/// 4. `.env`
+///
+/// The profile-specific files are loaded for every
+/// [active profile](AppProfile::active_layers), with later profiles taking
+/// precedence over earlier ones (and over the profiles they inherit from).
+/// The `APP_PROFILE` variable itself may be set in `.env.local` or `.env`.
+///
+/// The values may refer to other variables as `${NAME}`. A reference resolves
+/// against the environment of the process first, and then against the
+/// variables of the same file and of the files with lower precedence.
 ///
 /// Use [`tap`] for a safe, one-time load operation, or [`load`] to perform the
-/// operation directly.
+/// operation directly. The [strict](DotEnv::try_load) variants fail on
+/// malformed files instead of skipping the malformed lines.
 ///
 /// [pivot]: Pivot
+/// [`tap`]: DotEnv::tap
+/// [`load`]: DotEnv::load
 pub struct DotEnv;
 
+/// Lists the dot-env files that were loaded, in order of precedence, along
+/// with the number of environment variables that each of them has set.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DotEnvReport {
+    loaded: Vec<(PathBuf, usize)>,
+}
+
+/// Represents the failure to [strictly](DotEnv::try_load) load a dot-env file.
+#[derive(Debug)]
+pub struct DotEnvError {
+    path: PathBuf,
+    error: dotenvy::Error,
+}
+
 impl DotEnv {
     /// Ensures environment variables from dot-env files are loaded.
     ///
@@ -27,9 +77,20 @@ impl DotEnv {
     ///
     /// [`load`]: DotEnv::load
     pub fn tap() {
-        static INIT: Once = Once::new();
+        TAPPED.call_once(Self::load);
+    }
 
-        INIT.call_once(Self::load);
+    /// Same as [`tap`](DotEnv::tap), but loads the files
+    /// [strictly](DotEnv::try_load) on the first invocation.
+    ///
+    /// Only the first invocation of either method has any effect: if the files
+    /// were already tapped, this method always succeeds.
+    pub fn try_tap() -> Result<(), DotEnvError> {
+        let mut result = Ok(());
+
+        TAPPED.call_once(|| result = Self::try_load());
+
+        result
     }
 
     /// Loads environment variables from dot-env files into the environment.
@@ -38,26 +99,292 @@ impl DotEnv {
     /// set. It only loads values for variables that are not currently present in
     /// the process's environment.
     ///
-    /// ## Precedence
+    /// The files are loaded in the [order of precedence](DotEnv). If a file is
+    /// not found, it is silently ignored. Unreadable files and malformed lines
+    /// are skipped.
+    pub fn load() {
+        // Lenient loading never fails
+        let _ = Self::load_files(false, false);
+    }
+
+    /// Same as [`load`](DotEnv::load), but fails on the first unreadable file
+    /// or malformed line, in which case no variables are loaded (except for
+    /// `APP_PROFILE`, if it is set in a generic file, as it is needed to find
+    /// the profile-specific files).
+    pub fn try_load() -> Result<(), DotEnvError> {
+        Self::load_files(true, false)
+    }
+
+    /// Returns the paths of the dot-env files, in [order of precedence](DotEnv),
+    /// whether or not they exist.
     ///
-    /// The files are loaded in the following order, with variables from earlier
-    /// files taking precedence:
+    /// The profile-specific files depend on the
+    /// [active profiles](AppProfile::active_layers).
+    pub fn files() -> Vec<PathBuf> {
+        let pivot = Pivot::resolve();
+        let layers = AppProfile::active_layers();
+
+        let specific = |suffix: &str| {
+            layers
+                .iter()
+                .rev()
+                .map(|profile| pivot.join(format!("{}.{}{}", FILE_DOT_ENV_GLOBAL, profile, suffix)))
+                .collect::<Vec<_>>()
+        };
+
+        let mut files = specific(".local");
+        files.push(pivot.join(FILE_DOT_ENV_LOCAL));
+        files.extend(specific(""));
+        files.push(pivot.join(FILE_DOT_ENV_GLOBAL));
+
+        files
+    }
+
+    /// Re-reads the dot-env files after they have changed, updating the
+    /// [`EnvOverlay`] accordingly.
     ///
-    /// 1. `.env.local`
-    /// 2. `.env`
+    /// Unlike [`load`], this method overrides the environment variables that
+    /// were previously loaded from dot-env files, and hides those that are no
+    /// longer present in any of the files. The environment variables that were
+    /// set by other means are never overridden. The precedence of files is
+    /// the same as for [`load`], except that `APP_PROFILE` is not chosen
+    /// again.
     ///
-    /// Both files are searched for in the application's [pivot directory][pivot].
-    /// If a file is not found, it is silently ignored.
+    /// As this may happen while other threads read the environment, the
+    /// environment of the process is never changed: the reloaded variables
+    /// are only visible through the [`EnvOverlay`] (and thus to the
+    /// configuration).
     ///
-    /// [pivot]: Pivot
-    pub fn load() {
+    /// [`load`]: DotEnv::load
+    pub fn reload() {
+        let _ = Self::load_files(false, true);
+    }
+
+    /// Returns the path of the dot-env file that the environment variable with
+    /// the given name was [loaded](DotEnv::load) from, if any.
+    pub fn origin(name: impl AsRef<str>) -> Option<PathBuf> {
+        ORIGINS
+            .lock()
+            .as_ref()
+            .and_then(|origins| origins.get(name.as_ref()))
+            .cloned()
+    }
+
+    /// Returns the [`DotEnvReport`] of the most recent loading of the dot-env
+    /// files, which is empty if the files were never loaded.
+    pub fn report() -> DotEnvReport {
+        REPORT.lock().clone().unwrap_or_default()
+    }
+
+    /// Reads all dot-env files and applies their variables to the environment,
+    /// or to the [`EnvOverlay`] when reloading.
+    fn load_files(strict: bool, reloading: bool) -> Result<(), DotEnvError> {
+        let pivot = Pivot::resolve();
+        let generic = [
+            pivot.join(FILE_DOT_ENV_GLOBAL),
+            pivot.join(FILE_DOT_ENV_LOCAL),
+        ];
+
+        // The generic files may choose the profile, before it is first accessed
+        let generic_variables = Self::read_files(&generic, strict)?;
+        if !reloading && std::env::var_os(ENV_PROFILE).is_none() {
+            if let Some((profile, path)) = generic_variables.get(ENV_PROFILE) {
+                // SAFETY: the initial loading happens during start-up, before the
+                // runtime spawns any threads that might read the environment
+                unsafe { std::env::set_var(ENV_PROFILE, profile) };
+                ORIGINS
+                    .lock()
+                    .get_or_insert_with(HashMap::new)
+                    .insert(ENV_PROFILE.to_string(), path.clone());
+            }
+        }
+
+        // Read the files from the lowest precedence to the highest
+        let mut files = Self::files();
+        files.reverse();
+        let variables = Self::read_files(&files, strict)?;
+
+        Self::apply(variables, &files, reloading);
+
+        Ok(())
+    }
+
+    /// Reads the given dot-env files, from the lowest precedence to the
+    /// highest, into a single set of variables. Missing files are ignored.
+    ///
+    /// The references in the values of each file are expanded against the
+    /// variables of the files read before it.
+    fn read_files(files: &[PathBuf], strict: bool) -> Result<Variables, DotEnvError> {
+        let mut variables = Variables::new();
+
+        for path in files {
+            let content = match std::fs::read_to_string(path) {
+                Ok(content) => content,
+                Err(error) if error.kind() == std::io::ErrorKind::NotFound => continue,
+                Err(error) if strict => {
+                    return Err(DotEnvError {
+                        path: path.clone(),
+                        error: dotenvy::Error::Io(error),
+                    });
+                }
+                Err(_) => continue,
+            };
+
+            // Prepend the known variables so that the references can expand to them
+            let prelude = variables
+                .iter()
+                .map(|(name, (value, _))| format!("{}=\"{}\"\n", name, escape(value)))
+                .collect::<String>();
+            let input = prelude + &content;
+
+            for item in dotenvy::from_read_iter(input.as_bytes()).skip(variables.len()) {
+                match item {
+                    Ok((name, value)) => {
+                        variables.insert(name, (value, path.clone()));
+                    }
+                    Err(error) if strict => {
+                        return Err(DotEnvError {
+                            path: path.clone(),
+                            error,
+                        });
+                    }
+                    Err(_) => {}
+                }
+            }
+        }
+
+        Ok(variables)
+    }
+
+    /// Applies the given variables to the environment (or, when reloading, to
+    /// the [`EnvOverlay`]), overriding the variables previously loaded from
+    /// dot-env files and removing those that are gone, but never touching the
+    /// variables set by other means. Records the [`DotEnvReport`] for the
+    /// given files.
+    fn apply(variables: Variables, files: &[PathBuf], reloading: bool) {
+        let mut origins = ORIGINS.lock();
+        let origins = origins.get_or_insert_with(HashMap::new);
+
+        // Forget the variables that are gone from the files
+        origins.retain(|name, _| {
+            if variables.contains_key(name) {
+                return true;
+            }
+
+            match reloading {
+                true => EnvOverlay::set(name.as_str(), None),
+                // SAFETY: the initial loading happens during start-up, before the
+                // runtime spawns any threads that might read the environment
+                false => unsafe { std::env::remove_var(name) },
+            }
+
+            false
+        });
+
+        // Apply the variables that are loaded from the files, or are not set
+        for (name, (value, path)) in variables {
+            if !origins.contains_key(&name) && EnvOverlay::is_set(&name) {
+                continue;
+            }
+
+            match reloading {
+                true => EnvOverlay::set(name.as_str(), Some(value)),
+                // SAFETY: the initial loading happens during start-up, before the
+                // runtime spawns any threads that might read the environment
+                false => unsafe { std::env::set_var(&name, value) },
+            }
+
+            origins.insert(name, path);
+        }
+
+        // Report the existing files in order of precedence
+        let loaded = files
+            .iter()
+            .rev()
+            .filter(|path| path.is_file())
+            .map(|path| {
+                let count = origins.values().filter(|origin| *origin == path).count();
+                (path.clone(), count)
+            })
+            .collect();
+
+        *REPORT.lock() = Some(DotEnvReport { loaded });
+    }
+}
+
+/// Escapes the given value for a double-quoted dot-env value.
+fn escape(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+
+    for c in value.chars() {
+        match c {
+            '\\' | '"' | '$' => {
+                escaped.push('\\');
+                escaped.push(c);
+            }
+            '\n' => escaped.push_str("\\n"),
+            _ => escaped.push(c),
+        }
+    }
+
+    escaped
+}
+
+impl DotEnvReport {
+    /// Returns the loaded dot-env files, in order of precedence, along with the
+    /// number of environment variables that each of them has set.
+    pub fn loaded(&self) -> &[(PathBuf, usize)] {
+        &self.loaded
+    }
+
+    /// Reports whether no dot-env files were loaded.
+    pub fn is_empty(&self) -> bool {
+        self.loaded.is_empty()
+    }
+}
+
+impl Display for DotEnvReport {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if self.loaded.is_empty() {
+            return f.write_str("no dot-env files");
+        }
+
         let pivot = Pivot::resolve();
 
-        // Load local file (first priority)
-        let _ = dotenvy::from_path(pivot.join(FILE_DOT_ENV_LOCAL));
+        for (index, (path, count)) in self.loaded.iter().enumerate() {
+            if index > 0 {
+                f.write_str(", ")?;
+            }
+
+            let path = path.strip_prefix(&pivot).unwrap_or(path);
+            write!(f, "{} ({} variables)", path.display(), count)?;
+        }
 
-        // Load global file (second priority)
-        let _ = dotenvy::from_path(pivot.join(FILE_DOT_ENV_GLOBAL));
+        Ok(())
+    }
+}
+
+impl DotEnvError {
+    /// Returns the path of the dot-env file that failed to load.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+}
+
+impl Display for DotEnvError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "failed to load the dot-env file `{}`: {}",
+            self.path.display(),
+            self.error,
+        )
+    }
+}
+
+impl Error for DotEnvError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&self.error)
     }
 }
 
@@ -67,7 +394,7 @@ mod tests {
     use pretty_assertions::assert_eq;
     use scopeguard::defer;
     use std::env::remove_var;
-    use std::fs::{remove_file, File};
+    use std::fs::{File, remove_file};
     use std::io::Write;
 
     const TEST_VARIABLE_ENV_LOC_GLO: &str = "TEST_VARIABLE_ENV_LOC_GLO";
@@ -119,6 +446,12 @@ mod tests {
         assert(TEST_VARIABLE_________GLO, "glo");
         assert(TEST_VARIABLE____________, "");
 
+        // Check the origins of the loaded variables
+        assert_origin(TEST_VARIABLE_ENV_LOC_GLO, None);
+        assert_origin(TEST_VARIABLE_____LOC_GLO, Some(FILE_DOT_ENV_LOCAL));
+        assert_origin(TEST_VARIABLE_________GLO, Some(FILE_DOT_ENV_GLOBAL));
+        assert_origin(TEST_VARIABLE____________, None);
+
         // Tap the dot-env files again (should have no additional effect)
         DotEnv::tap();
 
@@ -154,6 +487,94 @@ mod tests {
         assert(TEST_VARIABLE_____LOC____, "loc");
         assert(TEST_VARIABLE_________GLO, "glo");
         assert(TEST_VARIABLE____________, "");
+
+        // Reload the dot-env files (should override only the loaded variables)
+        DotEnv::reload();
+
+        // Check that the environment of the process is left intact
+        assert_eq!(std::env::var(TEST_VARIABLE_____LOC_GLO).unwrap(), "loc");
+
+        // Check values in updated environment
+        assert(TEST_VARIABLE_ENV_LOC_GLO, "new_env");
+        assert(TEST_VARIABLE_ENV_LOC____, "new_env");
+        assert(TEST_VARIABLE_ENV_____GLO, "new_env");
+        assert(TEST_VARIABLE_ENV________, "new_env");
+        assert(TEST_VARIABLE_____LOC_GLO, "new_loc");
+        assert(TEST_VARIABLE_____LOC____, "new_loc");
+        assert(TEST_VARIABLE_________GLO, "new_glo");
+        assert(TEST_VARIABLE____________, "");
+
+        // Remove the dot-env files and reload (should remove the loaded variables)
+        clean_up_files();
+        DotEnv::reload();
+
+        // Check values in updated environment
+        assert(TEST_VARIABLE_ENV_LOC_GLO, "new_env");
+        assert(TEST_VARIABLE_ENV_LOC____, "new_env");
+        assert(TEST_VARIABLE_ENV_____GLO, "new_env");
+        assert(TEST_VARIABLE_ENV________, "new_env");
+        assert(TEST_VARIABLE_____LOC_GLO, "");
+        assert(TEST_VARIABLE_____LOC____, "");
+        assert(TEST_VARIABLE_________GLO, "");
+        assert(TEST_VARIABLE____________, "");
+        assert_origin(TEST_VARIABLE_____LOC_GLO, None);
+    }
+
+    #[test]
+    fn read_files_layered() {
+        // Given
+        let dir = std::env::temp_dir().join("strut_dotenv_layered");
+        std::fs::create_dir_all(&dir).unwrap();
+        let files =
+            [".env", ".env.prod", ".env.local", ".env.prod.local"].map(|name| dir.join(name));
+        std::fs::write(
+            &files[0],
+            "DB_HOST=localhost\nDB_PORT=5432\nNOTE=\"costs \\$5\"\n",
+        )
+        .unwrap();
+        std::fs::write(&files[1], "DB_HOST=db.internal\n").unwrap();
+        std::fs::write(
+            &files[3],
+            "DB_URL=postgres://${DB_HOST}:${DB_PORT}/app\nCOPY=${NOTE}\n",
+        )
+        .unwrap();
+
+        // When
+        let variables = DotEnv::read_files(&files, true).unwrap();
+
+        // Then
+        std::fs::remove_dir_all(&dir).unwrap();
+        let value = |name: &str| variables.get(name).map(|(value, _)| value.as_str());
+        assert_eq!(value("DB_HOST"), Some("db.internal"));
+        assert_eq!(value("DB_URL"), Some("postgres://db.internal:5432/app"));
+        assert_eq!(value("COPY"), Some("costs $5"));
+        assert_eq!(variables.len(), 5);
+        assert_eq!(variables["DB_URL"].1, files[3]);
+    }
+
+    #[test]
+    fn read_files_malformed() {
+        // Given
+        let dir = std::env::temp_dir().join("strut_dotenv_malformed");
+        std::fs::create_dir_all(&dir).unwrap();
+        let file = dir.join(".env");
+        std::fs::write(&file, "GOOD=1\nBAD=two words\nALSO_GOOD=2\n").unwrap();
+
+        // When
+        let lenient = DotEnv::read_files(std::slice::from_ref(&file), false).unwrap();
+        let strict = DotEnv::read_files(std::slice::from_ref(&file), true).unwrap_err();
+
+        // Then
+        std::fs::remove_dir_all(&dir).unwrap();
+        let mut names = lenient.keys().map(String::as_str).collect::<Vec<_>>();
+        names.sort();
+        assert_eq!(names, ["ALSO_GOOD", "GOOD"]);
+        assert_eq!(strict.path(), file);
+        assert!(
+            strict
+                .to_string()
+                .starts_with("failed to load the dot-env file `")
+        );
     }
 
     fn create_dotenv_files(local_value: &str, global_value: &str) {
@@ -242,7 +663,7 @@ mod tests {
     }
 
     fn assert(name: &str, expected: &str) {
-        let actual = std::env::var(name).unwrap_or_else(|_| "".to_string());
+        let actual = EnvOverlay::var(name).unwrap_or_default();
 
         assert_eq!(
             expected, &actual,
@@ -250,4 +671,14 @@ mod tests {
             name, expected, &actual,
         );
     }
+
+    fn assert_origin(name: &str, expected: Option<&str>) {
+        let actual = DotEnv::origin(name);
+        let actual = actual
+            .as_deref()
+            .and_then(Path::file_name)
+            .and_then(std::ffi::OsStr::to_str);
+
+        assert_eq!(expected, actual);
+    }
 }
@@ -0,0 +1,76 @@
+use parking_lot::Mutex as SyncMutex;
+use std::sync::Arc;
+use strut_core::AppContext;
+use strut_sync::Gate;
+
+pub mod config;
+pub mod server;
+
+/// The globally registered readiness gates.
+static GATES: SyncMutex<Vec<(Arc<str>, Gate)>> = SyncMutex::new(Vec::new());
+
+/// A facade for reporting the application’s health to external observers, such
+/// as the liveness and readiness probes of container orchestrators.
+///
+/// ## Liveness
+///
+/// The application is **alive** for as long as the global [`AppContext`] is
+/// [alive](AppContext::is_alive).
+///
+/// ## Readiness
+///
+/// The application is **ready** when it is alive and every
+/// [required](AppHealth::require) [`Gate`] is [open](Gate::is_open). As soon as
+/// the global [`AppContext`] is [terminated](AppContext::terminate), the
+/// application stops being ready, regardless of its gates.
+///
+/// ## Example
+///
+/// ```
+/// use strut::{AppHealth, Latch};
+///
+/// // Some resource that becomes usable at a later point
+/// let latch = Latch::new();
+///
+/// // Require it for readiness
+/// AppHealth::require("some_resource", latch.gate());
+/// assert!(!AppHealth::is_ready());
+///
+/// // Now the resource is usable
+/// latch.release();
+/// assert!(AppHealth::is_ready());
+/// ```
+pub struct AppHealth;
+
+impl AppHealth {
+    /// Registers the given [`Gate`] under the given human-readable `name`
+    /// (which needs not to be unique). The application will not be reported as
+    /// [ready](AppHealth::is_ready) until the gate is [open](Gate::is_open).
+    ///
+    /// A good example of such a gate is the one returned from
+    /// `MigrationsWorker::start` in the database integration.
+    pub fn require(name: impl AsRef<str>, gate: Gate) {
+        GATES.lock().push((Arc::from(name.as_ref()), gate));
+    }
+
+    /// Reports whether the application is alive as of this moment.
+    pub fn is_alive() -> bool {
+        AppContext::is_alive()
+    }
+
+    /// Reports whether the application is ready as of this moment.
+    pub fn is_ready() -> bool {
+        Self::is_alive() && GATES.lock().iter().all(|(_, gate)| gate.is_open())
+    }
+
+    /// Returns the names of all [required](AppHealth::require) gates that are
+    /// not yet open as of this moment.
+    pub fn pending() -> Vec<Arc<str>> {
+        GATES
+            .lock()
+            .iter()
+            .filter(|(_, gate)| !gate.is_open())
+            .map(|(name, _)| Arc::clone(name))
+            .collect()
+    }
+}
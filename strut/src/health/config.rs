@@ -0,0 +1,232 @@
+use serde::de::{MapAccess, Visitor};
+use serde::{Deserialize, Deserializer};
+use std::fmt::Formatter;
+use std::sync::Arc;
+use strut_deserialize::{ConfigSchema, Schema};
+use strut_factory::impl_deserialize_field;
+
+/// Represents the application-level configuration section that covers the
+/// built-in health subsystem: a tiny HTTP listener that answers liveness and
+/// readiness probes of container orchestrators.
+///
+/// The listener is disabled by default. Once enabled, it binds all interfaces
+/// by default, so that the orchestrator can reach it; the probes carry no
+/// authentication, so choose the [host](HealthConfig::host) and the
+/// [port](HealthConfig::port) accordingly.
+///
+/// This config comes with a custom [`Deserialize`] implementation, to support
+/// more human-oriented textual configuration.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HealthConfig {
+    enabled: bool,
+    host: Arc<str>,
+    port: u16,
+    liveness_path: Arc<str>,
+    readiness_path: Arc<str>,
+}
+
+impl HealthConfig {
+    /// Reports whether the health subsystem should be started at all.
+    pub fn enabled(&self) -> bool {
+        self.enabled
+    }
+
+    /// Returns the host (interface address) on which the health listener
+    /// binds.
+    pub fn host(&self) -> &str {
+        &self.host
+    }
+
+    /// Returns the TCP port on which the health listener binds.
+    pub fn port(&self) -> u16 {
+        self.port
+    }
+
+    /// Returns the HTTP path that reports the application’s liveness.
+    pub fn liveness_path(&self) -> &str {
+        &self.liveness_path
+    }
+
+    /// Returns the HTTP path that reports the application’s readiness.
+    pub fn readiness_path(&self) -> &str {
+        &self.readiness_path
+    }
+}
+
+impl Default for HealthConfig {
+    fn default() -> Self {
+        Self {
+            enabled: Self::default_enabled(),
+            host: Arc::from(Self::default_host()),
+            port: Self::default_port(),
+            liveness_path: Arc::from(Self::default_liveness_path()),
+            readiness_path: Arc::from(Self::default_readiness_path()),
+        }
+    }
+}
+
+impl HealthConfig {
+    fn default_enabled() -> bool {
+        false
+    }
+
+    fn default_host() -> &'static str {
+        "0.0.0.0"
+    }
+
+    fn default_port() -> u16 {
+        8081
+    }
+
+    fn default_liveness_path() -> &'static str {
+        "/health/live"
+    }
+
+    fn default_readiness_path() -> &'static str {
+        "/health/ready"
+    }
+}
+
+impl AsRef<HealthConfig> for HealthConfig {
+    fn as_ref(&self) -> &HealthConfig {
+        self
+    }
+}
+
+const _: () = {
+    impl<'de> Deserialize<'de> for HealthConfig {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            deserializer.deserialize_map(HealthConfigVisitor)
+        }
+    }
+
+    struct HealthConfigVisitor;
+
+    impl<'de> Visitor<'de> for HealthConfigVisitor {
+        type Value = HealthConfig;
+
+        fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
+            formatter.write_str("a map of health subsystem configuration")
+        }
+
+        fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+        where
+            A: MapAccess<'de>,
+        {
+            let mut enabled = None;
+            let mut host: Option<String> = None;
+            let mut port = None;
+            let mut liveness_path: Option<String> = None;
+            let mut readiness_path: Option<String> = None;
+
+            while let Some(key) = map.next_key()? {
+                match key {
+                    HealthConfigField::enabled => key.poll(&mut map, &mut enabled)?,
+                    HealthConfigField::host => key.poll(&mut map, &mut host)?,
+                    HealthConfigField::port => key.poll(&mut map, &mut port)?,
+                    HealthConfigField::liveness_path => key.poll(&mut map, &mut liveness_path)?,
+                    HealthConfigField::readiness_path => key.poll(&mut map, &mut readiness_path)?,
+                    HealthConfigField::__ignore => map.next_value()?,
+                };
+            }
+
+            Ok(HealthConfig {
+                enabled: enabled.unwrap_or_else(HealthConfig::default_enabled),
+                host: host
+                    .map(Arc::from)
+                    .unwrap_or_else(|| Arc::from(HealthConfig::default_host())),
+                port: port.unwrap_or_else(HealthConfig::default_port),
+                liveness_path: liveness_path
+                    .map(Arc::from)
+                    .unwrap_or_else(|| Arc::from(HealthConfig::default_liveness_path())),
+                readiness_path: readiness_path
+                    .map(Arc::from)
+                    .unwrap_or_else(|| Arc::from(HealthConfig::default_readiness_path())),
+            })
+        }
+    }
+
+    impl_deserialize_field!(
+        HealthConfigField,
+        strut_deserialize::Slug::eq_as_slugs,
+        enabled | enable,
+        host | address | bind,
+        port,
+        liveness_path | liveness | live,
+        readiness_path | readiness | ready,
+    );
+
+    impl ConfigSchema for HealthConfig {
+        fn config_schema() -> Schema {
+            Schema::object()
+                .with_description("The health subsystem configuration.")
+                .with_field(
+                    HealthConfigField::enabled.keys(),
+                    bool::config_schema().with_default(HealthConfig::default_enabled()),
+                )
+                .with_field(
+                    HealthConfigField::host.keys(),
+                    String::config_schema().with_default(HealthConfig::default_host()),
+                )
+                .with_field(
+                    HealthConfigField::port.keys(),
+                    u16::config_schema().with_default(HealthConfig::default_port()),
+                )
+                .with_field(
+                    HealthConfigField::liveness_path.keys(),
+                    String::config_schema().with_default(HealthConfig::default_liveness_path()),
+                )
+                .with_field(
+                    HealthConfigField::readiness_path.keys(),
+                    String::config_schema().with_default(HealthConfig::default_readiness_path()),
+                )
+        }
+    }
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn from_empty() {
+        // Given
+        let input = "{}";
+        let expected_output = HealthConfig::default();
+
+        // When
+        let actual_output = serde_yml::from_str::<HealthConfig>(input).unwrap();
+
+        // Then
+        assert_eq!(expected_output, actual_output);
+    }
+
+    #[test]
+    fn from_map_full() {
+        // Given
+        let input = r#"
+enabled: true
+address: 127.0.0.1
+port: 9000
+Liveness: /livez
+READY: /readyz
+"#;
+        let expected_output = HealthConfig {
+            enabled: true,
+            host: Arc::from("127.0.0.1"),
+            port: 9000,
+            liveness_path: Arc::from("/livez"),
+            readiness_path: Arc::from("/readyz"),
+        };
+
+        // When
+        let actual_output = serde_yml::from_str::<HealthConfig>(input).unwrap();
+
+        // Then
+        assert_eq!(expected_output, actual_output);
+    }
+}
@@ -0,0 +1,245 @@
+use crate::{AppHealth, HealthConfig};
+use std::time::Duration;
+use strut_core::{AppContext, AppSpindown, AppSpindownToken};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+use tokio::runtime::Runtime;
+use tokio::select;
+use tokio::task::JoinSet;
+
+/// How long a single probe connection may take to send its request line.
+const READ_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// How many bytes of a probe request are inspected at most.
+const MAX_REQUEST_LEN: usize = 1024;
+
+/// Runs in the background and answers the liveness and readiness probes over a
+/// minimal HTTP/1.1 listener, as defined in the given [`HealthConfig`].
+///
+/// This server is integrated with [`AppSpindown`]: once the global
+/// [`AppContext`] is terminated, this server stops accepting connections,
+/// waits for the probes that are still being answered, and punches out.
+pub(crate) struct HealthServer {
+    /// The configuration of this server.
+    config: &'static HealthConfig,
+    /// The canary token, which (once it goes out of scope) will inform the
+    /// application that this server gracefully completed.
+    _spindown_token: AppSpindownToken,
+}
+
+impl HealthServer {
+    /// Sends a new [`HealthServer`] into background on the given [`Runtime`],
+    /// unless the given [`HealthConfig`] disables it.
+    pub(crate) fn start(config: &'static HealthConfig, runtime: &Runtime) {
+        if !config.enabled() {
+            return;
+        }
+
+        let _spindown_token = AppSpindown::register("health:server");
+
+        let server = Self {
+            config,
+            _spindown_token,
+        };
+
+        runtime.spawn(server.serve());
+    }
+
+    /// Main, long-running function that binds the listener and serves the
+    /// incoming probes until the global [`AppContext`] is terminated.
+    async fn serve(self) {
+        let address = (self.config.host(), self.config.port());
+
+        let listener = match TcpListener::bind(address).await {
+            Ok(listener) => listener,
+            Err(_error) => {
+                #[cfg(feature = "tracing")]
+                tracing::error!(
+                    alert = true,
+                    host = self.config.host(),
+                    port = self.config.port(),
+                    error = ?_error,
+                    error_message = %_error,
+                    "Failed to bind the health listener",
+                );
+                return;
+            }
+        };
+
+        #[cfg(feature = "tracing")]
+        tracing::info!(
+            host = self.config.host(),
+            port = self.config.port(),
+            "Serving liveness at '{}' and readiness at '{}'",
+            self.config.liveness_path(),
+            self.config.readiness_path(),
+        );
+
+        // The probes being answered, tracked so that the spindown waits for them
+        let mut connections = JoinSet::new();
+
+        loop {
+            select! {
+                biased;
+                _ = AppContext::terminated() => break,
+                Some(_) = connections.join_next(), if !connections.is_empty() => {},
+                accepted = listener.accept() => {
+                    if let Ok((stream, _)) = accepted {
+                        connections.spawn(Self::answer(self.config, stream));
+                    }
+                },
+            }
+        }
+
+        // Stop accepting connections
+        drop(listener);
+
+        // Let the probes being answered complete
+        while connections.join_next().await.is_some() {}
+    }
+
+    /// Reads a single probe request from the given stream and answers it.
+    async fn answer(config: &'static HealthConfig, mut stream: TcpStream) {
+        let mut buffer = [0u8; MAX_REQUEST_LEN];
+
+        let len = match tokio::time::timeout(READ_TIMEOUT, stream.read(&mut buffer)).await {
+            Ok(Ok(len)) => len,
+            _ => return,
+        };
+
+        let request = &buffer[..len];
+        let response = Probe::parse(config, request).respond(!Probe::is_head(request));
+
+        let _ = stream.write_all(response.as_bytes()).await;
+        let _ = stream.shutdown().await;
+    }
+}
+
+/// Represents the kind of probe recognized from an incoming request.
+#[derive(Debug, PartialEq, Eq)]
+enum Probe {
+    Liveness,
+    Readiness,
+    Unknown,
+    Malformed,
+}
+
+impl Probe {
+    /// Recognizes the probe from the raw request bytes, looking only at the
+    /// request line.
+    fn parse(config: &HealthConfig, request: &[u8]) -> Self {
+        let request = String::from_utf8_lossy(request);
+        let request_line = request.lines().next().unwrap_or_default();
+
+        let mut parts = request_line.split_ascii_whitespace();
+
+        let (Some(method), Some(target)) = (parts.next(), parts.next()) else {
+            return Self::Malformed;
+        };
+
+        if method != "GET" && method != "HEAD" {
+            return Self::Malformed;
+        }
+
+        // Ignore the query string, if any
+        let path = target.split('?').next().unwrap_or_default();
+
+        if path == config.liveness_path() {
+            Self::Liveness
+        } else if path == config.readiness_path() {
+            Self::Readiness
+        } else {
+            Self::Unknown
+        }
+    }
+
+    /// Reports whether the given raw request is a `HEAD` request, which must be
+    /// answered without a body.
+    fn is_head(request: &[u8]) -> bool {
+        request.starts_with(b"HEAD ")
+    }
+
+    /// Composes a complete HTTP response for this probe, with or without the
+    /// body. The `Content-Length` header is the same either way.
+    fn respond(&self, with_body: bool) -> String {
+        let (status, body) = match self {
+            Self::Liveness if AppHealth::is_alive() => ("200 OK", "alive\n".to_string()),
+            Self::Liveness => ("503 Service Unavailable", "terminated\n".to_string()),
+            Self::Readiness if AppHealth::is_ready() => ("200 OK", "ready\n".to_string()),
+            Self::Readiness if !AppHealth::is_alive() => {
+                ("503 Service Unavailable", "terminated\n".to_string())
+            }
+            Self::Readiness => {
+                let pending = AppHealth::pending()
+                    .iter()
+                    .map(AsRef::as_ref)
+                    .collect::<Vec<&str>>()
+                    .join(", ");
+                ("503 Service Unavailable", format!("pending: {}\n", pending))
+            }
+            Self::Unknown => ("404 Not Found", "not found\n".to_string()),
+            Self::Malformed => ("400 Bad Request", "bad request\n".to_string()),
+        };
+
+        format!(
+            "HTTP/1.1 {}\r\nContent-Type: text/plain; charset=utf-8\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+            status,
+            body.len(),
+            if with_body { body.as_str() } else { "" },
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn parse_probes() {
+        // Given
+        let config = HealthConfig::default();
+
+        // Then
+        assert_eq!(
+            Probe::parse(&config, b"GET /health/live HTTP/1.1\r\nHost: x\r\n\r\n"),
+            Probe::Liveness,
+        );
+        assert_eq!(
+            Probe::parse(&config, b"HEAD /health/ready?verbose=1 HTTP/1.1\r\n\r\n"),
+            Probe::Readiness,
+        );
+        assert_eq!(
+            Probe::parse(&config, b"GET /other HTTP/1.1\r\n\r\n"),
+            Probe::Unknown,
+        );
+        assert_eq!(
+            Probe::parse(&config, b"POST /health/live HTTP/1.1\r\n\r\n"),
+            Probe::Malformed,
+        );
+        assert_eq!(Probe::parse(&config, b""), Probe::Malformed);
+    }
+
+    #[test]
+    fn respond_to_unknown() {
+        // When
+        let response = Probe::Unknown.respond(true);
+
+        // Then
+        assert!(response.starts_with("HTTP/1.1 404 Not Found\r\n"));
+        assert!(response.ends_with("\r\n\r\nnot found\n"));
+    }
+
+    #[test]
+    fn respond_to_head() {
+        // Given
+        let request = b"HEAD /other HTTP/1.1\r\n\r\n";
+
+        // When
+        let response = Probe::Unknown.respond(!Probe::is_head(request));
+
+        // Then
+        assert!(response.contains("\r\nContent-Length: 10\r\n"));
+        assert!(response.ends_with("\r\n\r\n"));
+    }
+}
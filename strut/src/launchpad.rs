@@ -1,11 +1,29 @@
+#[cfg(feature = "config-remote")]
+use crate::RemoteConfig;
+#[cfg(feature = "config-watch")]
+use crate::facade::config::live::watcher::{ConfigWatcher, DEFAULT_DEBOUNCE};
+use crate::launchpad::cli::{Cli, Command};
+use crate::launchpad::exit::{ExitCodeMapper, make_mapper};
+use crate::launchpad::service::Service;
 use crate::launchpad::wiring::configuration::DefaultConfigurationWiring;
 use crate::launchpad::wiring::preflight::DefaultPreflightWiring;
 use crate::launchpad::wiring::runtime::DefaultRuntimeWiring;
-use crate::{ConfigurationWiring, PreflightWiring, RuntimeWiring};
-use strut_config::AssemblerChoices;
-use strut_core::{AppContext, AppSpindown};
+use crate::{
+    AppConfig, ConfigurationWiring, DotEnv, MainExit, MainOutput, PreflightWiring, RestartPolicy,
+    RuntimeChoices, RuntimeFlavor, RuntimeWiring, ServiceOutput, UnknownKeyPolicy,
+};
+use config::{Config, Source, Value};
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use std::pin::Pin;
+use strut_config::{Assembler, AssemblerChoices};
+use strut_core::{AppContext, AppProfile, AppReplica, AppSpindown};
 use tokio::select;
 
+pub(crate) mod cli;
+pub mod exit;
+pub mod service;
+
 pub mod wiring {
     pub mod configuration;
     pub mod preflight;
@@ -32,14 +50,42 @@ pub mod wiring {
 /// 3.  **Preflight wiring:** Performs final setup tasks using the configuration
 ///     and the runtime before the main application logic begins.
 ///
-/// Once all stages are complete, the `Launchpad` executes the application's main
+/// Once all stages are complete, the `Launchpad` starts the registered
+/// [services](Launchpad::with_service), then executes the application's main
 /// future and waits for it to complete.
 ///
+/// ## Exit codes
+///
+/// The main future may output anything that implements [`MainOutput`], e.g.,
+/// a [`Result`]. If the main future fails, the error is logged, the
+/// [`AppContext`] is still terminated and the [`AppSpindown`] is still awaited,
+/// and only then the process exits with a non-zero
+/// [exit code](Launchpad::with_exit_code).
+///
+/// ## Command-line interface
+///
+/// An opt-in [command-line interface](Launchpad::with_cli) may be enabled to
+/// override the profile, the configuration directory, and the replica index
+/// via arguments, and to choose one of the built-in subcommands:
+///
+/// - `run` (default): runs the application’s main future.
+/// - `check-config`: assembles the configuration and exits.
+/// - `print-config`: assembles and prints the configuration (annotated with
+///   the source of every setting, with secrets redacted), then exits.
+/// - `print-schema`: prints the [JSON Schema](crate::AppConfig::json_schema)
+///   of the configuration, then exits.
+/// - `encrypt` (with the `config-encryption` feature): reads a value from the
+///   standard input and prints it [encrypted](strut_config::EncryptionKey)
+///   for the profile given via `--profile` (if any), then exits.
+/// - `migrate`: runs the [migrations](Launchpad::with_migrations) instead of
+///   the main future.
+///
 /// [`AppConfig`]: crate::AppConfig
 /// [`Runtime`]: tokio::runtime::Runtime
 pub struct Launchpad<Main>
 where
-    Main: Future<Output = ()>,
+    Main: Future,
+    Main::Output: MainOutput,
 {
     /// The application’s main asynchronous logic.
     async_main: Main,
@@ -47,6 +93,9 @@ where
     /// Customizable choices for assembling configuration.
     configuration_choices: AssemblerChoices,
 
+    /// Customizable choices for constructing the runtime.
+    runtime_choices: RuntimeChoices,
+
     /// The **configuration** wiring.
     configuration_wiring: Box<dyn ConfigurationWiring>,
 
@@ -55,11 +104,54 @@ where
 
     /// The **preflight** wiring.
     preflight_wiring: Box<dyn PreflightWiring>,
+
+    /// Whether to interpret the command-line arguments.
+    cli_enabled: bool,
+
+    /// Whether to print the configuration report on startup.
+    config_report_enabled: bool,
+
+    /// Whether to fail on malformed dot-env files.
+    dotenv_strict: bool,
+
+    /// Whether to map the environment variables against the configuration schema.
+    env_schema_enabled: bool,
+
+    /// The registrations of the custom configuration sections, applied on boot.
+    config_sections: Vec<Box<dyn FnOnce()>>,
+
+    /// The policy for unknown configuration keys, applied on boot, if chosen.
+    unknown_keys: Option<UnknownKeyPolicy>,
+
+    /// The per-section policies for unknown configuration keys, applied on boot.
+    unknown_keys_in: Vec<(String, UnknownKeyPolicy)>,
+
+    /// The migrations logic to run on the `migrate` subcommand, if any.
+    migrations: Option<Pin<Box<dyn Future<Output = ()>>>>,
+
+    /// The functions that choose the exit code for particular errors.
+    exit_code_mappers: Vec<ExitCodeMapper>,
+
+    /// The supervised background services.
+    services: Vec<Service>,
+
+    /// Whether to watch the configuration files for changes.
+    #[cfg(feature = "config-watch")]
+    config_watch_enabled: bool,
+
+    /// The quiet period to wait for after a change of configuration files.
+    #[cfg(feature = "config-watch")]
+    config_watch_debounce: std::time::Duration,
+
+    /// The remote configuration documents.
+    #[cfg(feature = "config-remote")]
+    remote_configs: Vec<std::sync::Arc<RemoteConfig>>,
 }
 
 impl<Main> Launchpad<Main>
 where
-    Main: Future<Output = ()>,
+    Main: Future,
+    Main::Output: MainOutput,
 {
     /// Creates a new `Launchpad` with default wiring.
     ///
@@ -69,16 +161,34 @@ where
         Self {
             async_main,
             configuration_choices: AssemblerChoices::default(),
+            runtime_choices: RuntimeChoices::default(),
             configuration_wiring: Box::new(DefaultConfigurationWiring),
             runtime_wiring: Box::new(DefaultRuntimeWiring),
             preflight_wiring: Box::new(DefaultPreflightWiring),
+            cli_enabled: false,
+            config_report_enabled: false,
+            dotenv_strict: false,
+            env_schema_enabled: false,
+            config_sections: Vec::new(),
+            unknown_keys: None,
+            unknown_keys_in: Vec::new(),
+            migrations: None,
+            exit_code_mappers: Vec::new(),
+            services: Vec::new(),
+            #[cfg(feature = "config-watch")]
+            config_watch_enabled: true,
+            #[cfg(feature = "config-watch")]
+            config_watch_debounce: DEFAULT_DEBOUNCE,
+            #[cfg(feature = "config-remote")]
+            remote_configs: Vec::new(),
         }
     }
 }
 
 impl<Main> Launchpad<Main>
 where
-    Main: Future<Output = ()>,
+    Main: Future,
+    Main::Output: MainOutput,
 {
     /// Specifies a custom name for the configuration directory.
     ///
@@ -142,6 +252,521 @@ where
         }
     }
 
+    /// Enables or disables mapping the environment variables onto the keys
+    /// known from the [JSON Schema](crate::AppConfig::json_schema) of the
+    /// application configuration.
+    ///
+    /// With the default separator `_`, an environment variable like
+    /// `APP_DATABASE_POSTGRES_MAIN_DB_HOST` is otherwise split on every
+    /// underscore, and cannot set `database.postgres.main_db.host`. When
+    /// enabled, the variable name is resolved against the known keys instead,
+    /// matching multi-word keys leniently, and the values of keys that accept
+    /// a list (e.g., `binding_keys`) are split on commas. The
+    /// [registered](Launchpad::with_config_section) custom sections accept
+    /// any keys.
+    ///
+    /// The environment variables with the prefix that match no known key are
+    /// ignored, and a warning is logged (or, without the `tracing` feature,
+    /// printed to the standard error) for each of them.
+    ///
+    /// This setting is only effective if environment overrides are
+    /// [enabled](Launchpad::with_env).
+    ///
+    /// Defaults to `false`.
+    pub fn with_env_schema(self, enabled: bool) -> Self {
+        Self {
+            env_schema_enabled: enabled,
+            ..self
+        }
+    }
+
+    /// Adds a layer of default configuration values, serialized from the
+    /// given value (e.g., a struct or a map).
+    ///
+    /// The defaults have the lowest precedence: they are overridden by the
+    /// config files and by everything else. Repeated calls add more layers,
+    /// where later layers override earlier ones.
+    ///
+    /// ```
+    /// use serde::Serialize;
+    /// use strut::{App, AppConfig};
+    ///
+    /// #[derive(Serialize)]
+    /// struct Defaults {
+    ///     name: &'static str,
+    /// }
+    ///
+    /// fn main() {
+    ///     App::launchpad(async_main())
+    ///         .with_config_defaults(Defaults { name: "billing" })
+    ///         .boot();
+    /// }
+    ///
+    /// async fn async_main() {
+    ///     assert_eq!(AppConfig::get().name(), "billing");
+    /// }
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the given value does not serialize into a map.
+    pub fn with_config_defaults<T>(mut self, defaults: T) -> Self
+    where
+        T: Serialize,
+    {
+        let defaults = Config::try_from(&defaults).unwrap_or_else(|error| {
+            panic!(
+                "the configuration defaults should serialize into a map: {}",
+                error
+            )
+        });
+
+        self.configuration_choices.defaults.push(Box::new(defaults));
+
+        self
+    }
+
+    /// Overrides the configuration value at the given key (e.g.,
+    /// `database.default.port`).
+    ///
+    /// The overrides have the highest precedence: they override the config
+    /// files, the [custom sources](Launchpad::with_config_source), and the
+    /// environment variables. Unlike those, the overrides are taken literally,
+    /// without resolving any references (such as `${env:NAME}`).
+    ///
+    /// # Panics
+    ///
+    /// Panics if the given key is not a valid configuration key.
+    pub fn with_config_override(mut self, key: impl Into<String>, value: impl Into<Value>) -> Self {
+        let key = key.into();
+        let value = value.into();
+
+        if let Err(error) = Config::builder().set_override(key.as_str(), value.clone()) {
+            panic!("invalid configuration override key '{}': {}", key, error);
+        }
+
+        self.configuration_choices.overrides.push((key, value));
+
+        self
+    }
+
+    /// Adds a custom configuration [`Source`] (e.g., a
+    /// [`File`](config::File) at a non-standard location, or a source backed
+    /// by a remote service).
+    ///
+    /// The custom sources override the config files, but are themselves
+    /// overridden by the environment variables and the
+    /// [overrides](Launchpad::with_config_override). Repeated calls add more
+    /// sources, where later sources override earlier ones.
+    ///
+    /// Same as the config files, the source is read again on every
+    /// [refresh](crate::AppLiveConfig::refresh) of the live configuration.
+    pub fn with_config_source<S>(mut self, source: S) -> Self
+    where
+        S: Source + Send + Sync + 'static,
+    {
+        self.configuration_choices.sources.push(Box::new(source));
+
+        self
+    }
+
+    /// Specifies the [flavor](RuntimeFlavor) of the Tokio runtime to construct.
+    ///
+    /// This setting is only effective with the default **runtime** wiring, or
+    /// with a custom wiring that honors it. It takes precedence over the
+    /// `runtime` section of the application configuration.
+    ///
+    /// Defaults to the flavor from the `runtime` section, or to
+    /// [`RuntimeFlavor::MultiThread`].
+    pub fn with_runtime_flavor(self, flavor: RuntimeFlavor) -> Self {
+        Self {
+            runtime_choices: RuntimeChoices {
+                flavor: Some(flavor),
+                ..self.runtime_choices
+            },
+            ..self
+        }
+    }
+
+    /// Specifies the number of worker threads for a
+    /// [multi-thread](RuntimeFlavor::MultiThread) Tokio runtime.
+    ///
+    /// This setting is only effective with the default **runtime** wiring, or
+    /// with a custom wiring that honors it. It takes precedence over the
+    /// `runtime` section of the application configuration.
+    ///
+    /// Defaults to the number from the `runtime` section, or to the number of
+    /// CPU cores available to the process.
+    pub fn with_worker_threads(self, worker_threads: usize) -> Self {
+        Self {
+            runtime_choices: RuntimeChoices {
+                worker_threads: Some(worker_threads),
+                ..self.runtime_choices
+            },
+            ..self
+        }
+    }
+
+    /// Enables or disables the command-line interface.
+    ///
+    /// When enabled, the command-line arguments are interpreted before the
+    /// **configuration** wiring stage. The following options are recognized:
+    ///
+    /// - `--profile <NAME>` sets the [active](AppProfile::active_all)
+    ///   profiles, taking precedence over the `APP_PROFILE` environment
+    ///   variable. The same syntax is accepted (e.g., `canary:prod,eu`).
+    /// - `--config-dir <PATH>` replaces the configuration directory set via
+    ///   [`with_config_dir`](Launchpad::with_config_dir). Note that the
+    ///   `APP_CONFIG_DIR` environment variable still takes precedence.
+    /// - `--replica-index <N>` sets the [replica index](AppReplica::index),
+    ///   taking precedence over the `APP_REPLICA_INDEX` environment variable.
+    ///   The index also selects the replica-specific configuration files
+    ///   (e.g., `app.replica-0.toml`).
+    ///
+    /// Unrecognized arguments abort the application with the usage information
+    /// and the exit code `2`.
+    ///
+    /// Defaults to `false`.
+    pub fn with_cli(self, enabled: bool) -> Self {
+        Self {
+            cli_enabled: enabled,
+            ..self
+        }
+    }
+
+    /// Registers a custom configuration section under the given key, so that
+    /// it is deserialized as the type `T` along with the sections known to
+    /// Strut, and validated eagerly during the **configuration** wiring stage.
+    ///
+    /// Without registration, a custom section read through
+    /// [`AppConfig::section`](crate::AppConfig::section) fails only when it is
+    /// first accessed. With registration, the application refuses to start if
+    /// the section cannot be deserialized, reporting all invalid sections at
+    /// once. Once the application is started, the registered section is
+    /// available as a static reference via
+    /// [`AppConfig::registered`](crate::AppConfig::registered).
+    ///
+    /// This is equivalent to calling
+    /// [`AppConfig::register`](crate::AppConfig::register), except that the
+    /// section is only registered when the application [boots](Launchpad::boot).
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use serde::Deserialize;
+    /// use strut::{App, AppConfig};
+    ///
+    /// #[derive(Default, Deserialize)]
+    /// struct BillingConfig {
+    ///     currency: String,
+    /// }
+    ///
+    /// fn main() {
+    ///     App::launchpad(async_main())
+    ///         .with_config_section::<BillingConfig>("billing")
+    ///         .boot();
+    /// }
+    ///
+    /// async fn async_main() {
+    ///     let billing: &'static BillingConfig = AppConfig::registered("billing");
+    ///
+    ///     println!("Billing in {}", billing.currency);
+    /// }
+    /// ```
+    pub fn with_config_section<T>(mut self, key: impl AsRef<str>) -> Self
+    where
+        T: DeserializeOwned + Default + Send + Sync + 'static,
+    {
+        let key = key.as_ref().to_string();
+        self.config_sections
+            .push(Box::new(move || AppConfig::register::<T>(key)));
+
+        self
+    }
+
+    /// Chooses how to treat the keys of the configuration that are not
+    /// recognized by any of its sections, such as a mistyped `prefech_count`.
+    ///
+    /// With [`UnknownKeyPolicy::Warn`], a warning is logged (or, without the
+    /// `tracing` feature, printed to the standard error) for every unknown key
+    /// during the **configuration** wiring stage. With
+    /// [`UnknownKeyPolicy::Deny`], the application refuses to start, reporting
+    /// the unknown keys along with the other validation errors. Either way,
+    /// every unknown key is reported with its full key path, the config file
+    /// that set it, and the closest known key as a suggestion.
+    ///
+    /// Custom sections are recognized at the top level only if they are
+    /// [registered](Launchpad::with_config_section).
+    ///
+    /// This is equivalent to calling
+    /// [`AppConfig::set_unknown_keys`](crate::AppConfig::set_unknown_keys),
+    /// except that the policy is only applied when the application
+    /// [boots](Launchpad::boot).
+    ///
+    /// Defaults to [`UnknownKeyPolicy::Ignore`].
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use strut::{App, UnknownKeyPolicy};
+    ///
+    /// fn main() {
+    ///     App::launchpad(async_main())
+    ///         .with_unknown_keys(UnknownKeyPolicy::Warn)
+    ///         .with_unknown_keys_in("rabbitmq", UnknownKeyPolicy::Deny)
+    ///         .boot();
+    /// }
+    ///
+    /// async fn async_main() {}
+    /// ```
+    pub fn with_unknown_keys(self, policy: UnknownKeyPolicy) -> Self {
+        Self {
+            unknown_keys: Some(policy),
+            ..self
+        }
+    }
+
+    /// Chooses how to treat the unknown keys in the given top-level section of
+    /// the configuration, overriding the policy chosen via
+    /// [`with_unknown_keys`](Launchpad::with_unknown_keys) for that section.
+    ///
+    /// This is equivalent to calling
+    /// [`AppConfig::set_unknown_keys_in`](crate::AppConfig::set_unknown_keys_in),
+    /// except that the policy is only applied when the application
+    /// [boots](Launchpad::boot).
+    pub fn with_unknown_keys_in(
+        mut self,
+        section: impl AsRef<str>,
+        policy: UnknownKeyPolicy,
+    ) -> Self {
+        self.unknown_keys_in
+            .push((section.as_ref().to_string(), policy));
+
+        self
+    }
+
+    /// Enables or disables printing the configuration report to the standard
+    /// error on startup, right after the **configuration** wiring stage.
+    ///
+    /// The report lists every setting of the merged configuration, annotated
+    /// with the config file or the environment variable that set it, and with
+    /// the config files that it overrode. Secrets (such as passwords and DSNs)
+    /// are redacted. The same report is printed by the `print-config`
+    /// subcommand of the [command-line interface](Launchpad::with_cli).
+    ///
+    /// Defaults to `false`.
+    pub fn with_config_report(self, enabled: bool) -> Self {
+        Self {
+            config_report_enabled: enabled,
+            ..self
+        }
+    }
+
+    /// Enables or disables the strict loading of the [dot-env](DotEnv) files.
+    ///
+    /// When enabled, the dot-env files are [loaded](DotEnv::try_tap) before
+    /// the **configuration** wiring stage, and an unreadable file or a
+    /// malformed line is printed to the standard error, exiting the process
+    /// with the code `78` (`EX_CONFIG`). When disabled, the malformed lines are
+    /// silently skipped.
+    ///
+    /// Defaults to `false`.
+    pub fn with_dotenv_strict(self, enabled: bool) -> Self {
+        Self {
+            dotenv_strict: enabled,
+            ..self
+        }
+    }
+
+    /// Enables or disables watching the configuration files for changes.
+    ///
+    /// When enabled, the configuration directory and the [dot-env](DotEnv)
+    /// files are watched once the application starts, and the
+    /// [live configuration](crate::AppLiveConfig) is
+    /// [refreshed](crate::AppLiveConfig::refresh) after every change. Code that
+    /// needs to react to new values may
+    /// [subscribe](crate::AppLiveConfig::subscribe) to a section. The
+    /// [initial configuration](AppConfig) never changes.
+    ///
+    /// This method is only available when the `config-watch` feature is
+    /// enabled.
+    ///
+    /// Defaults to `true`.
+    #[cfg(feature = "config-watch")]
+    pub fn with_config_watch(self, enabled: bool) -> Self {
+        Self {
+            config_watch_enabled: enabled,
+            ..self
+        }
+    }
+
+    /// Specifies how long to wait after a change of the configuration files,
+    /// with no further changes, before refreshing the live configuration.
+    ///
+    /// This method is only available when the `config-watch` feature is
+    /// enabled.
+    ///
+    /// Defaults to 500 milliseconds.
+    #[cfg(feature = "config-watch")]
+    pub fn with_config_watch_debounce(self, debounce: std::time::Duration) -> Self {
+        Self {
+            config_watch_debounce: debounce,
+            ..self
+        }
+    }
+
+    /// Adds a configuration document fetched from a remote location by the
+    /// given [`RemoteConfig`].
+    ///
+    /// The document is fetched before the initial configuration is assembled
+    /// (the application refuses to start if neither the document nor its
+    /// cached copy is available), and then polled while the application runs,
+    /// refreshing the [live configuration](crate::AppLiveConfig) whenever it
+    /// changes.
+    ///
+    /// The remote documents take precedence over the
+    /// [custom sources](Launchpad::with_config_source), and among themselves
+    /// in the order of registration.
+    ///
+    /// Unlike the custom sources, the remote documents are taken literally:
+    /// their string values are neither interpolated nor decrypted. A
+    /// document fetched from elsewhere must not be able to read the local
+    /// files or environment variables (such as mounted secrets) into the
+    /// configuration.
+    ///
+    /// This method is only available when the `config-remote` feature is
+    /// enabled.
+    #[cfg(feature = "config-remote")]
+    pub fn with_remote_config(mut self, remote_config: RemoteConfig) -> Self {
+        self.configuration_choices
+            .literal_sources
+            .push(Box::new(remote_config.source()));
+        self.remote_configs.push(std::sync::Arc::new(remote_config));
+
+        self
+    }
+
+    /// Specifies the logic to run on the `migrate` subcommand of the
+    /// [command-line interface](Launchpad::with_cli), instead of the
+    /// application’s main logic.
+    ///
+    /// The given future runs after all the wiring stages, and the application
+    /// exits once it completes.
+    pub fn with_migrations<M>(self, migrations: M) -> Self
+    where
+        M: Future<Output = ()> + 'static,
+    {
+        Self {
+            migrations: Some(Box::pin(migrations)),
+            ..self
+        }
+    }
+
+    /// Specifies the function that chooses the exit code for the errors of type
+    /// `E`, in case the main future fails with such an error.
+    ///
+    /// This method may be called repeatedly for different error types. If the
+    /// same type is given repeatedly, the first function wins. Errors of any
+    /// type without a function result in the exit code `1`.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use strut::App;
+    ///
+    /// #[derive(Debug)]
+    /// enum AppError {
+    ///     Config,
+    ///     Network,
+    /// }
+    ///
+    /// fn main() {
+    ///     App::launchpad(async_main())
+    ///         .with_exit_code(|error: &AppError| match error {
+    ///             AppError::Config => 78,
+    ///             AppError::Network => 69,
+    ///         })
+    ///         .boot();
+    /// }
+    ///
+    /// async fn async_main() -> Result<(), AppError> {
+    ///     Err(AppError::Network)
+    /// }
+    /// ```
+    pub fn with_exit_code<E, F>(mut self, mapper: F) -> Self
+    where
+        E: 'static,
+        F: Fn(&E) -> u8 + 'static,
+    {
+        self.exit_code_mappers.push(make_mapper(mapper));
+
+        self
+    }
+
+    /// Registers a long-lived background service (e.g., a consumer, a poller,
+    /// or a scheduler) under the given human-readable name, supervised with the
+    /// default [`RestartPolicy`].
+    ///
+    /// The given factory is called to produce each run of the service. The
+    /// first run is spawned after the **preflight** wiring stage, right before
+    /// the application’s main future. Whenever a run panics or returns an
+    /// error, the factory is called again after an exponential backoff.
+    ///
+    /// Each service is registered with [`AppSpindown`], so it is expected to
+    /// watch the global [`AppContext`] and return once it is terminated.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use strut::{App, AppContext};
+    ///
+    /// fn main() {
+    ///     App::launchpad(async_main())
+    ///         .with_service("poller", poll)
+    ///         .boot();
+    /// }
+    ///
+    /// async fn async_main() {
+    ///     AppContext::terminated().await;
+    /// }
+    ///
+    /// async fn poll() -> Result<(), std::io::Error> {
+    ///     while AppContext::is_alive() {
+    ///         // Poll something, returning early on errors
+    ///         tokio::time::sleep(std::time::Duration::from_secs(1)).await;
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn with_service<F, S>(self, name: impl AsRef<str>, factory: F) -> Self
+    where
+        F: Fn() -> S + Send + Sync + 'static,
+        S: Future + Send + 'static,
+        S::Output: ServiceOutput,
+    {
+        self.with_service_policy(name, factory, RestartPolicy::default())
+    }
+
+    /// Registers a long-lived background service, same as
+    /// [`with_service`](Launchpad::with_service), but supervised with the
+    /// given [`RestartPolicy`].
+    pub fn with_service_policy<F, S>(
+        mut self,
+        name: impl AsRef<str>,
+        factory: F,
+        policy: RestartPolicy,
+    ) -> Self
+    where
+        F: Fn() -> S + Send + Sync + 'static,
+        S: Future + Send + 'static,
+        S::Output: ServiceOutput,
+    {
+        self.services.push(Service::new(name, factory, policy));
+
+        self
+    }
+
     /// Replaces the default **configuration** wiring with a custom implementation.
     pub fn with_configuration_wiring<W>(self, configuration_wiring: W) -> Self
     where
@@ -184,48 +809,326 @@ where
 
 impl<Main> Launchpad<Main>
 where
-    Main: Future<Output = ()>,
+    Main: Future,
+    Main::Output: MainOutput,
 {
     /// Executes the wiring stages and runs the application.
     ///
     /// This method orchestrates the entire startup process:
-    /// 1. Runs the configuration, runtime, and preflight wiring stages in order.
+    /// 1. Runs the configuration, runtime, and preflight wiring stages in order,
+    ///    then starts the [services](Launchpad::with_service).
     /// 2. Blocks on the main asynchronous logic until it completes or a
     ///    termination signal is received.
     /// 3. Manages a graceful shutdown.
-    pub fn boot(self) {
+    /// 4. Exits the process with a non-zero code if the main future fails.
+    ///
+    /// If the [command-line interface](Launchpad::with_cli) is enabled, the
+    /// arguments are interpreted first, and the chosen subcommand may end the
+    /// process early.
+    pub fn boot(mut self) {
+        // Register the configuration sections and policies (the schema depends on them)
+        self.apply_config_registrations();
+
+        // Interpret the command-line arguments, if enabled
+        let command = match self.cli_enabled {
+            true => self.apply_cli(),
+            false => Command::Run,
+        };
+
+        // The schema does not depend on the configuration, nor on the environment
+        if command == Command::PrintSchema {
+            println!("{:#}", AppConfig::json_schema());
+            return;
+        }
+
+        // Load the dot-env files strictly, if requested (leaving nothing for the wiring to load)
+        if self.dotenv_strict {
+            if let Err(error) = DotEnv::try_tap() {
+                eprintln!("error: {}", error);
+                std::process::exit(78);
+            }
+        }
+
+        // Fetch the remote configuration documents, if any
+        #[cfg(feature = "config-remote")]
+        self.load_remote_configs();
+
+        // Map the environment variables against the configuration schema, if enabled
+        if self.env_schema_enabled {
+            self.configuration_choices.env_schema = Some(AppConfig::env_schema());
+        }
+
         // Resolve the initial application configuration
         let config = self.configuration_wiring.run(&self.configuration_choices);
 
+        // Warn about the environment variables that set nothing
+        for _variable in Assembler::unmatched_env(&self.configuration_choices) {
+            #[cfg(feature = "tracing")]
+            tracing::warn!(
+                alert = true,
+                variable = %_variable,
+                "The environment variable matches no configuration key",
+            );
+
+            #[cfg(not(feature = "tracing"))]
+            eprintln!(
+                "warning: the environment variable `{}` matches no configuration key",
+                _variable,
+            );
+        }
+
+        // Report the configuration, if requested
+        if self.config_report_enabled {
+            eprint!("{}", self.explain_config());
+        }
+
+        // Some subcommands need nothing beyond the configuration
+        match command {
+            Command::CheckConfig => {
+                println!("Configuration is valid (profile: {})", AppProfile::active());
+                return;
+            }
+            Command::PrintConfig => {
+                print!("{}", self.explain_config());
+                return;
+            }
+            _ => {}
+        }
+
         // Make the asynchronous runtime
-        let runtime = self.runtime_wiring.run(config);
+        let runtime = self.runtime_wiring.run(config, &self.runtime_choices);
 
         // Run the preflight steps
         self.preflight_wiring.run(config, &runtime);
 
-        // Proceed to the application’s main asynchronous logic
-        runtime.block_on(self.run_async_main());
+        // Proceed to the chosen asynchronous logic
+        let exit = match (command, self.migrations) {
+            (Command::Migrate, Some(migrations)) => runtime.block_on(Self::run_async(migrations)),
+            _ => {
+                // Start watching the configuration files, if enabled
+                #[cfg(feature = "config-watch")]
+                if self.config_watch_enabled {
+                    ConfigWatcher::new(
+                        self.configuration_choices.dir_name.as_deref(),
+                        self.config_watch_debounce,
+                    )
+                    .start(&runtime);
+                }
+
+                // Start polling the remote configuration documents
+                #[cfg(feature = "config-remote")]
+                for remote_config in &self.remote_configs {
+                    std::sync::Arc::clone(remote_config).start(&runtime);
+                }
+
+                // Start the supervised services
+                for service in self.services {
+                    service.start(&runtime);
+                }
+
+                runtime.block_on(Self::run_async(self.async_main))
+            }
+        };
+
+        // Shut down the runtime before possibly exiting the process
+        drop(runtime);
+
+        // Exit with the resolved exit code, unless it signals success
+        let code = exit.resolve(&self.exit_code_mappers);
+        if code != 0 {
+            std::process::exit(code as i32);
+        }
+    }
+
+    /// Applies the [custom sections](Launchpad::with_config_section) and the
+    /// [policies for unknown keys](Launchpad::with_unknown_keys) to the global
+    /// configuration registries, before the initial configuration is seeded.
+    fn apply_config_registrations(&mut self) {
+        for register in self.config_sections.drain(..) {
+            register();
+        }
+
+        if let Some(policy) = self.unknown_keys {
+            AppConfig::set_unknown_keys(policy);
+        }
+
+        for (section, policy) in self.unknown_keys_in.drain(..) {
+            AppConfig::set_unknown_keys_in(section, policy);
+        }
+    }
+
+    /// Parses the command-line arguments and applies the overrides given in
+    /// them. Returns the chosen [`Command`].
+    ///
+    /// Exits the process if the arguments cannot be parsed, if the usage
+    /// information is requested, or if the `migrate` subcommand is chosen
+    /// without any [migrations](Launchpad::with_migrations) registered.
+    fn apply_cli(&mut self) -> Command {
+        let mut args = std::env::args_os();
+        let program = args
+            .next()
+            .map(|program| program.to_string_lossy().into_owned())
+            .unwrap_or_default();
+
+        let cli = match Cli::parse(args) {
+            Ok(cli) => cli,
+            Err(error) => {
+                eprintln!("error: {}\n\n{}", error, Cli::usage(&program));
+                std::process::exit(2);
+            }
+        };
+
+        match cli.command {
+            Command::Help => {
+                print!("{}", Cli::usage(&program));
+                std::process::exit(0);
+            }
+            Command::Migrate if self.migrations.is_none() => {
+                eprintln!("error: no migrations are registered for this application");
+                std::process::exit(2);
+            }
+            _ => {}
+        }
+
+        // An explicitly given profile is the one to encrypt values for
+        #[cfg(feature = "config-encryption")]
+        let bound = cli.profile.is_some();
+
+        if let Some(profile) = cli.profile {
+            if let Err(error) = AppProfile::validate_spec(&profile) {
+                eprintln!("error: invalid value for --profile: {}", error);
+                std::process::exit(2);
+            }
+            if AppProfile::set_active_spec(profile).is_err() {
+                eprintln!("error: the profile cannot be changed after it is accessed");
+                std::process::exit(2);
+            }
+        }
+
+        if let Some(index) = cli.replica_index {
+            if AppReplica::set_index(index).is_err() {
+                eprintln!("error: the replica index cannot be changed after it is accessed");
+                std::process::exit(2);
+            }
+        }
+
+        if let Some(dir_name) = cli.config_dir {
+            self.configuration_choices.dir_name = Some(dir_name);
+        }
+
+        #[cfg(feature = "config-encryption")]
+        if cli.command == Command::Encrypt {
+            self.encrypt_stdin(bound);
+        }
+
+        cli.command
+    }
+
+    /// Encrypts the value read from the standard input with the
+    /// [`EncryptionKey`](strut_config::EncryptionKey) found in the environment
+    /// (after loading the dot-env files), prints it, and exits the process. If
+    /// `bound`, the value is encrypted for the [active](AppProfile::active)
+    /// profile.
+    ///
+    /// Exits the process with the code `78` (`EX_CONFIG`) if the key is
+    /// unavailable, or with the code `74` (`EX_IOERR`) if the standard input
+    /// cannot be read.
+    #[cfg(feature = "config-encryption")]
+    fn encrypt_stdin(&self, bound: bool) -> ! {
+        use std::io::Read;
+
+        match self.dotenv_strict {
+            true => {
+                if let Err(error) = DotEnv::try_tap() {
+                    eprintln!("error: {}", error);
+                    std::process::exit(78);
+                }
+            }
+            false => DotEnv::tap(),
+        }
+
+        let key = strut_config::EncryptionKey::from_env().unwrap_or_else(|error| {
+            eprintln!("error: {}", error);
+            std::process::exit(78);
+        });
+
+        let mut plaintext = String::new();
+        if let Err(error) = std::io::stdin().read_to_string(&mut plaintext) {
+            eprintln!(
+                "error: the value cannot be read from the standard input: {}",
+                error
+            );
+            std::process::exit(74);
+        }
+        let plaintext = plaintext.trim_end_matches(['\r', '\n']);
+
+        let profile = bound.then(AppProfile::active);
+        println!("{}", key.encrypt(plaintext, profile));
+
+        std::process::exit(0);
+    }
+
+    /// Fetches every remote configuration document (or reads its cached
+    /// copy) on a temporary runtime, as the main runtime does not exist yet.
+    ///
+    /// Exits the process with the code `78` (`EX_CONFIG`) if any document is
+    /// unavailable.
+    #[cfg(feature = "config-remote")]
+    fn load_remote_configs(&self) {
+        if self.remote_configs.is_empty() {
+            return;
+        }
+
+        let tmp_runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .expect("it should be possible to build a temporary tokio runtime");
+
+        for remote_config in &self.remote_configs {
+            if let Err(error) = tmp_runtime.block_on(remote_config.load()) {
+                eprintln!("error: {}", error);
+                std::process::exit(78);
+            }
+        }
+    }
+
+    /// Renders the report of the merged configuration, attributing the
+    /// environment variables to the dot-env files where applicable.
+    fn explain_config(&self) -> String {
+        Assembler::explain(&self.configuration_choices)
+            .map(|report| report.with_env_files(|name| DotEnv::origin(name)))
+            .unwrap_or_else(|error| panic!("failed to explain the configuration: {}", error))
+            .to_string()
     }
 
-    /// Wraps the main future to handle graceful shutdown.
+    /// Wraps the given future to handle graceful shutdown.
     ///
-    /// This internal function runs the user-provided `async_main` task and listens
-    /// for a termination signal from the [`AppContext`] concurrently.
+    /// This internal function runs the given future (typically, the
+    /// user-provided `async_main` task) and listens for a termination signal
+    /// from the [`AppContext`] concurrently.
     ///
     /// On exit, it ensures the `AppContext` is terminated and waits for the
-    /// [`AppSpindown`] process to complete before exiting.
-    async fn run_async_main(self) {
-        // Run the application’s main asynchronous logic, keeping an eye on the context
-        select! {
+    /// [`AppSpindown`] process to complete before exiting. The output of the
+    /// given future is interpreted as the [`MainExit`], while an interrupted
+    /// future counts as a success.
+    async fn run_async<F>(future: F) -> MainExit
+    where
+        F: Future,
+        F::Output: MainOutput,
+    {
+        // Run the given asynchronous logic, keeping an eye on the context
+        let exit = select! {
             biased;
-            _ = AppContext::terminated() => {},
-            _ = self.async_main => {},
-        }
+            _ = AppContext::terminated() => MainExit::success(),
+            output = future => output.into_exit(),
+        };
 
         // Terminate the context in case it is not terminated yet
         AppContext::terminate();
 
         // Wait for the application spindown to complete
         AppSpindown::completed().await;
+
+        exit
     }
 }
@@ -0,0 +1,303 @@
+use std::ffi::OsString;
+use std::fmt::{Display, Formatter};
+use std::path::Path;
+
+/// Represents the built-in subcommands of a Strut application, as recognized
+/// by the opt-in [command-line interface](crate::Launchpad::with_cli).
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Command {
+    /// Runs the application’s main asynchronous logic (the default).
+    #[default]
+    Run,
+
+    /// Assembles the initial application configuration and reports whether it
+    /// is valid, without running the application.
+    CheckConfig,
+
+    /// Assembles the initial application configuration and prints it (along
+    /// with the source of every setting), without running the application.
+    PrintConfig,
+
+    /// Prints the JSON Schema of the application configuration, without
+    /// assembling the configuration or running the application.
+    PrintSchema,
+
+    /// Reads a value from the standard input and prints it encrypted, without
+    /// assembling the configuration or running the application.
+    #[cfg(feature = "config-encryption")]
+    Encrypt,
+
+    /// Runs the [registered](crate::Launchpad::with_migrations) migrations
+    /// instead of the application’s main asynchronous logic.
+    Migrate,
+
+    /// Prints the usage information.
+    Help,
+}
+
+/// Represents the parsed command-line arguments of a Strut application.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub(crate) struct Cli {
+    /// The chosen subcommand.
+    pub(crate) command: Command,
+    /// The profile given via `--profile`, if any.
+    pub(crate) profile: Option<String>,
+    /// The configuration directory given via `--config-dir`, if any.
+    pub(crate) config_dir: Option<String>,
+    /// The replica index given via `--replica-index`, if any.
+    pub(crate) replica_index: Option<usize>,
+}
+
+/// Represents the ways in which the command-line arguments may be rejected.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum CliError {
+    /// An argument is not valid Unicode.
+    NonUnicode(OsString),
+    /// An option is not recognized.
+    UnknownOption(String),
+    /// A subcommand is not recognized.
+    UnknownCommand(String),
+    /// A subcommand is given more than once.
+    UnexpectedArgument(String),
+    /// An option that requires a value is given without one.
+    MissingValue(&'static str),
+    /// An option is given a value that cannot be interpreted.
+    InvalidValue {
+        /// The name of the option.
+        option: &'static str,
+        /// The rejected value.
+        value: String,
+    },
+}
+
+impl Cli {
+    /// Parses the given command-line arguments, which must **not** include the
+    /// program name.
+    pub(crate) fn parse<I, S>(args: I) -> Result<Self, CliError>
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<OsString>,
+    {
+        let mut cli = Self::default();
+        let mut command = None;
+        let mut help = false;
+        let mut args = args
+            .into_iter()
+            .map(|arg| arg.into().into_string().map_err(CliError::NonUnicode));
+
+        while let Some(arg) = args.next() {
+            let arg = arg?;
+
+            // Split the `--option=value` form, if applicable
+            let (name, inline_value) = match arg.split_once('=') {
+                Some((name, value)) if name.starts_with('-') => (name, Some(value.to_string())),
+                _ => (arg.as_str(), None),
+            };
+
+            // Resolve the value of an option either inline or from the next argument
+            let mut value_of = |option: &'static str| match inline_value.clone() {
+                Some(value) => Ok(value),
+                None => args.next().ok_or(CliError::MissingValue(option))?,
+            };
+
+            match name {
+                "-h" | "--help" => help = true,
+                "-p" | "--profile" => cli.profile = Some(value_of("--profile")?),
+                "-c" | "--config-dir" => cli.config_dir = Some(value_of("--config-dir")?),
+                "-r" | "--replica-index" => {
+                    let value = value_of("--replica-index")?;
+                    let index = value.parse().map_err(|_| CliError::InvalidValue {
+                        option: "--replica-index",
+                        value,
+                    })?;
+                    cli.replica_index = Some(index);
+                }
+                option if option.starts_with('-') => {
+                    return Err(CliError::UnknownOption(option.to_string()));
+                }
+                other => {
+                    if command.is_some() {
+                        return Err(CliError::UnexpectedArgument(other.to_string()));
+                    }
+                    command = Some(Self::parse_command(other)?);
+                }
+            }
+        }
+
+        // Help takes precedence regardless of the order of arguments
+        cli.command = match help {
+            true => Command::Help,
+            false => command.unwrap_or_default(),
+        };
+
+        Ok(cli)
+    }
+
+    /// Recognizes a subcommand by its name.
+    fn parse_command(name: &str) -> Result<Command, CliError> {
+        match name {
+            "run" => Ok(Command::Run),
+            "check-config" => Ok(Command::CheckConfig),
+            "print-config" => Ok(Command::PrintConfig),
+            "print-schema" => Ok(Command::PrintSchema),
+            #[cfg(feature = "config-encryption")]
+            "encrypt" => Ok(Command::Encrypt),
+            "migrate" => Ok(Command::Migrate),
+            "help" => Ok(Command::Help),
+            other => Err(CliError::UnknownCommand(other.to_string())),
+        }
+    }
+
+    /// Composes the usage information for the given program name (typically,
+    /// the first command-line argument).
+    pub(crate) fn usage(program: impl AsRef<str>) -> String {
+        let program = Path::new(program.as_ref())
+            .file_name()
+            .and_then(|name| name.to_str())
+            .unwrap_or("app");
+        let encrypt = match cfg!(feature = "config-encryption") {
+            true => "  encrypt       Encrypt a value read from stdin and exit\n",
+            false => "",
+        };
+
+        format!(
+            "\
+Usage: {program} [OPTIONS] [COMMAND]
+
+Commands:
+  run           Run the application (default)
+  check-config  Validate the configuration and exit
+  print-config  Print the resolved configuration and exit
+  print-schema  Print the JSON Schema of the configuration and exit
+{encrypt}  migrate       Apply the registered migrations and exit
+  help          Print this message and exit
+
+Options:
+  -p, --profile <NAMES>      Use the given profiles instead of APP_PROFILE
+  -c, --config-dir <PATH>    Look for configuration files in the given directory
+  -r, --replica-index <N>    Use the given replica index instead of APP_REPLICA_INDEX
+  -h, --help                 Print this message and exit
+"
+        )
+    }
+}
+
+impl Display for CliError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::NonUnicode(arg) => write!(f, "argument {:?} is not valid Unicode", arg),
+            Self::UnknownOption(option) => write!(f, "unrecognized option '{}'", option),
+            Self::UnknownCommand(command) => write!(f, "unrecognized command '{}'", command),
+            Self::UnexpectedArgument(arg) => write!(f, "unexpected argument '{}'", arg),
+            Self::MissingValue(option) => write!(f, "option '{}' requires a value", option),
+            Self::InvalidValue { option, value } => {
+                write!(f, "invalid value '{}' for option '{}'", value, option)
+            }
+        }
+    }
+}
+
+impl std::error::Error for CliError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn parse_empty() {
+        // When
+        let cli = Cli::parse(Vec::<String>::new()).unwrap();
+
+        // Then
+        assert_eq!(cli, Cli::default());
+        assert_eq!(cli.command, Command::Run);
+    }
+
+    #[test]
+    fn parse_full() {
+        // Given
+        let args = [
+            "--profile",
+            "preprod",
+            "--config-dir=settings",
+            "-r",
+            "3",
+            "check-config",
+        ];
+        let expected_output = Cli {
+            command: Command::CheckConfig,
+            profile: Some("preprod".to_string()),
+            config_dir: Some("settings".to_string()),
+            replica_index: Some(3),
+        };
+
+        // When
+        let actual_output = Cli::parse(args).unwrap();
+
+        // Then
+        assert_eq!(expected_output, actual_output);
+    }
+
+    #[test]
+    fn parse_print_schema() {
+        // When
+        let cli = Cli::parse(["print-schema"]).unwrap();
+
+        // Then
+        assert_eq!(cli.command, Command::PrintSchema);
+    }
+
+    #[cfg(feature = "config-encryption")]
+    #[test]
+    fn parse_encrypt() {
+        // When
+        let cli = Cli::parse(["encrypt", "--profile", "prod"]).unwrap();
+
+        // Then
+        assert_eq!(cli.command, Command::Encrypt);
+        assert_eq!(cli.profile.as_deref(), Some("prod"));
+    }
+
+    #[test]
+    fn parse_help() {
+        // Then
+        assert_eq!(
+            Cli::parse(["migrate", "--help"]).unwrap().command,
+            Command::Help
+        );
+        assert_eq!(
+            Cli::parse(["--help", "migrate"]).unwrap().command,
+            Command::Help
+        );
+        assert_eq!(Cli::parse(["help"]).unwrap().command, Command::Help);
+    }
+
+    #[test]
+    fn parse_errors() {
+        // Then
+        assert_eq!(
+            Cli::parse(["--verbose"]),
+            Err(CliError::UnknownOption("--verbose".to_string())),
+        );
+        assert_eq!(
+            Cli::parse(["deploy"]),
+            Err(CliError::UnknownCommand("deploy".to_string())),
+        );
+        assert_eq!(
+            Cli::parse(["run", "migrate"]),
+            Err(CliError::UnexpectedArgument("migrate".to_string())),
+        );
+        assert_eq!(
+            Cli::parse(["--profile"]),
+            Err(CliError::MissingValue("--profile")),
+        );
+        assert_eq!(
+            Cli::parse(["--replica-index", "first"]),
+            Err(CliError::InvalidValue {
+                option: "--replica-index",
+                value: "first".to_string(),
+            }),
+        );
+    }
+}
@@ -0,0 +1,219 @@
+use std::any::Any;
+use std::fmt::Debug;
+use std::process::ExitCode;
+
+/// The exit code used when the application’s main logic fails, unless a more
+/// specific [exit code](crate::Launchpad::with_exit_code) is configured for
+/// the error.
+pub const DEFAULT_FAILURE_EXIT_CODE: u8 = 1;
+
+/// Represents the output of the application’s main asynchronous logic that can
+/// be interpreted as the outcome of the whole application.
+///
+/// The following types are supported out of the box:
+///
+/// - `()`: the application always succeeds.
+/// - `u8`: the application exits with the given code.
+/// - [`ExitCode`]: the application succeeds if the given code is
+///   [`ExitCode::SUCCESS`], and fails with the code `1` otherwise. (The
+///   standard [`ExitCode`] is opaque, so prefer `u8` if the exact code
+///   matters.)
+/// - [`Result<T, E>`]: the application proceeds to interpret `T` in case of
+///   success, and fails in case of any error `E` that implements [`Debug`].
+///
+/// Custom output types may implement this trait to choose the [`MainExit`].
+pub trait MainOutput {
+    /// Interprets this output as the [`MainExit`].
+    fn into_exit(self) -> MainExit;
+}
+
+/// Represents the way in which the application exits after the main
+/// asynchronous logic completes: either with a particular exit code, or with an
+/// error.
+pub struct MainExit {
+    kind: MainExitKind,
+}
+
+/// Distinguishes the ways the application may exit.
+enum MainExitKind {
+    /// The application completed, choosing the given exit code.
+    Code(u8),
+    /// The application failed with the given error.
+    Failure(Box<dyn MainError>),
+}
+
+/// Represents an error returned from the application’s main logic, in a
+/// type-erased form that can be both logged and down-cast.
+trait MainError: Debug {
+    /// Exposes this error as [`Any`] for down-casting.
+    fn as_any(&self) -> &dyn Any;
+}
+
+impl<E> MainError for E
+where
+    E: Debug + 'static,
+{
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+impl MainExit {
+    /// Creates a [`MainExit`] that completes the application with the given
+    /// exit code.
+    pub fn code(code: u8) -> Self {
+        Self {
+            kind: MainExitKind::Code(code),
+        }
+    }
+
+    /// Creates a [`MainExit`] that completes the application successfully.
+    pub fn success() -> Self {
+        Self::code(0)
+    }
+
+    /// Creates a [`MainExit`] that fails the application with the given
+    /// error.
+    pub fn failure<E>(error: E) -> Self
+    where
+        E: Debug + 'static,
+    {
+        Self {
+            kind: MainExitKind::Failure(Box::new(error)),
+        }
+    }
+}
+
+impl MainExit {
+    /// Logs the error (if any) and resolves the exit code, consulting the given
+    /// exit code mappers in order.
+    pub(crate) fn resolve(self, mappers: &[ExitCodeMapper]) -> u8 {
+        let error = match self.kind {
+            MainExitKind::Code(code) => return code,
+            MainExitKind::Failure(error) => error,
+        };
+
+        #[cfg(feature = "tracing")]
+        tracing::error!(
+            alert = true,
+            error = ?error,
+            "The application’s main logic failed",
+        );
+
+        #[cfg(not(feature = "tracing"))]
+        eprintln!("Error: {:?}", error);
+
+        mappers
+            .iter()
+            .find_map(|mapper| mapper((*error).as_any()))
+            .unwrap_or(DEFAULT_FAILURE_EXIT_CODE)
+    }
+}
+
+/// A type-erased function that chooses the exit code for an error of a
+/// particular type, returning [`None`] for errors of any other type.
+pub(crate) type ExitCodeMapper = Box<dyn Fn(&dyn Any) -> Option<u8>>;
+
+/// Wraps the given typed function into an [`ExitCodeMapper`].
+pub(crate) fn make_mapper<E, F>(mapper: F) -> ExitCodeMapper
+where
+    E: 'static,
+    F: Fn(&E) -> u8 + 'static,
+{
+    Box::new(move |error: &dyn Any| error.downcast_ref::<E>().map(&mapper))
+}
+
+impl MainOutput for () {
+    fn into_exit(self) -> MainExit {
+        MainExit::success()
+    }
+}
+
+impl MainOutput for u8 {
+    fn into_exit(self) -> MainExit {
+        MainExit::code(self)
+    }
+}
+
+impl MainOutput for ExitCode {
+    fn into_exit(self) -> MainExit {
+        match self == ExitCode::SUCCESS {
+            true => MainExit::success(),
+            false => MainExit::code(DEFAULT_FAILURE_EXIT_CODE),
+        }
+    }
+}
+
+impl MainOutput for MainExit {
+    fn into_exit(self) -> MainExit {
+        self
+    }
+}
+
+impl<T, E> MainOutput for Result<T, E>
+where
+    T: MainOutput,
+    E: Debug + 'static,
+{
+    fn into_exit(self) -> MainExit {
+        match self {
+            Ok(output) => output.into_exit(),
+            Err(error) => MainExit::failure(error),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[derive(Debug)]
+    enum CustomError {
+        Config,
+        Network,
+    }
+
+    #[test]
+    fn resolve_codes() {
+        // Then
+        assert_eq!(().into_exit().resolve(&[]), 0);
+        assert_eq!(7u8.into_exit().resolve(&[]), 7);
+        assert_eq!(ExitCode::SUCCESS.into_exit().resolve(&[]), 0);
+        assert_eq!(ExitCode::from(3).into_exit().resolve(&[]), 1);
+        assert_eq!(Ok::<u8, String>(5).into_exit().resolve(&[]), 5);
+    }
+
+    #[test]
+    fn resolve_failures() {
+        // Given
+        let mappers = vec![
+            make_mapper(|error: &CustomError| match error {
+                CustomError::Config => 78,
+                CustomError::Network => 69,
+            }),
+            make_mapper(|_: &String| 2),
+        ];
+
+        // Then
+        assert_eq!(
+            Err::<(), _>(CustomError::Config)
+                .into_exit()
+                .resolve(&mappers),
+            78,
+        );
+        assert_eq!(
+            Err::<(), _>(CustomError::Network)
+                .into_exit()
+                .resolve(&mappers),
+            69,
+        );
+        assert_eq!(
+            Err::<(), _>("oops".to_string())
+                .into_exit()
+                .resolve(&mappers),
+            2,
+        );
+        assert_eq!(Err::<(), _>(0.5f32).into_exit().resolve(&mappers), 1);
+    }
+}
@@ -0,0 +1,475 @@
+use serde::de::{MapAccess, Visitor};
+use serde::{Deserialize, Deserializer};
+use std::fmt::{Debug, Formatter};
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use strut_core::{AppContext, AppSpindown, AppSpindownToken};
+use strut_factory::impl_deserialize_field;
+use strut_util::{Backoff, BackoffConfig};
+use tokio::runtime::Runtime;
+use tokio::select;
+
+/// Represents the output of a [service](crate::Launchpad::with_service) that
+/// can be interpreted as either a success or a failure.
+///
+/// The following types are supported out of the box:
+///
+/// - `()`: the service always succeeds.
+/// - [`Result<(), E>`]: the service fails in case of any error `E` that
+///   implements [`Debug`].
+pub trait ServiceOutput {
+    /// Interprets this output as the outcome of a single run of the service.
+    fn into_outcome(self) -> Result<(), ServiceFailure>;
+}
+
+/// Represents the reason why a single run of a service failed.
+#[derive(Debug)]
+pub enum ServiceFailure {
+    /// The service returned an error.
+    Error(Box<dyn Debug + Send>),
+    /// The service panicked.
+    Panic,
+}
+
+impl ServiceOutput for () {
+    fn into_outcome(self) -> Result<(), ServiceFailure> {
+        Ok(())
+    }
+}
+
+impl<E> ServiceOutput for Result<(), E>
+where
+    E: Debug + Send + 'static,
+{
+    fn into_outcome(self) -> Result<(), ServiceFailure> {
+        self.map_err(|error| ServiceFailure::Error(Box::new(error)))
+    }
+}
+
+/// Defines how a failed [service](crate::Launchpad::with_service) is
+/// restarted.
+///
+/// A service is restarted whenever it panics or returns an error, after a
+/// delay computed by an exponential [`Backoff`]. A service that completes
+/// successfully, or that stops because the global [`AppContext`] is
+/// terminated, is not restarted.
+///
+/// Once the service exhausts the [maximum number](RestartPolicy::max_restarts)
+/// of restarts (or the [max elapsed time](BackoffConfig::max_elapsed_time) of
+/// the backoff), the service gives up. The restarts are counted, and the
+/// backoff grows, within a single streak of failures: a run that stays up for
+/// at least [a while](RestartPolicy::healthy_after) before failing starts a
+/// new streak. If the service is
+/// [critical](RestartPolicy::critical), giving up terminates the global
+/// [`AppContext`], which shuts down the whole application.
+///
+/// This policy comes with a custom [`Deserialize`] implementation, so that it
+/// can be read from a custom configuration section.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RestartPolicy {
+    max_restarts: Option<usize>,
+    critical: bool,
+    backoff: BackoffConfig,
+    healthy_after: Duration,
+}
+
+impl Default for RestartPolicy {
+    fn default() -> Self {
+        Self {
+            max_restarts: None,
+            critical: false,
+            backoff: BackoffConfig::default(),
+            healthy_after: Self::default_healthy_after(),
+        }
+    }
+}
+
+impl RestartPolicy {
+    /// Creates a policy that never restarts the service.
+    pub fn never() -> Self {
+        Self::default().with_max_restarts(0)
+    }
+
+    /// Creates a policy that restarts the service indefinitely.
+    pub fn always() -> Self {
+        Self::default()
+    }
+
+    /// Limits the number of restarts, after which the service gives up.
+    pub fn with_max_restarts(self, max_restarts: usize) -> Self {
+        Self {
+            max_restarts: Some(max_restarts),
+            ..self
+        }
+    }
+
+    /// Marks the service as critical (or not), meaning that the whole
+    /// application terminates once the service gives up.
+    pub fn with_critical(self, critical: bool) -> Self {
+        Self { critical, ..self }
+    }
+
+    /// Replaces the configuration of the exponential backoff between restarts.
+    pub fn with_backoff(self, backoff: BackoffConfig) -> Self {
+        Self { backoff, ..self }
+    }
+
+    /// Specifies how long a run of the service must stay up before failing to
+    /// be considered healthy, which resets the count of restarts and the
+    /// backoff.
+    pub fn with_healthy_after(self, healthy_after: Duration) -> Self {
+        Self {
+            healthy_after,
+            ..self
+        }
+    }
+}
+
+impl RestartPolicy {
+    /// Returns the maximum number of restarts, if limited. Defaults to
+    /// [`None`] (unlimited).
+    pub fn max_restarts(&self) -> Option<usize> {
+        self.max_restarts
+    }
+
+    /// Reports whether giving up on the service terminates the whole
+    /// application. Defaults to `false`.
+    pub fn critical(&self) -> bool {
+        self.critical
+    }
+
+    /// Returns the configuration of the exponential backoff between restarts.
+    pub fn backoff(&self) -> &BackoffConfig {
+        &self.backoff
+    }
+
+    /// Returns how long a run of the service must stay up before failing to
+    /// reset the count of restarts and the backoff. Defaults to one minute.
+    pub fn healthy_after(&self) -> Duration {
+        self.healthy_after
+    }
+}
+
+impl RestartPolicy {
+    fn default_healthy_after() -> Duration {
+        Duration::from_secs(60)
+    }
+}
+
+impl AsRef<RestartPolicy> for RestartPolicy {
+    fn as_ref(&self) -> &RestartPolicy {
+        self
+    }
+}
+
+/// The type-erased future returned from the factory of a [`Service`].
+type ServiceFuture = Pin<Box<dyn Future<Output = Result<(), ServiceFailure>> + Send>>;
+
+/// The type-erased factory of a [`Service`].
+type ServiceFactory = Arc<dyn Fn() -> ServiceFuture + Send + Sync>;
+
+/// Represents a long-lived background workload registered on the
+/// [`Launchpad`](crate::Launchpad), along with its [`RestartPolicy`].
+pub(crate) struct Service {
+    name: Arc<str>,
+    factory: ServiceFactory,
+    policy: RestartPolicy,
+}
+
+impl Service {
+    /// Creates a new [`Service`] with the given name, which produces each run
+    /// of the service from the given factory.
+    pub(crate) fn new<F, S>(name: impl AsRef<str>, factory: F, policy: RestartPolicy) -> Self
+    where
+        F: Fn() -> S + Send + Sync + 'static,
+        S: Future + Send + 'static,
+        S::Output: ServiceOutput,
+    {
+        let factory: ServiceFactory = Arc::new(move || {
+            let future = factory();
+            Box::pin(async move { future.await.into_outcome() })
+        });
+
+        Self {
+            name: Arc::from(name.as_ref()),
+            factory,
+            policy,
+        }
+    }
+
+    /// Registers this service with [`AppSpindown`] and sends its supervisor
+    /// into background on the given [`Runtime`].
+    pub(crate) fn start(self, runtime: &Runtime) {
+        let spindown_token = AppSpindown::register(format!("service:{}", self.name));
+
+        runtime.spawn(self.supervise(spindown_token));
+    }
+
+    /// Main, long-running function that runs the service and restarts it
+    /// according to the [`RestartPolicy`], until the service completes, gives
+    /// up, or the global [`AppContext`] is terminated.
+    ///
+    /// Each run of the service is spawned as a separate task to catch panics.
+    /// Once the global [`AppContext`] is terminated, the current run is given
+    /// the chance to wind down on its own (within the spindown timeout).
+    async fn supervise(self, _spindown_token: AppSpindownToken) {
+        let backoff = Backoff::new(self.policy.backoff());
+        let mut restarts = 0;
+
+        loop {
+            // Run the service once
+            let started_at = Instant::now();
+            let outcome = match tokio::spawn((self.factory)()).await {
+                Ok(outcome) => outcome,
+                Err(_) => Err(ServiceFailure::Panic),
+            };
+
+            // Interpret the outcome
+            let _failure = match outcome {
+                _ if AppContext::is_terminated() => return,
+                Ok(()) => {
+                    #[cfg(feature = "tracing")]
+                    tracing::info!(service = %self.name, "Service completed");
+                    return;
+                }
+                Err(failure) => failure,
+            };
+
+            // A run that stayed up long enough starts a new streak of failures
+            if started_at.elapsed() >= self.policy.healthy_after() {
+                restarts = 0;
+                backoff.reset();
+            }
+
+            // Decide whether to give up
+            let delay = match self.policy.max_restarts() {
+                Some(max_restarts) if restarts >= max_restarts => None,
+                _ => backoff.next(),
+            };
+            let Some(delay) = delay else {
+                #[cfg(feature = "tracing")]
+                tracing::error!(
+                    alert = true,
+                    service = %self.name,
+                    restarts,
+                    critical = self.policy.critical(),
+                    failure = ?_failure,
+                    "Service failed and will not be restarted",
+                );
+
+                if self.policy.critical() {
+                    AppContext::terminate();
+                }
+
+                return;
+            };
+
+            #[cfg(feature = "tracing")]
+            tracing::warn!(
+                service = %self.name,
+                restarts,
+                delay = ?delay,
+                failure = ?_failure,
+                "Service failed and will be restarted",
+            );
+
+            // Wait before restarting, unless terminated meanwhile
+            select! {
+                biased;
+                _ = AppContext::terminated() => return,
+                _ = tokio::time::sleep(delay) => {},
+            }
+
+            restarts += 1;
+        }
+    }
+}
+
+const _: () = {
+    impl<'de> Deserialize<'de> for RestartPolicy {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            deserializer.deserialize_map(RestartPolicyVisitor)
+        }
+    }
+
+    struct RestartPolicyVisitor;
+
+    impl<'de> Visitor<'de> for RestartPolicyVisitor {
+        type Value = RestartPolicy;
+
+        fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
+            formatter.write_str("a map of restart policy")
+        }
+
+        fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+        where
+            A: MapAccess<'de>,
+        {
+            let mut max_restarts = None;
+            let mut critical = None;
+            let mut backoff = None;
+            let mut healthy_after = None;
+
+            while let Some(key) = map.next_key()? {
+                match key {
+                    RestartPolicyField::max_restarts => key.poll(&mut map, &mut max_restarts)?,
+                    RestartPolicyField::critical => key.poll(&mut map, &mut critical)?,
+                    RestartPolicyField::backoff => key.poll(&mut map, &mut backoff)?,
+                    RestartPolicyField::healthy_after => key.poll(&mut map, &mut healthy_after)?,
+                    RestartPolicyField::__ignore => map.next_value()?,
+                };
+            }
+
+            Ok(RestartPolicy {
+                max_restarts,
+                critical: critical.unwrap_or_default(),
+                backoff: backoff.unwrap_or_default(),
+                healthy_after: healthy_after.unwrap_or_else(RestartPolicy::default_healthy_after),
+            })
+        }
+    }
+
+    impl_deserialize_field!(
+        RestartPolicyField,
+        strut_deserialize::Slug::eq_as_slugs,
+        max_restarts | restarts,
+        critical,
+        backoff,
+        healthy_after | reset_after,
+    );
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::time::Duration;
+
+    #[test]
+    fn from_empty() {
+        // Given
+        let input = "{}";
+        let expected_output = RestartPolicy::default();
+
+        // When
+        let actual_output = serde_yml::from_str::<RestartPolicy>(input).unwrap();
+
+        // Then
+        assert_eq!(expected_output, actual_output);
+    }
+
+    #[test]
+    fn from_map_full() {
+        // Given
+        let input = r#"
+MaxRestarts: 3
+critical: true
+backoff:
+  max_interval: { secs: 5, nanos: 0 }
+healthy_after: { secs: 30, nanos: 0 }
+"#;
+        let expected_backoff =
+            serde_yml::from_str::<BackoffConfig>("max_interval: { secs: 5, nanos: 0 }");
+        let expected_output = RestartPolicy::default()
+            .with_max_restarts(3)
+            .with_critical(true)
+            .with_backoff(expected_backoff.unwrap())
+            .with_healthy_after(Duration::from_secs(30));
+
+        // When
+        let actual_output = serde_yml::from_str::<RestartPolicy>(input).unwrap();
+
+        // Then
+        assert_eq!(expected_output, actual_output);
+    }
+
+    #[test]
+    fn supervise_gives_up() {
+        // Given
+        let runs = Arc::new(AtomicUsize::new(0));
+        let backoff =
+            serde_yml::from_str::<BackoffConfig>("initial_interval: { secs: 0, nanos: 1000000 }")
+                .unwrap();
+        let policy = RestartPolicy::default()
+            .with_max_restarts(2)
+            .with_backoff(backoff);
+        let service = {
+            let runs = runs.clone();
+            Service::new(
+                "flaky",
+                move || {
+                    let runs = runs.clone();
+                    async move {
+                        if runs.fetch_add(1, Ordering::SeqCst) % 2 == 0 {
+                            panic!("flaky service panicked");
+                        }
+                        Err::<(), _>("flaky service failed")
+                    }
+                },
+                policy,
+            )
+        };
+        let runtime = Runtime::new().unwrap();
+
+        // When
+        runtime.block_on(async {
+            let spindown_token = AppSpindown::register("test:flaky");
+            tokio::time::timeout(Duration::from_secs(5), service.supervise(spindown_token))
+                .await
+                .unwrap();
+        });
+
+        // Then
+        assert_eq!(runs.load(Ordering::SeqCst), 3);
+        assert!(AppContext::is_alive());
+    }
+
+    #[test]
+    fn supervise_resets_after_healthy_run() {
+        // Given
+        let runs = Arc::new(AtomicUsize::new(0));
+        let backoff =
+            serde_yml::from_str::<BackoffConfig>("initial_interval: { secs: 0, nanos: 1000000 }")
+                .unwrap();
+        let policy = RestartPolicy::default()
+            .with_max_restarts(1)
+            .with_backoff(backoff)
+            .with_healthy_after(Duration::from_millis(50));
+        let service = {
+            let runs = runs.clone();
+            Service::new(
+                "recovering",
+                move || {
+                    let runs = runs.clone();
+                    async move {
+                        // The second run is healthy for a while before failing
+                        if runs.fetch_add(1, Ordering::SeqCst) == 1 {
+                            tokio::time::sleep(Duration::from_millis(100)).await;
+                        }
+                        Err::<(), _>("recovering service failed")
+                    }
+                },
+                policy,
+            )
+        };
+        let runtime = Runtime::new().unwrap();
+
+        // When
+        runtime.block_on(async {
+            let spindown_token = AppSpindown::register("test:recovering");
+            tokio::time::timeout(Duration::from_secs(5), service.supervise(spindown_token))
+                .await
+                .unwrap();
+        });
+
+        // Then (without the reset, the service would give up after two runs)
+        assert_eq!(runs.load(Ordering::SeqCst), 3);
+        assert!(AppContext::is_alive());
+    }
+}
@@ -1,4 +1,4 @@
-use crate::{AppConfig, DotEnv};
+use crate::{AppConfig, ConfigValidationReport, DotEnv};
 use config::ConfigBuilder;
 use strut_config::{Assembler, AssemblerChoices};
 
@@ -123,23 +123,29 @@ pub trait ConfigurationWiring {
         crate::AppLiveConfig::set_builder(builder);
     }
 
-    /// Builds and seeds the initial, immutable `AppConfig`.
+    /// Builds, validates, and seeds the initial, immutable `AppConfig`.
     ///
     /// This method takes ownership of the builder, builds the configuration, and
-    /// stores it in a static location for the lifetime of the application.
+    /// stores it in a static location for the lifetime of the application. If
+    /// the configuration is invalid, the report is passed to
+    /// [`reject_config`](ConfigurationWiring::reject_config).
     ///
     /// When the `config-async` feature is enabled, this method must create a
     /// temporary Tokio runtime to build the configuration, as the main
     /// application runtime has not yet been created.
     #[cfg(not(feature = "config-async"))]
     fn seed_initial_config(&self, builder: ConfigBuilder<DefaultState>) {
-        AppConfig::seed(builder);
+        if let Err(report) = AppConfig::try_seed(builder) {
+            self.reject_config(report);
+        }
     }
 
-    /// Builds and seeds the initial, immutable `AppConfig`.
+    /// Builds, validates, and seeds the initial, immutable `AppConfig`.
     ///
     /// This method takes ownership of the builder, builds the configuration, and
-    /// stores it in a static location for the lifetime of the application.
+    /// stores it in a static location for the lifetime of the application. If
+    /// the configuration is invalid, the report is passed to
+    /// [`reject_config`](ConfigurationWiring::reject_config).
     ///
     /// When the `config-async` feature is enabled, this method must create a
     /// temporary Tokio runtime to build the configuration, as the main
@@ -152,7 +158,19 @@ pub trait ConfigurationWiring {
             .build()
             .expect("it should be possible to build a temporary tokio runtime");
 
-        tmp_runtime.block_on(AppConfig::seed(builder));
+        if let Err(report) = tmp_runtime.block_on(AppConfig::try_seed(builder)) {
+            self.reject_config(report);
+        }
+    }
+
+    /// Refuses to start the application with an invalid configuration.
+    ///
+    /// The default implementation prints the readable summary of all errors
+    /// to the standard error and exits the process with the code `78`
+    /// (`EX_CONFIG`).
+    fn reject_config(&self, report: ConfigValidationReport) -> ! {
+        eprint!("{}", report);
+        std::process::exit(78);
     }
 
     /// Retrieves the now-initialized static `AppConfig`.
@@ -8,7 +8,8 @@ use tokio::runtime::Runtime;
 /// both the finalized [`AppConfig`] and the configured Tokio [`Runtime`].
 ///
 /// This stage is ideal for performing final checks or logging startup
-/// announcements.
+/// announcements. It is also where the built-in background subsystems (such as
+/// the health listener) are started.
 ///
 /// ## Customization example
 ///
@@ -45,13 +46,18 @@ pub trait PreflightWiring {
     fn run(&self, config: &'static AppConfig, runtime: &Runtime) {
         // Announce startup
         self.announce_startup(config, runtime);
+
+        // Start the health subsystem
+        #[cfg(feature = "health")]
+        self.start_health(config, runtime);
     }
 
     /// Announces that the application has started successfully.
     ///
     /// The default implementation logs a startup message using `tracing` that
-    /// includes the application's name, active profile, and replica information.
-    /// This log is only emitted if the `tracing` feature is enabled.
+    /// includes the application's name, active profile, and replica information,
+    /// followed by the [dot-env files](crate::DotEnv::report) that were loaded.
+    /// These logs are only emitted if the `tracing` feature is enabled.
     fn announce_startup(&self, _config: &'static AppConfig, _runtime: &Runtime) {
         #[cfg(feature = "tracing")]
         {
@@ -69,8 +75,29 @@ pub trait PreflightWiring {
                 replica_description,
                 strut_core::AppReplica::lifetime_id(),
             );
+
+            let dotenv_report = crate::DotEnv::report();
+            if !dotenv_report.is_empty() {
+                tracing::info!("Loaded environment variables from {}", dotenv_report);
+            }
         }
     }
+
+    /// Starts the built-in health subsystem in the background of the given
+    /// runtime.
+    ///
+    /// The default implementation starts an HTTP listener that answers the
+    /// liveness and readiness probes as reported by [`AppHealth`], unless it
+    /// is disabled in the [`HealthConfig`]. The listener shuts down gracefully
+    /// once the global [`AppContext`] is terminated.
+    ///
+    /// [`AppHealth`]: crate::AppHealth
+    /// [`HealthConfig`]: crate::HealthConfig
+    /// [`AppContext`]: strut_core::AppContext
+    #[cfg(feature = "health")]
+    fn start_health(&self, config: &'static AppConfig, runtime: &Runtime) {
+        crate::health::server::HealthServer::start(config.health(), runtime);
+    }
 }
 
 /// The default `PreflightWiring` implementation used by Strut.
@@ -1,5 +1,48 @@
-use crate::AppConfig;
-use tokio::runtime::Runtime;
+use crate::{AppConfig, RuntimeConfig};
+use strut_factory::{ConfigSchema, Deserialize as StrutDeserialize};
+use tokio::runtime::{Builder, Runtime};
+
+pub mod config;
+
+/// Represents the flavor of the Tokio [`Runtime`] to construct.
+#[derive(
+    Debug,
+    Default,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    StrutDeserialize,
+    ConfigSchema,
+)]
+#[strut(eq_fn = strut_deserialize::Slug::eq_as_slugs)]
+pub enum RuntimeFlavor {
+    /// A runtime that executes all tasks on the current thread.
+    #[strut(alias = "current", alias = "single_thread")]
+    CurrentThread,
+
+    /// A runtime that distributes tasks across a pool of worker threads.
+    #[default]
+    #[strut(alias = "multi")]
+    MultiThread,
+}
+
+/// Collects the choices that customize the Tokio [`Runtime`] constructed by
+/// the **runtime** wiring, as specified on the [`Launchpad`](crate::Launchpad).
+///
+/// The choices given here take precedence over the
+/// [`RuntimeConfig`] of the application configuration.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct RuntimeChoices {
+    /// If given, defines the flavor of the runtime.
+    pub flavor: Option<RuntimeFlavor>,
+    /// If given, defines how many worker threads a
+    /// [multi-thread](RuntimeFlavor::MultiThread) runtime should start.
+    pub worker_threads: Option<usize>,
+}
 
 /// Defines the **runtime wiring** stage of a Strut application.
 ///
@@ -17,7 +60,7 @@ use tokio::runtime::Runtime;
 /// You can replace the default wiring to use a different type of Tokio runtime.
 ///
 /// ```
-/// use strut::{App, AppConfig, RuntimeWiring};
+/// use strut::{App, AppConfig, RuntimeConfig, RuntimeWiring};
 /// use tokio::runtime::Runtime;
 ///
 /// fn main() {
@@ -33,7 +76,7 @@ use tokio::runtime::Runtime;
 /// struct CustomRuntimeWiring;
 ///
 /// impl RuntimeWiring for CustomRuntimeWiring {
-///     fn make_runtime(&self) -> Runtime {
+///     fn make_configured_runtime(&self, _config: &RuntimeConfig) -> Runtime {
 ///         // Build on current thread instead of multi-thread
 ///         tokio::runtime::Builder::new_current_thread()
 ///             .enable_all()
@@ -49,20 +92,21 @@ pub trait RuntimeWiring {
     /// including initializing `sentry` and `tracing` before finally creating the
     /// application's main Tokio [`Runtime`]. It is not typically necessary to
     /// override this method directly.
-    fn run(&self, _config: &'static AppConfig) -> Runtime {
+    fn run(&self, config: &'static AppConfig, choices: &RuntimeChoices) -> Runtime {
         // Initialize the Sentry integration
         #[cfg(feature = "sentry")]
-        let sentry_guard = self.init_sentry(_config);
+        let sentry_guard = self.init_sentry(config);
 
         // Initialize the `tracing` crate
         #[cfg(feature = "tracing")]
         {
-            let tracing_subscriber = self.make_tracing_subscriber(_config);
+            let tracing_subscriber = self.make_tracing_subscriber(config);
             self.init_tracing_subscriber(tracing_subscriber);
         }
 
         // Make the application’s main runtime
-        let runtime = self.make_runtime();
+        let runtime_config = config.runtime().resolve(choices);
+        let runtime = self.make_configured_runtime(&runtime_config);
 
         // With the runtime constructed, schedule flushing of Sentry events
         #[cfg(feature = "sentry")]
@@ -146,13 +190,53 @@ pub trait RuntimeWiring {
         subscriber.init()
     }
 
-    /// Creates the main Tokio [`Runtime`] for the application.
+    /// Creates a Tokio [`Runtime`] with the default [`RuntimeConfig`].
     ///
-    /// The default implementation builds a multithreaded runtime with all
-    /// features enabled. Override this method to use a different kind of runtime
-    /// (e.g., `new_current_thread`) or to customize its configuration.
+    /// The default implementation delegates to
+    /// [`make_configured_runtime`](RuntimeWiring::make_configured_runtime).
+    /// This method is no longer called by [`run`](RuntimeWiring::run), which
+    /// passes the configuration of the runtime to `make_configured_runtime`
+    /// instead: override that method to customize the runtime.
     fn make_runtime(&self) -> Runtime {
-        tokio::runtime::Builder::new_multi_thread()
+        self.make_configured_runtime(&RuntimeConfig::default())
+    }
+
+    /// Creates the main Tokio [`Runtime`] for the application.
+    ///
+    /// The default implementation builds a runtime of the configured
+    /// [flavor](RuntimeFlavor) with all features enabled, honoring every
+    /// setting of the given (already [resolved](RuntimeConfig::resolve))
+    /// [`RuntimeConfig`]. Override this method to use a different kind of
+    /// runtime or to customize its configuration further.
+    fn make_configured_runtime(&self, config: &RuntimeConfig) -> Runtime {
+        let mut builder = match config.flavor().unwrap_or_default() {
+            RuntimeFlavor::CurrentThread => Builder::new_current_thread(),
+            RuntimeFlavor::MultiThread => {
+                let mut builder = Builder::new_multi_thread();
+                if let Some(worker_threads) = config.worker_threads() {
+                    builder.worker_threads(worker_threads);
+                }
+                builder
+            }
+        };
+
+        if let Some(max_blocking_threads) = config.max_blocking_threads() {
+            builder.max_blocking_threads(max_blocking_threads);
+        }
+
+        if let Some(thread_name) = config.thread_name() {
+            builder.thread_name(thread_name);
+        }
+
+        if let Some(thread_stack_size) = config.thread_stack_size() {
+            builder.thread_stack_size(thread_stack_size);
+        }
+
+        if let Some(thread_keep_alive) = config.thread_keep_alive() {
+            builder.thread_keep_alive(thread_keep_alive);
+        }
+
+        builder
             .enable_all()
             .build()
             .expect("it should be possible to build a tokio runtime")
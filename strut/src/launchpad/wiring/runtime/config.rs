@@ -0,0 +1,360 @@
+use crate::{RuntimeChoices, RuntimeFlavor};
+use humantime::parse_duration;
+use serde::de::{Error, IgnoredAny, MapAccess, Visitor};
+use serde::{Deserialize, Deserializer};
+use std::fmt::Formatter;
+use std::sync::Arc;
+use std::time::Duration;
+use strut_deserialize::{ConfigSchema, Schema};
+use strut_factory::impl_deserialize_field;
+
+/// Represents the application-level configuration section that covers the
+/// Tokio [`Runtime`](tokio::runtime::Runtime) constructed by the default
+/// [`RuntimeWiring`](crate::RuntimeWiring).
+///
+/// Every setting is optional: a missing setting falls back on the Tokio’s own
+/// default. The [`RuntimeChoices`] given explicitly on the
+/// [`Launchpad`](crate::Launchpad) (or in the `#[strut::main]` attribute) take
+/// precedence over this config.
+///
+/// Like any other section, this config may be overridden by environment
+/// variables. Multi-word settings are reachable either through their
+/// single-word aliases (e.g., `APP_RUNTIME_WORKERS`), or through their full
+/// names once the environment variables are
+/// [mapped against the schema](crate::Launchpad::with_env_schema) (e.g.,
+/// `APP_RUNTIME_WORKER_THREADS`).
+///
+/// This config comes with a custom [`Deserialize`] implementation, to support
+/// more human-oriented textual configuration.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct RuntimeConfig {
+    flavor: Option<RuntimeFlavor>,
+    worker_threads: Option<usize>,
+    max_blocking_threads: Option<usize>,
+    thread_name: Option<Arc<str>>,
+    thread_stack_size: Option<usize>,
+    thread_keep_alive: Option<Duration>,
+}
+
+impl RuntimeConfig {
+    /// Returns the [flavor](RuntimeFlavor) of the runtime, if configured.
+    pub fn flavor(&self) -> Option<RuntimeFlavor> {
+        self.flavor
+    }
+
+    /// Returns the number of worker threads of a
+    /// [multi-thread](RuntimeFlavor::MultiThread) runtime, if configured.
+    pub fn worker_threads(&self) -> Option<usize> {
+        self.worker_threads
+    }
+
+    /// Returns the limit of additional threads spawned for blocking
+    /// operations, if configured.
+    pub fn max_blocking_threads(&self) -> Option<usize> {
+        self.max_blocking_threads
+    }
+
+    /// Returns the name of the threads spawned by the runtime, if configured.
+    pub fn thread_name(&self) -> Option<&str> {
+        self.thread_name.as_deref()
+    }
+
+    /// Returns the stack size (in bytes) of the threads spawned by the
+    /// runtime, if configured.
+    pub fn thread_stack_size(&self) -> Option<usize> {
+        self.thread_stack_size
+    }
+
+    /// Returns for how long a blocking thread is kept alive without work, if
+    /// configured.
+    pub fn thread_keep_alive(&self) -> Option<Duration> {
+        self.thread_keep_alive
+    }
+}
+
+impl RuntimeConfig {
+    /// Resolves the effective configuration of the runtime by overriding the
+    /// settings of this config with the explicitly given [`RuntimeChoices`].
+    pub fn resolve(&self, choices: &RuntimeChoices) -> Self {
+        let mut resolved = self.clone();
+
+        resolved.flavor = choices.flavor.or(self.flavor);
+        resolved.worker_threads = choices.worker_threads.or(self.worker_threads);
+
+        resolved
+    }
+}
+
+impl AsRef<RuntimeConfig> for RuntimeConfig {
+    fn as_ref(&self) -> &RuntimeConfig {
+        self
+    }
+}
+
+const _: () = {
+    impl<'de> Deserialize<'de> for RuntimeConfig {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            deserializer.deserialize_map(RuntimeConfigVisitor)
+        }
+    }
+
+    struct RuntimeConfigVisitor;
+
+    impl<'de> Visitor<'de> for RuntimeConfigVisitor {
+        type Value = RuntimeConfig;
+
+        fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
+            formatter.write_str("a map of runtime configuration")
+        }
+
+        fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+        where
+            A: MapAccess<'de>,
+        {
+            let mut flavor = None;
+            let mut worker_threads = None;
+            let mut max_blocking_threads = None;
+            let mut thread_name: Option<String> = None;
+            let mut thread_stack_size = None;
+            let mut thread_keep_alive = None;
+
+            while let Some(key) = map.next_key()? {
+                match key {
+                    RuntimeConfigField::flavor => key.poll(&mut map, &mut flavor)?,
+                    RuntimeConfigField::worker_threads => {
+                        key.poll(&mut map, &mut worker_threads)?
+                    }
+                    RuntimeConfigField::max_blocking_threads => {
+                        key.poll(&mut map, &mut max_blocking_threads)?
+                    }
+                    RuntimeConfigField::thread_name => key.poll(&mut map, &mut thread_name)?,
+                    RuntimeConfigField::thread_stack_size => {
+                        key.poll(&mut map, &mut thread_stack_size)?
+                    }
+                    RuntimeConfigField::thread_keep_alive => {
+                        let duration_string = map.next_value::<String>()?;
+                        let duration = parse_duration(&duration_string).map_err(Error::custom)?;
+                        thread_keep_alive = Some(duration);
+                        IgnoredAny
+                    }
+                    RuntimeConfigField::__ignore => map.next_value()?,
+                };
+            }
+
+            if worker_threads == Some(0) {
+                return Err(Error::custom(
+                    "the number of worker threads must be positive",
+                ));
+            }
+
+            if max_blocking_threads == Some(0) {
+                return Err(Error::custom(
+                    "the maximum number of blocking threads must be positive",
+                ));
+            }
+
+            Ok(RuntimeConfig {
+                flavor,
+                worker_threads,
+                max_blocking_threads,
+                thread_name: thread_name.map(Arc::from),
+                thread_stack_size,
+                thread_keep_alive,
+            })
+        }
+    }
+
+    impl_deserialize_field!(
+        RuntimeConfigField,
+        strut_deserialize::Slug::eq_as_slugs,
+        flavor | kind,
+        worker_threads | workers,
+        max_blocking_threads | blocking_threads,
+        thread_name,
+        thread_stack_size | stack_size,
+        thread_keep_alive | keep_alive,
+    );
+
+    impl ConfigSchema for RuntimeConfig {
+        fn config_schema() -> Schema {
+            Schema::object()
+                .with_description("The Tokio runtime configuration.")
+                .with_field(
+                    RuntimeConfigField::flavor.keys(),
+                    RuntimeFlavor::config_schema(),
+                )
+                .with_field(
+                    RuntimeConfigField::worker_threads.keys(),
+                    Schema::positive(),
+                )
+                .with_field(
+                    RuntimeConfigField::max_blocking_threads.keys(),
+                    Schema::positive(),
+                )
+                .with_field(
+                    RuntimeConfigField::thread_name.keys(),
+                    String::config_schema(),
+                )
+                .with_field(
+                    RuntimeConfigField::thread_stack_size.keys(),
+                    usize::config_schema(),
+                )
+                .with_field(
+                    RuntimeConfigField::thread_keep_alive.keys(),
+                    Duration::config_schema(),
+                )
+        }
+    }
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn from_empty() {
+        // Given
+        let input = "{}";
+        let expected_output = RuntimeConfig::default();
+
+        // When
+        let actual_output = serde_yml::from_str::<RuntimeConfig>(input).unwrap();
+
+        // Then
+        assert_eq!(expected_output, actual_output);
+    }
+
+    #[test]
+    fn from_map_full() {
+        // Given
+        let input = r#"
+flavor: current-thread
+workers: 3
+MaxBlockingThreads: 16
+thread_name: billing-worker
+stack_size: 4194304
+thread_keep_alive: 15s
+"#;
+        let expected_output = RuntimeConfig {
+            flavor: Some(RuntimeFlavor::CurrentThread),
+            worker_threads: Some(3),
+            max_blocking_threads: Some(16),
+            thread_name: Some(Arc::from("billing-worker")),
+            thread_stack_size: Some(4194304),
+            thread_keep_alive: Some(Duration::from_secs(15)),
+        };
+
+        // When
+        let actual_output = serde_yml::from_str::<RuntimeConfig>(input).unwrap();
+
+        // Then
+        assert_eq!(expected_output, actual_output);
+    }
+
+    #[test]
+    fn from_map_zero_workers() {
+        // Given
+        let input = "worker_threads: 0";
+
+        // When
+        let result = serde_yml::from_str::<RuntimeConfig>(input);
+
+        // Then
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn from_config_strings() {
+        // Given
+        let config = config::Config::builder()
+            .set_override("runtime.workers", "8")
+            .unwrap()
+            .set_override("runtime.keep_alive", "1s 500ms")
+            .unwrap()
+            .build()
+            .unwrap();
+        let expected_output = RuntimeConfig {
+            worker_threads: Some(8),
+            thread_keep_alive: Some(Duration::from_millis(1500)),
+            ..RuntimeConfig::default()
+        };
+
+        // When
+        let actual_output = config.get::<RuntimeConfig>("runtime").unwrap();
+
+        // Then
+        assert_eq!(expected_output, actual_output);
+    }
+
+    #[test]
+    fn from_config_invalid() {
+        // Given
+        let config = config::Config::builder()
+            .set_override("runtime.workers", "many")
+            .unwrap()
+            .build()
+            .unwrap();
+
+        // When
+        let result = config.get::<RuntimeConfig>("runtime");
+
+        // Then
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn resolve_choices_first() {
+        // Given
+        let config = RuntimeConfig {
+            flavor: Some(RuntimeFlavor::CurrentThread),
+            worker_threads: Some(2),
+            thread_name: Some(Arc::from("from-file")),
+            ..RuntimeConfig::default()
+        };
+        let choices = RuntimeChoices {
+            flavor: Some(RuntimeFlavor::MultiThread),
+            worker_threads: None,
+        };
+        let expected_output = RuntimeConfig {
+            flavor: Some(RuntimeFlavor::MultiThread),
+            worker_threads: Some(2),
+            thread_name: Some(Arc::from("from-file")),
+            ..RuntimeConfig::default()
+        };
+
+        // When
+        let actual_output = config.resolve(&choices);
+
+        // Then
+        assert_eq!(expected_output, actual_output);
+    }
+
+    #[test]
+    fn config_schema() {
+        // When
+        let schema = RuntimeConfig::config_schema();
+        let properties = &schema.as_json()["properties"];
+
+        // Then
+        assert_eq!(properties["flavor"], properties["kind"]);
+        assert_eq!(
+            properties["flavor"]["enum"],
+            serde_json::json!([
+                "current_thread",
+                "current",
+                "single_thread",
+                "multi_thread",
+                "multi"
+            ]),
+        );
+        assert_eq!(properties["workers"]["minimum"], 1);
+        assert_eq!(
+            properties["keep_alive"],
+            serde_json::Value::from(Duration::config_schema()),
+        );
+    }
+}
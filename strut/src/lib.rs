@@ -13,8 +13,21 @@ mod facade {
         #[cfg(feature = "config-live")]
         pub mod live;
 
+        /// Implements the [`RemoteConfig`] and its providers.
+        #[cfg(feature = "config-remote")]
+        pub mod remote;
+
         /// Implements the custom [`AppConfigError`] type.
         pub mod error;
+
+        /// Implements the registry of custom configuration sections.
+        pub mod registry;
+
+        /// Implements the eager validation of the [`AppConfig`].
+        pub mod validation;
+
+        /// Implements the detection of unknown keys in the [`AppConfig`].
+        pub mod unknown;
     }
 
     /// Implements the [`DotEnv`] facade.
@@ -38,11 +51,17 @@ pub use self::facade::config::error::AppConfigError;
 pub use self::facade::config::initial::AppConfig;
 #[cfg(feature = "config-live")]
 pub use self::facade::config::live::AppLiveConfig;
-
+#[cfg(feature = "config-remote")]
+pub use self::facade::config::remote::http::HttpConfigProvider;
+#[cfg(feature = "config-remote")]
+pub use self::facade::config::remote::{
+    RemoteConfig, RemoteConfigError, RemoteConfigProvider, RemoteFetchError,
+};
+pub use self::facade::config::unknown::UnknownKeyPolicy;
+pub use self::facade::config::validation::{ConfigValidationFailure, ConfigValidationReport};
 
 /// Re-exports the [`DotEnv`] facade.
-pub use self::facade::dotenv::DotEnv;
-
+pub use self::facade::dotenv::{DotEnv, DotEnvError, DotEnvReport};
 
 /// Re-exports the [`Database`] facade.
 #[cfg(any(
@@ -52,30 +71,24 @@ pub use self::facade::dotenv::DotEnv;
 ))]
 pub use self::facade::database::Database;
 
-
 /// Re-exports the [`RabbitMq`] facade.
 #[cfg(feature = "rabbitmq")]
 pub use self::facade::rabbitmq::RabbitMq;
 
-
 /// Re-exports the public API of `strut-core` in the root of this crate for
 /// convenience.
 pub use strut_core::*;
 
-
 /// Re-exports the public API of `tokio` for convenience.
 pub use tokio;
 
-
 /// Re-exports the public API of `strut-config` for convenience.
 pub use strut_config as config;
 
-
 /// Partly re-exports the public API of `tracing` for convenience.
 #[cfg(feature = "tracing")]
 pub use tracing;
 
-
 /// Re-exports the public API of `strut-database` for convenience.
 #[cfg(any(
     feature = "database-mysql",
@@ -84,18 +97,33 @@ pub use tracing;
 ))]
 pub use strut_database as database;
 
-
 /// Re-exports the public API of `strut-rabbitmq` for convenience.
 #[cfg(feature = "rabbitmq")]
 pub use strut_rabbitmq as rabbitmq;
 
+/// Implements the built-in health subsystem.
+#[cfg(feature = "health")]
+mod health;
+#[cfg(feature = "health")]
+pub use self::health::{AppHealth, config::HealthConfig};
+#[cfg(feature = "health")]
+pub use strut_sync::{Gate, Latch};
 
 /// Implements the [`Launchpad`] utility for building an [`App`].
 mod launchpad;
+pub use self::launchpad::Launchpad;
+pub use self::launchpad::exit::{MainExit, MainOutput};
+pub use self::launchpad::service::{RestartPolicy, ServiceFailure, ServiceOutput};
 pub use self::launchpad::wiring::configuration::ConfigurationWiring;
 pub use self::launchpad::wiring::preflight::PreflightWiring;
-pub use self::launchpad::wiring::runtime::RuntimeWiring;
-pub use self::launchpad::Launchpad;
+pub use self::launchpad::wiring::runtime::config::RuntimeConfig;
+pub use self::launchpad::wiring::runtime::{RuntimeChoices, RuntimeFlavor, RuntimeWiring};
+pub use strut_util::BackoffConfig;
+
+/// Implements the [`TestApp`](testing::TestApp) harness for booting
+/// applications in tests.
+#[cfg(feature = "testing")]
+pub mod testing;
 
 /// Implements the [`App`] facade.
 mod app;
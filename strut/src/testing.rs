@@ -0,0 +1,427 @@
+use crate::facade::config::initial::statics::StaticInitialConfig;
+use crate::{AppConfig, AppContext, AppProfile, AppReplica, AppSpindown, MainOutput};
+use config::{ConfigBuilder, File, FileFormat};
+use serde::de::DeserializeOwned;
+use std::env;
+use std::process::Command;
+
+#[cfg(feature = "config-async")]
+use config::builder::AsyncState;
+#[cfg(not(feature = "config-async"))]
+use config::builder::DefaultState;
+
+/// The environment variable that marks the child process in which an
+/// [isolated](TestApp::with_isolation) test is re-run, and carries the exact
+/// path of that test.
+const ISOLATED_ENV_VAR: &str = "STRUT_TEST_ISOLATED";
+
+/// The default spindown timeout in tests, which is shorter than in
+/// applications, to fail fast on lingering workloads.
+const DEFAULT_SPINDOWN_TIMEOUT_SECS: u64 = 1;
+
+/// Boots a Strut application inside a test, with an in-memory configuration
+/// document and an explicit profile and replica index, and runs a test future
+/// under the global [`AppContext`].
+///
+/// Once the test future completes, the context is
+/// [terminated](AppContext::terminate), and the test fails unless every
+/// workload registered with [`AppSpindown`] completes within the spindown
+/// timeout. The test also fails if the future outputs a failure (see
+/// [`MainOutput`]).
+///
+/// ## Isolation
+///
+/// The [`AppConfig`], [`AppProfile`], [`AppReplica`], [`AppContext`], and
+/// [`AppSpindown`] are process-global, and can be set up only once per
+/// process. To let every test boot its own application, the test is re-run in
+/// a child process (the same test binary, filtered to the exact path of the
+/// current test, which is also passed to the child via an environment
+/// variable), and the application is booted only there. The output of the
+/// child process is printed by the parent, and a non-zero exit status of the
+/// child fails the test in the parent. This is transparent to the test, whose body simply runs twice: up
+/// to [`run`](TestApp::run) in the parent, and fully in the child.
+///
+/// The isolation may be [disabled](TestApp::with_isolation) where each test
+/// already runs in its own process (e.g., under `cargo nextest`). Tests marked
+/// with `#[should_panic]` are not supported with the isolation: the expected
+/// panic happens in the child process, which the parent then sees as a pass.
+///
+/// The configuration is read only from the given document: neither the
+/// config files, nor the environment variables, nor the dot-env files are
+/// consulted. The wiring stages of the [`Launchpad`](crate::Launchpad) are not
+/// run either.
+///
+/// ## Example
+///
+/// Typically, in the body of a `#[test]` function:
+///
+/// ```
+/// use strut::testing::TestApp;
+/// use strut::{AppConfig, AppProfile};
+///
+/// TestApp::new()
+///     .with_config_toml("name = 'billing'")
+///     .with_profile("staging")
+///     .run(async {
+///         assert_eq!(AppConfig::get().name(), "billing");
+///         assert!(AppProfile::active_is("staging"));
+///     });
+/// ```
+pub struct TestApp {
+    document: String,
+    format: FileFormat,
+    profile: Option<String>,
+    replica_index: Option<usize>,
+    spindown_timeout_secs: u64,
+    isolation_enabled: bool,
+    registrations: Vec<Box<dyn FnOnce()>>,
+}
+
+impl TestApp {
+    /// Creates a new [`TestApp`] with an empty configuration document, the
+    /// default profile and replica, and the isolation enabled.
+    pub fn new() -> Self {
+        Self {
+            document: String::new(),
+            format: FileFormat::Toml,
+            profile: None,
+            replica_index: None,
+            spindown_timeout_secs: DEFAULT_SPINDOWN_TIMEOUT_SECS,
+            isolation_enabled: true,
+            registrations: Vec::new(),
+        }
+    }
+
+    /// Specifies the configuration document in the TOML format.
+    pub fn with_config_toml(self, document: impl Into<String>) -> Self {
+        self.with_config(document, FileFormat::Toml)
+    }
+
+    /// Specifies the configuration document in the YAML format.
+    pub fn with_config_yaml(self, document: impl Into<String>) -> Self {
+        self.with_config(document, FileFormat::Yaml)
+    }
+
+    /// Specifies the configuration document in the given format.
+    pub fn with_config(self, document: impl Into<String>, format: FileFormat) -> Self {
+        Self {
+            document: document.into(),
+            format,
+            ..self
+        }
+    }
+
+    /// Specifies the active [profiles](AppProfile::set_active_spec) (e.g.,
+    /// `"prod"` or `"canary:staging,eu-west"`).
+    pub fn with_profile(self, spec: impl Into<String>) -> Self {
+        Self {
+            profile: Some(spec.into()),
+            ..self
+        }
+    }
+
+    /// Specifies the [replica index](AppReplica::set_index).
+    pub fn with_replica_index(self, index: usize) -> Self {
+        Self {
+            replica_index: Some(index),
+            ..self
+        }
+    }
+
+    /// Specifies the [spindown timeout](AppSpindown::set_timeout_secs), within
+    /// which all registered workloads must complete once the test future
+    /// completes. Defaults to one second.
+    pub fn with_spindown_timeout_secs(self, timeout_secs: u64) -> Self {
+        Self {
+            spindown_timeout_secs: timeout_secs,
+            ..self
+        }
+    }
+
+    /// [Registers](AppConfig::register) a custom configuration section, same
+    /// as [`Launchpad::with_config_section`](crate::Launchpad::with_config_section).
+    pub fn with_config_section<T>(mut self, key: impl AsRef<str>) -> Self
+    where
+        T: DeserializeOwned + Default + Send + Sync + 'static,
+    {
+        let key = key.as_ref().to_string();
+        self.registrations
+            .push(Box::new(move || AppConfig::register::<T>(key)));
+
+        self
+    }
+
+    /// Enables or disables re-running the test in a child process (see
+    /// [isolation](TestApp#isolation)). Enabled by default.
+    pub fn with_isolation(self, enabled: bool) -> Self {
+        Self {
+            isolation_enabled: enabled,
+            ..self
+        }
+    }
+}
+
+impl Default for TestApp {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TestApp {
+    /// Boots the application and runs the given test future to completion on
+    /// a fresh multi-threaded Tokio runtime, then terminates the
+    /// [`AppContext`] and waits for the [`AppSpindown`].
+    ///
+    /// # Panics
+    ///
+    /// Panics (failing the test) if the test future panics or outputs a
+    /// failure, if the configuration document is invalid, if the spindown
+    /// times out, or if an application has already been booted in this
+    /// process.
+    pub fn run<F>(self, test: F)
+    where
+        F: Future,
+        F::Output: MainOutput,
+    {
+        // In the child process, boot only in the exact test re-run by the parent
+        if let Ok(path) = env::var(ISOLATED_ENV_VAR) {
+            if Self::test_name().as_deref() != Some(path.as_str()) {
+                panic!(
+                    "the child process should re-run only the test '{}', not '{}'",
+                    path,
+                    Self::test_name().unwrap_or_default(),
+                );
+            }
+
+            return self.run_in_process(test);
+        }
+
+        match Self::test_name() {
+            Some(path) if self.isolation_enabled => Self::run_isolated(&path),
+            _ => self.run_in_process(test),
+        }
+    }
+
+    /// Reports the path of the currently running test (e.g.,
+    /// `billing::tests::charges`), if known. The test harness names the thread
+    /// of every test after its path.
+    fn test_name() -> Option<String> {
+        std::thread::current()
+            .name()
+            .filter(|name| *name != "main")
+            .map(ToString::to_string)
+    }
+
+    /// Re-runs the test with the given path in a child process, failing if
+    /// the child exits with a non-zero status.
+    fn run_isolated(path: &str) {
+        let program = env::current_exe()
+            .expect("it should be possible to locate the currently running test binary");
+
+        let output = Command::new(program)
+            .args([
+                path,
+                "--exact",
+                "--include-ignored",
+                "--nocapture",
+                "--test-threads=1",
+            ])
+            .env(ISOLATED_ENV_VAR, path)
+            .output()
+            .unwrap_or_else(|error| {
+                panic!(
+                    "failed to re-run the test '{}' in a child process: {}",
+                    path, error,
+                )
+            });
+
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        let stderr = String::from_utf8_lossy(&output.stderr);
+
+        if !output.status.success() {
+            panic!(
+                "the test '{}' failed in the child process ({}):\n{}{}",
+                path, output.status, stdout, stderr,
+            );
+        }
+
+        print!("{}", stdout);
+        eprint!("{}", stderr);
+    }
+
+    /// Boots the application in the current process and runs the given test
+    /// future.
+    fn run_in_process<F>(self, test: F)
+    where
+        F: Future,
+        F::Output: MainOutput,
+    {
+        if StaticInitialConfig::is_seeded() {
+            panic!(
+                "an application has already been booted in this process; enable the isolation to boot one per test",
+            );
+        }
+
+        // Set up the process-global state
+        AppSpindown::set_timeout_secs(self.spindown_timeout_secs);
+        if let Some(spec) = self.profile {
+            if let Err(error) = AppProfile::validate_spec(&spec) {
+                panic!("invalid test profile '{}': {}", spec, error);
+            }
+            if AppProfile::set_active_spec(spec).is_err() {
+                panic!("the profile cannot be changed after it is accessed");
+            }
+        }
+        if let Some(index) = self.replica_index {
+            if AppReplica::set_index(index).is_err() {
+                panic!("the replica index cannot be changed after it is accessed");
+            }
+        }
+        for registration in self.registrations {
+            registration();
+        }
+
+        let runtime = tokio::runtime::Builder::new_multi_thread()
+            .enable_all()
+            .build()
+            .expect("it should be possible to build a tokio runtime for the test");
+
+        let builder = Self::make_config_builder(&self.document, self.format);
+
+        #[cfg(feature = "config-live")]
+        crate::AppLiveConfig::set_builder(builder.clone());
+
+        #[cfg(not(feature = "config-async"))]
+        let seeded = AppConfig::try_seed(builder);
+        #[cfg(feature = "config-async")]
+        let seeded = runtime.block_on(AppConfig::try_seed(builder));
+
+        if let Err(report) = seeded {
+            panic!("the test configuration is invalid:\n{}", report);
+        }
+
+        let code = runtime.block_on(async {
+            let exit = test.await.into_exit();
+
+            AppContext::terminate();
+
+            if let Err(timeout) = AppSpindown::try_completed().await {
+                panic!("the application did not spin down cleanly: {}", timeout);
+            }
+
+            exit.resolve(&[])
+        });
+
+        if code != 0 {
+            panic!("the test failed with the exit code {}", code);
+        }
+    }
+
+    /// Creates the `ConfigBuilder` that reads only the given document.
+    #[cfg(not(feature = "config-async"))]
+    fn make_config_builder(document: &str, format: FileFormat) -> ConfigBuilder<DefaultState> {
+        ConfigBuilder::<DefaultState>::default().add_source(File::from_str(document, format))
+    }
+
+    /// Creates the `ConfigBuilder` that reads only the given document.
+    #[cfg(feature = "config-async")]
+    fn make_config_builder(document: &str, format: FileFormat) -> ConfigBuilder<AsyncState> {
+        ConfigBuilder::<AsyncState>::default().add_source(File::from_str(document, format))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+    use serde::Deserialize;
+    use std::panic::{self, AssertUnwindSafe};
+
+    #[derive(Debug, Default, Deserialize)]
+    struct BillingConfig {
+        currency: String,
+    }
+
+    /// Re-runs the given (ignored) test in a child process, and returns the
+    /// message of the resulting panic.
+    fn run_failing(name: &str) -> String {
+        let error = panic::catch_unwind(AssertUnwindSafe(|| TestApp::run_isolated(name)))
+            .expect_err("the test should fail in the child process");
+
+        error.downcast::<String>().map(|message| *message).unwrap()
+    }
+
+    /// Reports whether this is the child process of an isolated test.
+    fn is_isolated() -> bool {
+        env::var_os(ISOLATED_ENV_VAR).is_some()
+    }
+
+    #[test]
+    fn boots_app() {
+        TestApp::new()
+            .with_config_yaml("name: billing\nbilling:\n  currency: EUR\n")
+            .with_profile("canary:staging")
+            .with_replica_index(3)
+            .with_config_section::<BillingConfig>("billing")
+            .run(async {
+                // Given
+                let token = AppSpindown::register("test-workload");
+                tokio::spawn(async move {
+                    AppContext::terminated().await;
+                    token.punch_out();
+                });
+
+                // When
+                let config = AppConfig::get();
+                let billing = AppConfig::registered::<BillingConfig>("billing");
+
+                // Then
+                assert_eq!(config.name(), "billing");
+                assert_eq!(billing.currency, "EUR");
+                assert_eq!(AppProfile::active().as_str(), "canary");
+                assert!(AppProfile::active_is("staging"));
+                assert_eq!(AppReplica::index(), Some(3));
+            });
+    }
+
+    #[test]
+    fn fails_lingering_workload() {
+        // When
+        let message = run_failing("testing::tests::lingering_workload");
+
+        // Then
+        assert!(message.contains("the application did not spin down cleanly: failed to fully spin down all workloads within the timeout: 0 completed, 1 timed out"));
+    }
+
+    #[test]
+    #[ignore = "run by fails_lingering_workload"]
+    fn lingering_workload() {
+        if !is_isolated() {
+            return;
+        }
+
+        TestApp::new().run(async {
+            // The token is never punched out
+            std::mem::forget(AppSpindown::register("lingering-workload"));
+        });
+    }
+
+    #[test]
+    fn fails_failed_output() {
+        // When
+        let message = run_failing("testing::tests::failed_output");
+
+        // Then
+        assert!(message.contains("the test failed with the exit code 1"));
+    }
+
+    #[test]
+    #[ignore = "run by fails_failed_output"]
+    fn failed_output() {
+        if !is_isolated() {
+            return;
+        }
+
+        TestApp::new().run(async { Err::<(), _>("failure") });
+    }
+}
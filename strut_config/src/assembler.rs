@@ -1,8 +1,29 @@
-use crate::Scanner;
+use crate::overlay::OverlaidEnvironment;
+use crate::{ConfigReport, Interpolated, Scanner, SchemaEnvironment};
 use config::builder::{AsyncState, DefaultState};
-use config::{ConfigBuilder, Environment};
+use config::{ConfigBuilder, ConfigError, Environment, Map, Source, Value};
+use strut_deserialize::Schema;
 
 /// A small facade for assembling the opinionated version of [`ConfigBuilder`].
+///
+/// The sources are added to the builder in the following order, where later
+/// sources take precedence over earlier ones:
+///
+/// 1. The [default sources](AssemblerChoices::defaults).
+/// 2. The config files, as found by the [`Scanner`].
+/// 3. The [custom sources](AssemblerChoices::sources).
+/// 4. The [literal sources](AssemblerChoices::literal_sources).
+/// 5. The environment variables, if [enabled](AssemblerChoices::env_enabled),
+///    mapped against the [schema](AssemblerChoices::env_schema), if given.
+/// 6. The [overrides](AssemblerChoices::overrides) of individual keys.
+///
+/// The default sources, the config files, and the custom sources are
+/// [`Interpolated`], so that string values may refer to files (e.g., mounted
+/// secrets) and environment variables, such as
+/// `${file:/run/secrets/db_password}` or `${env:PORT:-5432}`. The values of
+/// the literal sources, the environment variables, and the overrides are
+/// taken literally, so that whoever controls them cannot make them read a
+/// file or an environment variable.
 pub struct Assembler;
 
 /// A simple preference collection accepted by the [`Assembler`] facade.
@@ -17,6 +38,24 @@ pub struct AssemblerChoices {
     /// If given, defines which separator to pass to the [`Environment::prefix`]
     /// method.
     pub env_separator: Option<String>,
+    /// If given, the environment variables are mapped onto the keys known
+    /// from this [`Schema`] by the [`SchemaEnvironment`] source, instead of
+    /// being split on the separator by the [`Environment`] source.
+    pub env_schema: Option<Schema>,
+    /// The sources of the default values, added to the [`ConfigBuilder`]
+    /// before the config files, in the given order.
+    pub defaults: Vec<Box<dyn Source + Send + Sync>>,
+    /// The custom sources, added to the [`ConfigBuilder`] after the config
+    /// files and before the environment variables, in the given order.
+    pub sources: Vec<Box<dyn Source + Send + Sync>>,
+    /// The custom sources that are taken literally, without being
+    /// [`Interpolated`] (e.g., the documents fetched from a remote location),
+    /// added to the [`ConfigBuilder`] after the other custom sources, in the
+    /// given order.
+    pub literal_sources: Vec<Box<dyn Source + Send + Sync>>,
+    /// The values of individual keys (e.g., `database.default.port`) that
+    /// override every source, applied in the given order.
+    pub overrides: Vec<(String, Value)>,
 }
 
 impl Default for AssemblerChoices {
@@ -26,21 +65,49 @@ impl Default for AssemblerChoices {
             env_enabled: true,
             env_prefix: Some("APP".to_string()),
             env_separator: Some("_".to_string()),
+            env_schema: None,
+            defaults: Vec::new(),
+            sources: Vec::new(),
+            literal_sources: Vec::new(),
+            overrides: Vec::new(),
         }
     }
 }
 
+/// Wraps a boxed [`Source`], so that it is added to the [`ConfigBuilder`] as
+/// it is.
+#[derive(Debug)]
+struct Literal(Box<dyn Source + Send + Sync>);
+
 macro_rules! bootstrap_builder {
     ($builder:expr, $choices:expr) => {{
         let mut builder = $builder;
 
+        // Add the default sources
+        for source in &$choices.defaults {
+            builder = builder.add_source(Interpolated::from_box(source.clone_into_box()));
+        }
+
         // Find and add all config files as sources
         for config_file in Scanner::find_config_files($choices.dir_name.as_deref()) {
-            builder = builder.add_source(config::File::from(config_file));
+            builder = builder.add_source(Interpolated::new(config::File::from(config_file)));
+        }
+
+        // Add the custom sources
+        for source in &$choices.sources {
+            builder = builder.add_source(Interpolated::from_box(source.clone_into_box()));
+        }
+
+        // Add the literal sources, as they are
+        for source in &$choices.literal_sources {
+            builder = builder.add_source(Literal(source.clone_into_box()));
         }
 
         // Conditionally add an environment-based source
-        if $choices.env_enabled {
+        if let Some(env_source) = SchemaEnvironment::from_choices(&$choices) {
+            // Add the schema-aware source to the builder
+            builder = builder.add_source(env_source);
+        } else if $choices.env_enabled {
             // Create the base source
             let mut env_source = Environment::default();
 
@@ -54,8 +121,17 @@ macro_rules! bootstrap_builder {
                 env_source = env_source.separator(separator);
             }
 
-            // Add to the builder
-            builder = builder.add_source(env_source);
+            // Add to the builder, reading the variables through the overlay
+            builder = builder.add_source(OverlaidEnvironment::new(env_source));
+        }
+
+        // Apply the overrides
+        for (key, value) in &$choices.overrides {
+            builder = builder
+                .set_override(key.as_str(), value.clone())
+                .unwrap_or_else(|error| {
+                    panic!("invalid configuration override key '{}': {}", key, error)
+                });
         }
 
         builder
@@ -78,4 +154,129 @@ impl Assembler {
     pub fn make_async_builder(choices: &AssemblerChoices) -> ConfigBuilder<AsyncState> {
         bootstrap_builder!(ConfigBuilder::<AsyncState>::default(), choices)
     }
+
+    /// Explains the configuration that the builders above would produce for
+    /// the given choices: returns a [`ConfigReport`] that annotates every leaf
+    /// with the source that set it, with secrets redacted.
+    pub fn explain(choices: &AssemblerChoices) -> Result<ConfigReport, ConfigError> {
+        ConfigReport::assemble(choices)
+    }
+
+    /// Returns the names of the environment variables with the prefix that
+    /// match no key in the [schema](AssemblerChoices::env_schema), if one is
+    /// given (see [`SchemaEnvironment::unmatched`]).
+    pub fn unmatched_env(choices: &AssemblerChoices) -> Vec<String> {
+        SchemaEnvironment::from_choices(choices)
+            .map(|env_source| env_source.unmatched())
+            .unwrap_or_default()
+    }
+}
+
+impl Source for Literal {
+    fn clone_into_box(&self) -> Box<dyn Source + Send + Sync> {
+        Box::new(Self(self.0.clone_into_box()))
+    }
+
+    fn collect(&self) -> Result<Map<String, Value>, ConfigError> {
+        self.0.collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use config::{File, FileFormat};
+    use pretty_assertions::assert_eq;
+    use std::env;
+
+    #[test]
+    fn make_sync_builder_layers() {
+        // Given
+        let choices = AssemblerChoices {
+            dir_name: Some("strut_config_assembler_missing".to_string()),
+            env_enabled: false,
+            defaults: vec![Box::new(File::from_str(
+                "[billing]\ncurrency = 'USD'\nretries = 1\nregion = 'us'\n",
+                FileFormat::Toml,
+            ))],
+            sources: vec![Box::new(File::from_str(
+                "billing:\n  currency: EUR\n  retries: 2\n",
+                FileFormat::Yaml,
+            ))],
+            overrides: vec![("billing.retries".to_string(), Value::from(3))],
+            ..AssemblerChoices::default()
+        };
+
+        // When
+        let config = Assembler::make_sync_builder(&choices).build().unwrap();
+
+        // Then
+        assert_eq!(config.get_string("billing.currency").unwrap(), "EUR");
+        assert_eq!(config.get_int("billing.retries").unwrap(), 3);
+        assert_eq!(config.get_string("billing.region").unwrap(), "us");
+    }
+
+    #[test]
+    fn keep_literal_sources() {
+        // Given
+        let choices = AssemblerChoices {
+            dir_name: Some("strut_config_assembler_missing".to_string()),
+            env_enabled: false,
+            sources: vec![Box::new(File::from_str(
+                "[billing]\nregion = '${env:STRUT_ASSEMBLER_MISSING:-eu}'\n",
+                FileFormat::Toml,
+            ))],
+            literal_sources: vec![Box::new(File::from_str(
+                "[billing]\nendpoint = '${file:/etc/passwd}'\n",
+                FileFormat::Toml,
+            ))],
+            ..AssemblerChoices::default()
+        };
+
+        // When
+        let config = Assembler::make_sync_builder(&choices).build().unwrap();
+
+        // Then
+        assert_eq!(config.get_string("billing.region").unwrap(), "eu");
+        assert_eq!(
+            config.get_string("billing.endpoint").unwrap(),
+            "${file:/etc/passwd}"
+        );
+    }
+
+    #[test]
+    fn report_unmatched_env() {
+        // Given
+        unsafe {
+            env::set_var("STRUT_ASSEMBLER_BILLING_CURRENCY", "EUR");
+            env::set_var("STRUT_ASSEMBLER_BILING_CURRENCY", "USD");
+        }
+        let choices = AssemblerChoices {
+            dir_name: Some("strut_config_assembler_missing".to_string()),
+            env_prefix: Some("STRUT_ASSEMBLER".to_string()),
+            env_schema: Some(Schema::object().with_field(
+                &["billing"],
+                Schema::object().with_field(&["currency"], Schema::string()),
+            )),
+            ..AssemblerChoices::default()
+        };
+        let schemaless_choices = AssemblerChoices {
+            env_schema: None,
+            env_prefix: Some("STRUT_ASSEMBLER".to_string()),
+            ..AssemblerChoices::default()
+        };
+
+        // When
+        let unmatched = Assembler::unmatched_env(&choices);
+        let schemaless_unmatched = Assembler::unmatched_env(&schemaless_choices);
+        let config = Assembler::make_sync_builder(&choices).build().unwrap();
+
+        // Then
+        assert_eq!(
+            unmatched,
+            vec!["STRUT_ASSEMBLER_BILING_CURRENCY".to_string()],
+        );
+        assert!(schemaless_unmatched.is_empty());
+        assert_eq!(config.get_string("billing.currency").unwrap(), "EUR");
+    }
 }
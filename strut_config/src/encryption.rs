@@ -0,0 +1,352 @@
+use crate::{EnvOverlay, REDACTED};
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD as BASE64;
+use ring::aead::{AES_256_GCM, Aad, LessSafeKey, NONCE_LEN, Nonce, UnboundKey};
+use ring::rand::{SecureRandom, SystemRandom};
+use std::error::Error;
+use std::fmt::{Debug, Display, Formatter};
+use std::path::PathBuf;
+use strut_core::{AppProfile, Pivot};
+
+/// The prefix that marks an encrypted string value.
+pub const ENCRYPTED_PREFIX: &str = "enc:";
+
+/// The name of the environment variable that holds the base64-encoded
+/// [`EncryptionKey`].
+pub const ENCRYPTION_KEY_ENV: &str = "APP_CONFIG_SECRET_KEY";
+
+/// The name of the environment variable that holds the path to a file with the
+/// base64-encoded [`EncryptionKey`].
+pub const ENCRYPTION_KEY_FILE_ENV: &str = "APP_CONFIG_SECRET_KEY_FILE";
+
+/// The length of an [`EncryptionKey`], in bytes.
+const KEY_LEN: usize = 32;
+
+/// The length of the authentication tag appended to every ciphertext, in bytes.
+const TAG_LEN: usize = 16;
+
+/// A 256-bit key that encrypts and decrypts configuration values with
+/// AES-256-GCM.
+///
+/// An encrypted value is a string of the form `enc:PAYLOAD` or
+/// `enc:PROFILE:PAYLOAD`, where `PAYLOAD` is the base64 encoding of a random
+/// nonce followed by the ciphertext. A value encrypted for a
+/// [profile](AppProfile) can only be decrypted while that profile is
+/// [active](AppProfile::active_layers) (directly or through inheritance), so
+/// that a value meant for one environment is never silently used in another.
+///
+/// The key is [looked up](EncryptionKey::from_env) in the environment: either
+/// in the `APP_CONFIG_SECRET_KEY` variable, or in the file that the
+/// `APP_CONFIG_SECRET_KEY_FILE` variable points to. Both are expected to hold
+/// the key in base64 (e.g., as produced by `openssl rand -base64 32`).
+///
+/// The [`Debug`] output of this key never contains the key itself.
+#[derive(Clone, PartialEq, Eq)]
+pub struct EncryptionKey {
+    bytes: [u8; KEY_LEN],
+}
+
+/// Represents the failure to encrypt or decrypt a configuration value.
+///
+/// The messages never contain the key, nor the plaintext.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum EncryptionError {
+    /// Neither of the environment variables that hold the key is set.
+    MissingKey,
+
+    /// The key is not the base64 encoding of exactly 32 bytes.
+    InvalidKey,
+
+    /// The file that holds the key cannot be read.
+    UnreadableKeyFile {
+        /// The resolved path of the file.
+        path: PathBuf,
+
+        /// The underlying error.
+        error: std::io::Error,
+    },
+
+    /// The encrypted value is not of the form `enc:[PROFILE:]PAYLOAD`.
+    Malformed,
+
+    /// The value is encrypted for a profile that is not active.
+    InactiveProfile {
+        /// The name of the profile.
+        profile: String,
+    },
+
+    /// The value cannot be decrypted with the key (e.g., it was encrypted with
+    /// another key, or it was tampered with).
+    Undecryptable,
+}
+
+impl EncryptionKey {
+    /// Generates a new random key.
+    pub fn generate() -> Self {
+        let mut bytes = [0; KEY_LEN];
+
+        SystemRandom::new()
+            .fill(&mut bytes)
+            .expect("the system random number generator should be available");
+
+        Self { bytes }
+    }
+
+    /// Decodes a key from its base64 encoding. Surrounding whitespace is
+    /// ignored.
+    pub fn from_base64(encoded: impl AsRef<str>) -> Result<Self, EncryptionError> {
+        let decoded = BASE64
+            .decode(encoded.as_ref().trim())
+            .map_err(|_| EncryptionError::InvalidKey)?;
+        let bytes = decoded
+            .try_into()
+            .map_err(|_| EncryptionError::InvalidKey)?;
+
+        Ok(Self { bytes })
+    }
+
+    /// Looks up the key in the `APP_CONFIG_SECRET_KEY` environment variable,
+    /// or else in the file that the `APP_CONFIG_SECRET_KEY_FILE` environment
+    /// variable points to. A relative path is resolved against the
+    /// [pivot directory](Pivot::resolve).
+    pub fn from_env() -> Result<Self, EncryptionError> {
+        if let Some(encoded) = non_empty_env(ENCRYPTION_KEY_ENV) {
+            return Self::from_base64(encoded);
+        }
+
+        let Some(path) = non_empty_env(ENCRYPTION_KEY_FILE_ENV) else {
+            return Err(EncryptionError::MissingKey);
+        };
+        let path = Pivot::resolve().join(path);
+
+        match std::fs::read_to_string(&path) {
+            Ok(encoded) => Self::from_base64(encoded),
+            Err(error) => Err(EncryptionError::UnreadableKeyFile { path, error }),
+        }
+    }
+
+    /// Encodes this key in base64, as expected by
+    /// [`from_base64`](EncryptionKey::from_base64).
+    pub fn to_base64(&self) -> String {
+        BASE64.encode(self.bytes)
+    }
+}
+
+impl EncryptionKey {
+    /// Encrypts the given plaintext, returning an `enc:`-prefixed value that
+    /// may be placed in a configuration file. If a profile is given, the value
+    /// can only be decrypted while that profile is active.
+    pub fn encrypt(&self, plaintext: impl AsRef<str>, profile: Option<&AppProfile>) -> String {
+        let profile = profile.map(AppProfile::as_str);
+
+        let mut nonce = [0; NONCE_LEN];
+        SystemRandom::new()
+            .fill(&mut nonce)
+            .expect("the system random number generator should be available");
+
+        let mut payload = plaintext.as_ref().as_bytes().to_vec();
+        self.cipher()
+            .seal_in_place_append_tag(
+                Nonce::assume_unique_for_key(nonce),
+                Aad::from(profile.unwrap_or_default()),
+                &mut payload,
+            )
+            .expect("a configuration value should not exceed the limits of AES-256-GCM");
+        payload.splice(0..0, nonce);
+
+        match profile {
+            Some(profile) => format!("{}{}:{}", ENCRYPTED_PREFIX, profile, BASE64.encode(payload)),
+            None => format!("{}{}", ENCRYPTED_PREFIX, BASE64.encode(payload)),
+        }
+    }
+
+    /// Decrypts the given `enc:`-prefixed value, checking that the profile it
+    /// is encrypted for (if any) is [active](AppProfile::active_layers).
+    pub fn decrypt(&self, value: impl AsRef<str>) -> Result<String, EncryptionError> {
+        self.decrypt_within(value.as_ref(), &AppProfile::active_layers())
+    }
+
+    /// Decrypts the given `enc:`-prefixed value, checking that the profile it
+    /// is encrypted for (if any) is among the given active profiles.
+    fn decrypt_within(
+        &self,
+        value: &str,
+        active: &[AppProfile],
+    ) -> Result<String, EncryptionError> {
+        let body = value
+            .strip_prefix(ENCRYPTED_PREFIX)
+            .ok_or(EncryptionError::Malformed)?;
+
+        // The base64 alphabet has no colons, so the payload follows the last one
+        let (profile, encoded) = match body.rsplit_once(':') {
+            Some((profile, encoded)) => (Some(profile), encoded),
+            None => (None, body),
+        };
+
+        if let Some(profile) = profile {
+            if !active.iter().any(|active| active.as_str() == profile) {
+                return Err(EncryptionError::InactiveProfile {
+                    profile: profile.to_string(),
+                });
+            }
+        }
+
+        let mut payload = BASE64
+            .decode(encoded.trim())
+            .map_err(|_| EncryptionError::Malformed)?;
+        if payload.len() < NONCE_LEN + TAG_LEN {
+            return Err(EncryptionError::Malformed);
+        }
+        let mut ciphertext = payload.split_off(NONCE_LEN);
+        let nonce =
+            Nonce::try_assume_unique_for_key(&payload).map_err(|_| EncryptionError::Malformed)?;
+
+        let plaintext = self
+            .cipher()
+            .open_in_place(
+                nonce,
+                Aad::from(profile.unwrap_or_default()),
+                &mut ciphertext,
+            )
+            .map_err(|_| EncryptionError::Undecryptable)?;
+
+        String::from_utf8(plaintext.to_vec()).map_err(|_| EncryptionError::Undecryptable)
+    }
+
+    /// Reports whether the given string value is encrypted, i.e., whether it
+    /// starts with `enc:`.
+    pub fn is_encrypted(value: impl AsRef<str>) -> bool {
+        value.as_ref().starts_with(ENCRYPTED_PREFIX)
+    }
+
+    /// Makes the AES-256-GCM cipher out of this key.
+    fn cipher(&self) -> LessSafeKey {
+        let key = UnboundKey::new(&AES_256_GCM, &self.bytes)
+            .expect("a 32-byte key should be valid for AES-256-GCM");
+
+        LessSafeKey::new(key)
+    }
+}
+
+/// Reads the environment variable with the given name, if it is set and
+/// non-empty.
+fn non_empty_env(name: &str) -> Option<String> {
+    EnvOverlay::var(name).filter(|value| !value.is_empty())
+}
+
+impl Debug for EncryptionKey {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("EncryptionKey").field(&REDACTED).finish()
+    }
+}
+
+impl Display for EncryptionError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::MissingKey => write!(
+                f,
+                "the value is encrypted, but neither `{}` nor `{}` is set",
+                ENCRYPTION_KEY_ENV, ENCRYPTION_KEY_FILE_ENV,
+            ),
+            Self::InvalidKey => write!(
+                f,
+                "the encryption key is not the base64 encoding of {} bytes",
+                KEY_LEN,
+            ),
+            Self::UnreadableKeyFile { path, error } => write!(
+                f,
+                "the encryption key file `{}` cannot be read: {}",
+                path.display(),
+                error,
+            ),
+            Self::Malformed => write!(
+                f,
+                "the encrypted value is malformed, expected `{}[PROFILE:]PAYLOAD`",
+                ENCRYPTED_PREFIX,
+            ),
+            Self::InactiveProfile { profile } => write!(
+                f,
+                "the value is encrypted for the profile `{}`, which is not active",
+                profile,
+            ),
+            Self::Undecryptable => write!(
+                f,
+                "the encrypted value cannot be decrypted with the given key",
+            ),
+        }
+    }
+}
+
+impl Error for EncryptionError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn roundtrip() {
+        // Given
+        let key = EncryptionKey::generate();
+        let prod = AppProfile::new("prod");
+
+        // When
+        let plain = key.encrypt("hunter2", None);
+        let bound = key.encrypt("hunter2", Some(&prod));
+
+        // Then
+        assert!(plain.starts_with("enc:"));
+        assert!(bound.starts_with("enc:prod:"));
+        assert_eq!(key.decrypt_within(&plain, &[]).unwrap(), "hunter2");
+        assert_eq!(key.decrypt_within(&bound, &[prod]).unwrap(), "hunter2");
+    }
+
+    #[test]
+    fn key_base64() {
+        // Given
+        let key = EncryptionKey::generate();
+
+        // When
+        let decoded = EncryptionKey::from_base64(format!("{}\n", key.to_base64())).unwrap();
+
+        // Then
+        assert_eq!(decoded, key);
+        assert_eq!(format!("{:?}", key), "EncryptionKey(\"<redacted>\")");
+        assert!(matches!(
+            EncryptionKey::from_base64("c2hvcnQ="),
+            Err(EncryptionError::InvalidKey),
+        ));
+    }
+
+    #[test]
+    fn decrypt_invalid() {
+        // Given
+        let key = EncryptionKey::generate();
+        let other_key = EncryptionKey::generate();
+        let value = key.encrypt("hunter2", Some(&AppProfile::new("prod")));
+        let tampered = value.replacen("enc:prod:", "enc:", 1);
+
+        // When
+        let outputs = [
+            other_key.decrypt_within(&value, &[AppProfile::new("prod")]),
+            key.decrypt_within(&value, &[AppProfile::new("dev")]),
+            key.decrypt_within(&tampered, &[]),
+            key.decrypt_within("enc:not base64!", &[]),
+            key.decrypt_within("plain", &[]),
+        ]
+        .map(|output| output.unwrap_err().to_string());
+
+        // Then
+        assert_eq!(
+            outputs,
+            [
+                "the encrypted value cannot be decrypted with the given key",
+                "the value is encrypted for the profile `prod`, which is not active",
+                "the encrypted value cannot be decrypted with the given key",
+                "the encrypted value is malformed, expected `enc:[PROFILE:]PAYLOAD`",
+                "the encrypted value is malformed, expected `enc:[PROFILE:]PAYLOAD`",
+            ],
+        );
+    }
+}
@@ -0,0 +1,467 @@
+use crate::{AssemblerChoices, EnvOverlay};
+use config::{ConfigError, Map, Source, Value, ValueKind};
+use serde_json::{Map as JsonMap, Value as JsonValue};
+use std::collections::HashMap;
+use strut_core::RESERVED_ENV_VARS;
+use strut_deserialize::{Schema, Slug};
+
+/// The origin assigned to every collected value, the same as the one assigned
+/// by the [`Environment`](config::Environment) source.
+const ENV_ORIGIN: &str = "the environment";
+
+/// A [`Source`] of environment variables that maps the variable names onto
+/// the keys known from the given [`Schema`], instead of blindly splitting
+/// them on the separator.
+///
+/// With the default separator `_`, a variable like
+/// `APP_DATABASE_POSTGRES_MAIN_DB_HOST` is ambiguous for the
+/// [`Environment`](config::Environment) source, but here it is resolved
+/// against the schema: `database` and `postgres` are known fields, the
+/// handles of `postgres` have arbitrary names, and `host` is a known field of
+/// a handle, so the variable sets `database.postgres.main_db.host`. Field
+/// names are matched as [slugs](Slug::eq_as_slugs), so multi-word fields like
+/// `max_connections` need no special separator. When a name is still
+/// ambiguous, longer known fields are preferred, then shorter arbitrary names.
+///
+/// The values of fields that accept a list (e.g., `binding_keys`) are split on
+/// the list separator (`,` by default) and trimmed. Where the schema accepts
+/// any value, the rest of the variable name is split on the separator, the
+/// same way as by the [`Environment`](config::Environment) source.
+///
+/// The variables with the prefix that match no key in the schema are skipped,
+/// and may be listed via [`unmatched`](SchemaEnvironment::unmatched).
+#[derive(Debug, Clone)]
+pub struct SchemaEnvironment {
+    schema: JsonValue,
+    prefix: Option<String>,
+    separator: String,
+    list_separator: String,
+    source: Option<HashMap<String, String>>,
+}
+
+/// The shape of the value that a single environment variable sets.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Shape {
+    Scalar,
+    List,
+}
+
+impl SchemaEnvironment {
+    /// Creates a source that resolves the environment variables against the
+    /// given [`Schema`], with no prefix, and with the default separators.
+    pub fn new(schema: Schema) -> Self {
+        Self {
+            schema: JsonValue::from(schema),
+            prefix: None,
+            separator: "_".to_string(),
+            list_separator: ",".to_string(),
+            source: None,
+        }
+    }
+
+    /// Creates a source configured by the given [`AssemblerChoices`], unless
+    /// the environment variables are disabled or no
+    /// [schema](AssemblerChoices::env_schema) is given.
+    pub(crate) fn from_choices(choices: &AssemblerChoices) -> Option<Self> {
+        if !choices.env_enabled {
+            return None;
+        }
+
+        let mut source = Self::new(choices.env_schema.clone()?);
+        source.prefix = choices.env_prefix.clone();
+        if let Some(separator) = choices.env_separator.as_deref() {
+            source = source.separator(separator);
+        }
+
+        Some(source)
+    }
+
+    /// Only considers the variables that start with the given prefix,
+    /// followed by the separator. The prefix is matched case-insensitively.
+    pub fn prefix(self, prefix: impl Into<String>) -> Self {
+        Self {
+            prefix: Some(prefix.into()),
+            ..self
+        }
+    }
+
+    /// Sets the separator between the segments of variable names. Defaults
+    /// to `_`. An empty separator is ignored.
+    pub fn separator(self, separator: impl Into<String>) -> Self {
+        let separator = separator.into();
+        if separator.is_empty() {
+            return self;
+        }
+
+        Self { separator, ..self }
+    }
+
+    /// Sets the separator between the elements of list values. Defaults to
+    /// `,`. An empty separator is ignored.
+    pub fn list_separator(self, list_separator: impl Into<String>) -> Self {
+        let list_separator = list_separator.into();
+        if list_separator.is_empty() {
+            return self;
+        }
+
+        Self {
+            list_separator,
+            ..self
+        }
+    }
+
+    /// Reads the variables from the given map instead of the process
+    /// environment (useful for testing).
+    pub fn source(self, source: Option<HashMap<String, String>>) -> Self {
+        Self { source, ..self }
+    }
+
+    /// Returns the names of the variables that start with the prefix, but
+    /// match no key in the schema, in alphabetical order. The
+    /// [reserved](RESERVED_ENV_VARS) variables (e.g., `APP_PROFILE`) are never
+    /// reported.
+    ///
+    /// Without a prefix, every variable in the environment would be a
+    /// candidate, so nothing is reported.
+    pub fn unmatched(&self) -> Vec<String> {
+        if self.prefix.is_none() {
+            return Vec::new();
+        }
+
+        self.vars()
+            .into_iter()
+            .filter(|(name, _)| {
+                !RESERVED_ENV_VARS
+                    .iter()
+                    .any(|reserved| reserved.eq_ignore_ascii_case(name))
+            })
+            .filter_map(|(name, _)| {
+                let key = self.strip_prefix(&name)?;
+                self.resolve(&key).is_none().then_some(name)
+            })
+            .collect()
+    }
+
+    /// Returns the resolved dotted key, the name, and the value of every
+    /// variable that matches a key in the schema, in alphabetical order of
+    /// names.
+    pub(crate) fn variables(&self) -> Vec<(String, String, Value)> {
+        let origin = ENV_ORIGIN.to_string();
+
+        self.vars()
+            .into_iter()
+            .filter_map(|(name, value)| {
+                let (key, shape) = self.resolve(&self.strip_prefix(&name)?)?;
+                let kind = match shape {
+                    Shape::Scalar => ValueKind::String(value),
+                    Shape::List => ValueKind::Array(
+                        value
+                            .split(self.list_separator.as_str())
+                            .map(str::trim)
+                            .filter(|item| !item.is_empty())
+                            .map(|item| Value::new(Some(&origin), item))
+                            .collect(),
+                    ),
+                };
+
+                Some((key, name, Value::new(Some(&origin), kind)))
+            })
+            .collect()
+    }
+
+    /// Returns the variables from the chosen source, sorted by name, skipping
+    /// those that are not valid Unicode.
+    fn vars(&self) -> Vec<(String, String)> {
+        let mut vars = match &self.source {
+            Some(source) => source
+                .iter()
+                .map(|(name, value)| (name.clone(), value.clone()))
+                .collect::<Vec<_>>(),
+            None => EnvOverlay::vars(),
+        };
+        vars.sort();
+
+        vars
+    }
+
+    /// Strips the prefix and the following separator from the given variable
+    /// name, returning the rest in lowercase, unless the name does not start
+    /// with the prefix.
+    fn strip_prefix(&self, name: &str) -> Option<String> {
+        let name = name.to_lowercase();
+
+        match &self.prefix {
+            Some(prefix) => {
+                let pattern = format!("{}{}", prefix, self.separator).to_lowercase();
+                name.strip_prefix(&pattern).map(str::to_string)
+            }
+            None => Some(name),
+        }
+    }
+
+    /// Resolves the given variable name (without the prefix) into the dotted
+    /// key and the shape of its value, unless it matches no key in the schema.
+    fn resolve(&self, key: &str) -> Option<(String, Shape)> {
+        let tokens = key
+            .split(self.separator.as_str())
+            .filter(|token| !token.is_empty())
+            .collect::<Vec<_>>();
+        if tokens.is_empty() {
+            return None;
+        }
+
+        let mut path = Vec::new();
+        let shape = self.resolve_tokens(&self.schema, &tokens, &mut path)?;
+
+        Some((path.join("."), shape))
+    }
+
+    /// Recursively resolves the given segments of a variable name against the
+    /// given JSON Schema, pushing the matched keys onto the given path.
+    fn resolve_tokens(
+        &self,
+        schema: &JsonValue,
+        tokens: &[&str],
+        path: &mut Vec<String>,
+    ) -> Option<Shape> {
+        if tokens.is_empty() {
+            return match (accepts_list(schema), accepts_scalar(schema)) {
+                (true, _) => Some(Shape::List),
+                (false, true) => Some(Shape::Scalar),
+                (false, false) => None,
+            };
+        }
+
+        let branches = object_branches(schema);
+
+        // Known fields, longer first
+        for length in (1..=tokens.len()).rev() {
+            let name = tokens[..length].join(&self.separator);
+            let field = branches
+                .iter()
+                .filter_map(|branch| properties_of(branch))
+                .flat_map(JsonMap::iter)
+                .find(|(field, _)| Slug::eq_as_slugs(field, &name));
+
+            if let Some((field, field_schema)) = field {
+                path.push(field.clone());
+                if let Some(shape) = self.resolve_tokens(field_schema, &tokens[length..], path) {
+                    return Some(shape);
+                }
+                path.pop();
+            }
+        }
+
+        // Arbitrary keys of a map, shorter first
+        let values_schema = branches
+            .iter()
+            .find_map(|branch| branch.get("additionalProperties"));
+        if let Some(values_schema) = values_schema {
+            for length in 1..=tokens.len() {
+                path.push(tokens[..length].join(&self.separator));
+                if let Some(shape) = self.resolve_tokens(values_schema, &tokens[length..], path) {
+                    return Some(shape);
+                }
+                path.pop();
+            }
+        }
+
+        // Any key is accepted
+        if branches.iter().any(|branch| {
+            properties_of(branch).is_none() && !branch.contains_key("additionalProperties")
+        }) {
+            path.extend(tokens.iter().map(|token| token.to_string()));
+            return Some(Shape::Scalar);
+        }
+
+        None
+    }
+}
+
+impl Source for SchemaEnvironment {
+    fn clone_into_box(&self) -> Box<dyn Source + Send + Sync> {
+        Box::new(self.clone())
+    }
+
+    fn collect(&self) -> Result<Map<String, Value>, ConfigError> {
+        Ok(self
+            .variables()
+            .into_iter()
+            .map(|(key, _, value)| (key, value))
+            .collect())
+    }
+}
+
+/// Returns the alternatives of the given JSON Schema (flattening `anyOf`)
+/// that accept a map.
+fn object_branches(schema: &JsonValue) -> Vec<&JsonMap<String, JsonValue>> {
+    alternatives(schema)
+        .into_iter()
+        .filter(|alternative| matches!(type_of(alternative), None | Some("object")))
+        .collect()
+}
+
+/// Reports whether the given JSON Schema declares an alternative that accepts
+/// a list.
+fn accepts_list(schema: &JsonValue) -> bool {
+    alternatives(schema)
+        .into_iter()
+        .any(|alternative| type_of(alternative) == Some("array"))
+}
+
+/// Reports whether the given JSON Schema has an alternative that accepts a
+/// single value other than a map (alternatives without a declared type accept
+/// any value).
+fn accepts_scalar(schema: &JsonValue) -> bool {
+    match schema {
+        JsonValue::Bool(accepts) => *accepts,
+        _ => alternatives(schema)
+            .into_iter()
+            .any(|alternative| !matches!(type_of(alternative), Some("object" | "array"))),
+    }
+}
+
+/// Returns the alternatives of the given JSON Schema, flattening `anyOf`.
+fn alternatives(schema: &JsonValue) -> Vec<&JsonMap<String, JsonValue>> {
+    let Some(schema) = schema.as_object() else {
+        return Vec::new();
+    };
+
+    match schema.get("anyOf") {
+        Some(JsonValue::Array(branches)) => branches.iter().flat_map(alternatives).collect(),
+        _ => vec![schema],
+    }
+}
+
+/// Returns the declared JSON type of the given JSON Schema, if any.
+fn type_of(schema: &JsonMap<String, JsonValue>) -> Option<&str> {
+    schema.get("type").and_then(JsonValue::as_str)
+}
+
+/// Returns the fixed fields of the given object JSON Schema, if it declares
+/// any.
+fn properties_of(schema: &JsonMap<String, JsonValue>) -> Option<&JsonMap<String, JsonValue>> {
+    schema.get("properties").and_then(JsonValue::as_object)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use config::Config;
+    use pretty_assertions::assert_eq;
+
+    fn schema() -> Schema {
+        let handle = Schema::any_of([
+            Schema::string(),
+            Schema::object()
+                .with_field(&["host"], Schema::string())
+                .with_field(&["max_connections", "max_conn"], Schema::unsigned()),
+        ]);
+
+        Schema::object()
+            .with_field(&["name"], Schema::string())
+            .with_field(
+                &["database"],
+                Schema::object()
+                    .with_field(&["default_handle"], Schema::string())
+                    .with_field(&["postgres"], Schema::map(handle)),
+            )
+            .with_field(
+                &["ingress"],
+                Schema::object()
+                    .with_field(&["binding_keys"], Schema::one_or_many(Schema::string())),
+            )
+            .with_field(&["billing"], Schema::any())
+    }
+
+    fn source(vars: &[(&str, &str)]) -> SchemaEnvironment {
+        let vars = vars
+            .iter()
+            .map(|(name, value)| (name.to_string(), value.to_string()))
+            .collect();
+
+        SchemaEnvironment::new(schema())
+            .prefix("APP")
+            .source(Some(vars))
+    }
+
+    #[test]
+    fn collect_known_keys() {
+        // Given
+        let source = source(&[
+            ("APP_NAME", "billing"),
+            ("APP_DATABASE_DEFAULT_HANDLE", "main_db"),
+            ("APP_DATABASE_POSTGRES_MAIN_DB_HOST", "localhost"),
+            ("APP_DATABASE_POSTGRES_MAIN_DB_MAX_CONN", "5"),
+            ("APP_DATABASE_POSTGRES_REPLICA", "postgres://replica"),
+            ("APP_BILLING_CURRENCY", "EUR"),
+        ]);
+
+        // When
+        let config = Config::builder().add_source(source).build().unwrap();
+
+        // Then
+        assert_eq!(config.get_string("name").unwrap(), "billing");
+        assert_eq!(
+            config.get_string("database.default_handle").unwrap(),
+            "main_db",
+        );
+        assert_eq!(
+            config.get_string("database.postgres.main_db.host").unwrap(),
+            "localhost",
+        );
+        assert_eq!(
+            config
+                .get_int("database.postgres.main_db.max_conn")
+                .unwrap(),
+            5,
+        );
+        assert_eq!(
+            config.get_string("database.postgres.replica").unwrap(),
+            "postgres://replica",
+        );
+        assert_eq!(config.get_string("billing.currency").unwrap(), "EUR");
+    }
+
+    #[test]
+    fn collect_lists() {
+        // Given
+        let source = source(&[("APP_INGRESS_BINDING_KEYS", "orders.*, payments.*,")]);
+
+        // When
+        let config = Config::builder().add_source(source).build().unwrap();
+
+        // Then
+        assert_eq!(
+            config.get::<Vec<String>>("ingress.binding_keys").unwrap(),
+            vec!["orders.*".to_string(), "payments.*".to_string()],
+        );
+    }
+
+    #[test]
+    fn report_unmatched() {
+        // Given
+        let source = source(&[
+            ("APP_NAME", "billing"),
+            ("APP_DATABSE_DEFAULT_HANDLE", "main_db"),
+            ("APP_DATABASE_POSTGRES", "postgres://main"),
+            ("APP_INGRESS_BINDING_KEYS_EXTRA", "orders.*"),
+            ("APP_PROFILE", "prod"),
+            ("APP_CONFIG_SECRET_KEY_FILE", "/run/secrets/config_key"),
+            ("HOME", "/root"),
+        ]);
+
+        // When
+        let unmatched = source.unmatched();
+
+        // Then
+        assert_eq!(
+            unmatched,
+            vec![
+                "APP_DATABASE_POSTGRES".to_string(),
+                "APP_DATABSE_DEFAULT_HANDLE".to_string(),
+                "APP_INGRESS_BINDING_KEYS_EXTRA".to_string(),
+            ],
+        );
+    }
+}
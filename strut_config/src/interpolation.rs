@@ -0,0 +1,519 @@
+#[cfg(feature = "encryption")]
+use crate::EncryptionKey;
+use crate::EnvOverlay;
+use config::{ConfigError, Map, Source, Value, ValueKind};
+use std::collections::BTreeSet;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::path::PathBuf;
+use std::sync::Mutex;
+use strut_core::Pivot;
+
+/// The opening of a reference within a string value.
+const REFERENCE_START: &str = "${";
+
+/// The escaped opening of a reference, which stands for a literal `${`.
+const ESCAPED_REFERENCE_START: &str = "$${";
+
+/// The closing of a reference within a string value.
+const REFERENCE_END: char = '}';
+
+/// The separator of the default value in an environment variable reference.
+const ENV_DEFAULT_SEPARATOR: &str = ":-";
+
+// The lowercase dotted keys of the leaves ever resolved from a reference or
+// decrypted by any [`Interpolated`] source, with array indices cut off
+static RESOLVED_KEYS: Mutex<BTreeSet<String>> = Mutex::new(BTreeSet::new());
+
+/// Wraps another configuration [`Source`], resolving the references in its
+/// string values before they are deserialized.
+///
+/// The following references are supported, anywhere within a string value:
+///
+/// - `${file:PATH}` is replaced with the contents of the file at `PATH`
+///   (e.g., a secret mounted under `/run/secrets`), without the trailing line
+///   breaks. A relative `PATH` is resolved against the
+///   [pivot directory](Pivot::resolve).
+/// - `${env:NAME}` is replaced with the value of the environment variable
+///   `NAME`, which must be set and non-empty.
+/// - `${env:NAME:-DEFAULT}` is replaced with the value of the environment
+///   variable `NAME`, or with `DEFAULT` if it is unset or empty. The
+///   `DEFAULT` may itself contain references, such as
+///   `${env:PRIMARY_HOST:-${env:FALLBACK_HOST}}`, which are only resolved
+///   when the default is used.
+/// - `$${` stands for a literal `${`.
+///
+/// With the `encryption` feature, a string value that starts with `enc:` is
+/// instead decrypted as a whole with the
+/// [`EncryptionKey`](crate::EncryptionKey) found in the environment, and its
+/// plaintext is not interpolated any further.
+///
+/// A reference that cannot be resolved (or a value that cannot be decrypted)
+/// fails the collection of the source with an error that names the offending
+/// key and the file it comes from. Neither
+/// the errors nor the [`Debug`] output of this source ever contain the
+/// resolved values. The keys of the resolved values are remembered, so that
+/// a [`ConfigDiff`](crate::ConfigDiff) always redacts them.
+#[derive(Debug)]
+pub struct Interpolated {
+    source: Box<dyn Source + Send + Sync>,
+}
+
+/// Represents the failure to resolve a single reference within a string value.
+///
+/// The messages mention the references, but never the resolved values.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum InterpolationError {
+    /// The reference is not closed with a `}`.
+    Unterminated {
+        /// The text of the unterminated reference.
+        reference: String,
+    },
+
+    /// The reference is neither a `${file:…}` nor an `${env:…}` reference.
+    Unknown {
+        /// The text of the unknown reference.
+        reference: String,
+    },
+
+    /// The referenced environment variable is unset or empty, and no default
+    /// is given.
+    MissingEnv {
+        /// The name of the environment variable.
+        name: String,
+    },
+
+    /// The referenced file cannot be read.
+    UnreadableFile {
+        /// The resolved path of the file.
+        path: PathBuf,
+
+        /// The underlying error.
+        error: std::io::Error,
+    },
+}
+
+impl Interpolated {
+    /// Wraps the given [`Source`].
+    pub fn new<S>(source: S) -> Self
+    where
+        S: Source + Send + Sync + 'static,
+    {
+        Self {
+            source: Box::new(source),
+        }
+    }
+
+    /// Wraps the given boxed [`Source`].
+    pub(crate) fn from_box(source: Box<dyn Source + Send + Sync>) -> Self {
+        Self { source }
+    }
+}
+
+impl Source for Interpolated {
+    fn clone_into_box(&self) -> Box<dyn Source + Send + Sync> {
+        Box::new(Self {
+            source: self.source.clone_into_box(),
+        })
+    }
+
+    fn collect(&self) -> Result<Map<String, Value>, ConfigError> {
+        self.source
+            .collect()?
+            .into_iter()
+            .map(|(key, value)| {
+                let value = interpolate_value(&key, value)?;
+                Ok((key, value))
+            })
+            .collect()
+    }
+}
+
+/// Resolves the references in every string within the given value, which is
+/// found at the given key.
+fn interpolate_value(key: &str, value: Value) -> Result<Value, ConfigError> {
+    let origin = value.origin().map(ToString::to_string);
+
+    let kind = match value.kind {
+        ValueKind::String(input) => {
+            let output = resolve_string(&input).map_err(|error| ConfigError::At {
+                error: Box::new(ConfigError::Foreign(error)),
+                origin: origin.clone(),
+                key: Some(key.to_string()),
+            })?;
+
+            if output != input {
+                remember_resolved(key);
+            }
+
+            ValueKind::String(output)
+        }
+        ValueKind::Table(table) => ValueKind::Table(
+            table
+                .into_iter()
+                .map(|(name, value)| {
+                    let value = interpolate_value(&format!("{}.{}", key, name), value)?;
+                    Ok((name, value))
+                })
+                .collect::<Result<_, ConfigError>>()?,
+        ),
+        ValueKind::Array(array) => ValueKind::Array(
+            array
+                .into_iter()
+                .enumerate()
+                .map(|(index, value)| interpolate_value(&format!("{}[{}]", key, index), value))
+                .collect::<Result<_, ConfigError>>()?,
+        ),
+        other => other,
+    };
+
+    Ok(Value::new(origin.as_ref(), kind))
+}
+
+/// Remembers that the value at the given key is resolved from a reference or
+/// decrypted. Within an array, the whole array is remembered, as arrays are
+/// compared as whole leaves.
+fn remember_resolved(key: &str) {
+    let leaf = key.split('[').next().unwrap_or(key).to_lowercase();
+
+    RESOLVED_KEYS
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .insert(leaf);
+}
+
+/// Reports whether the value at the given dotted key has ever been resolved
+/// from a reference or decrypted by an [`Interpolated`] source.
+pub(crate) fn is_resolved_key(key: &str) -> bool {
+    RESOLVED_KEYS
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .contains(&key.to_lowercase())
+}
+
+/// Resolves the given string value: decrypts it if it is encrypted, or else
+/// resolves the references in it.
+fn resolve_string(input: &str) -> Result<String, Box<dyn Error + Send + Sync>> {
+    #[cfg(feature = "encryption")]
+    if EncryptionKey::is_encrypted(input) {
+        return Ok(EncryptionKey::from_env()?.decrypt(input)?);
+    }
+
+    Ok(interpolate(input)?)
+}
+
+/// Resolves the references in the given string.
+fn interpolate(input: &str) -> Result<String, InterpolationError> {
+    let mut output = String::with_capacity(input.len());
+    let mut rest = input;
+
+    while let Some(position) = rest.find('$') {
+        output.push_str(&rest[..position]);
+        rest = &rest[position..];
+
+        if let Some(after) = rest.strip_prefix(ESCAPED_REFERENCE_START) {
+            output.push_str(REFERENCE_START);
+            rest = after;
+        } else if let Some(after) = rest.strip_prefix(REFERENCE_START) {
+            let Some(end) = find_reference_end(after) else {
+                return Err(InterpolationError::Unterminated {
+                    reference: rest.to_string(),
+                });
+            };
+
+            output.push_str(&resolve(&after[..end])?);
+            rest = &after[end + 1..];
+        } else {
+            output.push('$');
+            rest = &rest[1..];
+        }
+    }
+
+    output.push_str(rest);
+
+    Ok(output)
+}
+
+/// Finds the position of the `}` that closes a reference in the given text,
+/// which follows the opening `${`, skipping over the nested references.
+fn find_reference_end(after: &str) -> Option<usize> {
+    let mut depth = 0usize;
+    let mut position = 0;
+
+    while position < after.len() {
+        let rest = &after[position..];
+
+        if rest.starts_with(ESCAPED_REFERENCE_START) {
+            position += ESCAPED_REFERENCE_START.len();
+        } else if rest.starts_with(REFERENCE_START) {
+            depth += 1;
+            position += REFERENCE_START.len();
+        } else if rest.starts_with(REFERENCE_END) {
+            if depth == 0 {
+                return Some(position);
+            }
+            depth -= 1;
+            position += 1;
+        } else {
+            position += rest.chars().next().map_or(1, char::len_utf8);
+        }
+    }
+
+    None
+}
+
+/// Resolves the reference with the given body (the text between `${` and `}`).
+fn resolve(body: &str) -> Result<String, InterpolationError> {
+    match body.split_once(':') {
+        Some(("file", path)) if !path.is_empty() => resolve_file(path),
+        Some(("env", name)) if !name.is_empty() => resolve_env(name),
+        _ => Err(InterpolationError::Unknown {
+            reference: format!("{}{}{}", REFERENCE_START, body, REFERENCE_END),
+        }),
+    }
+}
+
+/// Reads the file at the given path.
+fn resolve_file(path: &str) -> Result<String, InterpolationError> {
+    let path = Pivot::resolve().join(path);
+
+    match std::fs::read_to_string(&path) {
+        Ok(content) => Ok(content.trim_end_matches(['\r', '\n']).to_string()),
+        Err(error) => Err(InterpolationError::UnreadableFile { path, error }),
+    }
+}
+
+/// Reads the environment variable with the given name, which may be followed
+/// by a default value, itself possibly containing references.
+fn resolve_env(name: &str) -> Result<String, InterpolationError> {
+    let (name, default) = match name.split_once(ENV_DEFAULT_SEPARATOR) {
+        Some((name, default)) => (name, Some(default)),
+        None => (name, None),
+    };
+
+    match (EnvOverlay::var(name), default) {
+        (Some(value), _) if !value.is_empty() => Ok(value),
+        (_, Some(default)) => interpolate(default),
+        _ => Err(InterpolationError::MissingEnv {
+            name: name.to_string(),
+        }),
+    }
+}
+
+impl Display for InterpolationError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Unterminated { reference } => {
+                write!(f, "the reference `{}` is not closed with `}}`", reference)
+            }
+            Self::Unknown { reference } => write!(
+                f,
+                "the reference `{}` is unknown, expected `${{file:PATH}}` or `${{env:NAME}}`",
+                reference,
+            ),
+            Self::MissingEnv { name } => write!(
+                f,
+                "the referenced environment variable `{}` is not set",
+                name,
+            ),
+            Self::UnreadableFile { path, error } => write!(
+                f,
+                "the referenced file `{}` cannot be read: {}",
+                path.display(),
+                error,
+            ),
+        }
+    }
+}
+
+impl Error for InterpolationError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use config::{Config, File, FileFormat};
+    use pretty_assertions::assert_eq;
+    use std::env;
+
+    #[test]
+    fn interpolate_plain() {
+        // Given
+        let input = "no references, only $ and {}";
+
+        // When
+        let output = interpolate(input).unwrap();
+
+        // Then
+        assert_eq!(output, input);
+    }
+
+    #[test]
+    fn interpolate_env() {
+        // Given
+        unsafe {
+            env::set_var("STRUT_INTERPOLATION_USER", "alice");
+            env::set_var("STRUT_INTERPOLATION_EMPTY", "");
+        }
+        let input = "postgres://${env:STRUT_INTERPOLATION_USER}@${env:STRUT_INTERPOLATION_HOST:-localhost}:${env:STRUT_INTERPOLATION_EMPTY:-5432}/$${env:db}";
+
+        // When
+        let output = interpolate(input).unwrap();
+
+        // Then
+        assert_eq!(output, "postgres://alice@localhost:5432/${env:db}");
+    }
+
+    #[test]
+    fn interpolate_nested_default() {
+        // Given
+        unsafe {
+            env::set_var("STRUT_INTERPOLATION_FALLBACK", "replica");
+        }
+        let input = "${env:STRUT_INTERPOLATION_PRIMARY:-${env:STRUT_INTERPOLATION_FALLBACK}}/${env:STRUT_INTERPOLATION_SECONDARY:-${env:STRUT_INTERPOLATION_TERTIARY:-local}}";
+
+        // When
+        let output = interpolate(input).unwrap();
+        let error =
+            interpolate("${env:STRUT_INTERPOLATION_PRIMARY:-${env:STRUT_INTERPOLATION_FALLBACK}")
+                .unwrap_err();
+
+        // Then
+        assert_eq!(output, "replica/local");
+        assert_eq!(
+            error.to_string(),
+            "the reference `${env:STRUT_INTERPOLATION_PRIMARY:-${env:STRUT_INTERPOLATION_FALLBACK}` is not closed with `}`",
+        );
+    }
+
+    #[test]
+    fn interpolate_file() {
+        // Given
+        let path = env::temp_dir().join("strut_interpolation_secret");
+        std::fs::write(&path, "hunter2\n").unwrap();
+        let input = format!("${{file:{}}}", path.display());
+
+        // When
+        let output = interpolate(&input).unwrap();
+
+        // Then
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(output, "hunter2");
+    }
+
+    #[test]
+    fn interpolate_invalid() {
+        // Given
+        let inputs = [
+            "${env:STRUT_INTERPOLATION_UNSET}",
+            "${vault:secret}",
+            "${env:",
+            "${file:/nonexistent/strut/secret}",
+        ];
+
+        // When
+        let outputs = inputs
+            .iter()
+            .map(|input| interpolate(input).unwrap_err().to_string())
+            .collect::<Vec<_>>();
+
+        // Then
+        assert_eq!(
+            outputs[..3],
+            [
+                "the referenced environment variable `STRUT_INTERPOLATION_UNSET` is not set",
+                "the reference `${vault:secret}` is unknown, expected `${file:PATH}` or `${env:NAME}`",
+                "the reference `${env:` is not closed with `}`",
+            ],
+        );
+        assert!(
+            outputs[3]
+                .starts_with("the referenced file `/nonexistent/strut/secret` cannot be read: ")
+        );
+    }
+
+    #[test]
+    fn collect_nested() {
+        // Given
+        unsafe {
+            env::set_var("STRUT_INTERPOLATION_PASSWORD", "hunter2");
+        }
+        let input = r#"
+database:
+  password: ${env:STRUT_INTERPOLATION_PASSWORD}
+  port: ${env:STRUT_INTERPOLATION_PORT:-5432}
+  hosts: [ "${env:STRUT_INTERPOLATION_HOST:-localhost}" ]
+"#;
+        let source = Interpolated::new(File::from_str(input, FileFormat::Yaml));
+
+        // When
+        let config = Config::builder().add_source(source).build().unwrap();
+
+        // Then
+        assert_eq!(config.get_string("database.password").unwrap(), "hunter2",);
+        assert_eq!(config.get_int("database.port").unwrap(), 5432);
+        assert_eq!(
+            config.get::<Vec<String>>("database.hosts").unwrap(),
+            vec!["localhost"],
+        );
+    }
+
+    #[test]
+    fn collect_failed() {
+        // Given
+        let input = "database:\n  hosts: [ localhost, \"${env:STRUT_INTERPOLATION_MISSING}\" ]\n";
+        let source = Interpolated::new(File::from_str(input, FileFormat::Yaml));
+
+        // When
+        let error = Config::builder().add_source(source).build().unwrap_err();
+
+        // Then
+        assert_eq!(
+            error.to_string(),
+            "the referenced environment variable `STRUT_INTERPOLATION_MISSING` is not set for key `database.hosts[1]`",
+        );
+    }
+
+    #[cfg(feature = "encryption")]
+    #[test]
+    fn collect_encrypted() {
+        // Given
+        // Tests that set the key in the environment concurrently share the same key
+        let key =
+            EncryptionKey::from_base64("AAECAwQFBgcICQoLDA0ODxAREhMUFRYXGBkaGxwdHh8=").unwrap();
+        unsafe {
+            env::set_var(crate::ENCRYPTION_KEY_ENV, key.to_base64());
+        }
+        let valid = format!(
+            "database:\n  password: \"{}\"\n",
+            key.encrypt("${env:hunter2}", None),
+        );
+        let invalid = format!(
+            "database:\n  password: \"{}\"\n",
+            EncryptionKey::generate().encrypt("hunter2", None),
+        );
+
+        // When
+        let config = Config::builder()
+            .add_source(Interpolated::new(File::from_str(&valid, FileFormat::Yaml)))
+            .build()
+            .unwrap();
+        let error = Config::builder()
+            .add_source(Interpolated::new(File::from_str(
+                &invalid,
+                FileFormat::Yaml,
+            )))
+            .build()
+            .unwrap_err();
+
+        // Then
+        assert_eq!(
+            config.get_string("database.password").unwrap(),
+            "${env:hunter2}",
+        );
+        assert_eq!(
+            error.to_string(),
+            "the encrypted value cannot be decrypted with the given key for key `database.password`",
+        );
+    }
+}
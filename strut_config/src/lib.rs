@@ -7,8 +7,36 @@ mod scanner;
 pub use self::scanner::dir::ConfigDir;
 pub use self::scanner::entry::{ConfigEntry, ConfigEntryIter};
 pub use self::scanner::file::ConfigFile;
-pub use self::scanner::Scanner;
+pub use self::scanner::{INHERITS_KEY, PROFILES_KEY, Scanner};
 
 /// Implements an [`Assembler`] for the opinionated [`ConfigBuilder`](config::ConfigBuilder).
 mod assembler;
 pub use self::assembler::{Assembler, AssemblerChoices};
+
+/// Implements the [`SchemaEnvironment`] source that maps environment variables onto known keys.
+mod environment;
+pub use self::environment::SchemaEnvironment;
+
+/// Implements the [`EnvOverlay`] on top of the environment variables of the process.
+mod overlay;
+pub use self::overlay::EnvOverlay;
+
+/// Implements the [`Interpolated`] source that resolves references in values.
+mod interpolation;
+pub use self::interpolation::{Interpolated, InterpolationError};
+
+/// Implements the [`EncryptionKey`] that decrypts `enc:`-prefixed values.
+#[cfg(feature = "encryption")]
+mod encryption;
+#[cfg(feature = "encryption")]
+pub use self::encryption::{
+    ENCRYPTED_PREFIX, ENCRYPTION_KEY_ENV, ENCRYPTION_KEY_FILE_ENV, EncryptionError, EncryptionKey,
+};
+
+/// Implements a [`ConfigReport`] that explains where the configuration comes from.
+mod report;
+pub use self::report::{ConfigReport, ConfigReportEntry, ConfigSource, REDACTED};
+
+/// Implements a [`ConfigDiff`] that describes what changed between two configurations.
+mod diff;
+pub use self::diff::{ConfigChange, ConfigChangeKind, ConfigDiff};
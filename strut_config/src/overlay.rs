@@ -0,0 +1,176 @@
+use config::{ConfigError, Environment, Map, Source, Value};
+use std::collections::HashMap;
+use std::env;
+use std::sync::RwLock;
+
+// The variables that override (or, if `None`, hide) those of the process
+static OVERLAY: RwLock<Option<HashMap<String, Option<String>>>> = RwLock::new(None);
+
+/// An in-process overlay on top of the environment variables of the process.
+///
+/// Changing the environment of the process is unsound once other threads may
+/// be reading it (e.g., on a running multi-threaded runtime). The variables
+/// that change after start-up (such as those re-read from the dot-env files)
+/// are thus kept in this overlay instead, and the environment of the process
+/// is never touched.
+///
+/// The overlay is read by the environment-based sources of the
+/// [`Assembler`](crate::Assembler), by the `${env:…}` references of the
+/// [`Interpolated`](crate::Interpolated) source, and by the
+/// [`ConfigReport`](crate::ConfigReport).
+pub struct EnvOverlay;
+
+/// Wraps the [`Environment`] source, so that it reads the variables of the
+/// process through the [`EnvOverlay`].
+#[derive(Debug, Clone)]
+pub(crate) struct OverlaidEnvironment {
+    inner: Environment,
+}
+
+impl EnvOverlay {
+    /// Overrides the environment variable with the given name with the given
+    /// value, or hides it if the value is `None`.
+    pub fn set(name: impl Into<String>, value: Option<String>) {
+        OVERLAY
+            .write()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .get_or_insert_with(HashMap::new)
+            .insert(name.into(), value);
+    }
+
+    /// Returns the value of the environment variable with the given name, as
+    /// seen through the overlay, unless it is unset (or not valid unicode).
+    pub fn var(name: impl AsRef<str>) -> Option<String> {
+        let name = name.as_ref();
+
+        if let Some(value) = Self::lookup(name) {
+            return value;
+        }
+
+        env::var(name).ok()
+    }
+
+    /// Reports whether the environment variable with the given name is set, as
+    /// seen through the overlay.
+    pub fn is_set(name: impl AsRef<str>) -> bool {
+        let name = name.as_ref();
+
+        match Self::lookup(name) {
+            Some(value) => value.is_some(),
+            None => env::var_os(name).is_some(),
+        }
+    }
+
+    /// Returns the names and values of all environment variables, as seen
+    /// through the overlay, in no particular order. The variables that are not
+    /// valid unicode are skipped.
+    pub fn vars() -> Vec<(String, String)> {
+        let mut vars = env::vars_os()
+            .filter_map(|(name, value)| Some((name.into_string().ok()?, value.into_string().ok()?)))
+            .collect::<HashMap<_, _>>();
+
+        if let Some(overlay) = OVERLAY
+            .read()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .as_ref()
+        {
+            for (name, value) in overlay {
+                match value {
+                    Some(value) => vars.insert(name.clone(), value.clone()),
+                    None => vars.remove(name),
+                };
+            }
+        }
+
+        vars.into_iter().collect()
+    }
+
+    /// Reports whether anything is overlaid at all.
+    fn is_empty() -> bool {
+        OVERLAY
+            .read()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .as_ref()
+            .is_none_or(HashMap::is_empty)
+    }
+
+    /// Looks up the environment variable with the given name in the overlay
+    /// only.
+    fn lookup(name: &str) -> Option<Option<String>> {
+        OVERLAY
+            .read()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .as_ref()
+            .and_then(|overlay| overlay.get(name).cloned())
+    }
+}
+
+impl OverlaidEnvironment {
+    /// Wraps the given [`Environment`] source.
+    pub(crate) fn new(inner: Environment) -> Self {
+        Self { inner }
+    }
+}
+
+impl Source for OverlaidEnvironment {
+    fn clone_into_box(&self) -> Box<dyn Source + Send + Sync> {
+        Box::new(self.clone())
+    }
+
+    fn collect(&self) -> Result<Map<String, Value>, ConfigError> {
+        // Nothing to overlay, which is the case until something changes after start-up
+        if EnvOverlay::is_empty() {
+            return self.inner.collect();
+        }
+
+        self.inner
+            .clone()
+            .source(Some(EnvOverlay::vars().into_iter().collect()))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use config::Config;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn overlay_env() {
+        // Given
+        unsafe {
+            env::set_var("STRUT_OVERLAY_KEPT", "kept");
+            env::set_var("STRUT_OVERLAY_CHANGED", "old");
+            env::set_var("STRUT_OVERLAY_HIDDEN", "hidden");
+        }
+
+        // When
+        EnvOverlay::set("STRUT_OVERLAY_CHANGED", Some("new".to_string()));
+        EnvOverlay::set("STRUT_OVERLAY_HIDDEN", None);
+        EnvOverlay::set("STRUT_OVERLAY_ADDED", Some("added".to_string()));
+        let config = Config::builder()
+            .add_source(OverlaidEnvironment::new(
+                Environment::default().prefix("STRUT_OVERLAY"),
+            ))
+            .build()
+            .unwrap();
+
+        // Then
+        assert_eq!(
+            EnvOverlay::var("STRUT_OVERLAY_KEPT").as_deref(),
+            Some("kept")
+        );
+        assert_eq!(
+            EnvOverlay::var("STRUT_OVERLAY_CHANGED").as_deref(),
+            Some("new")
+        );
+        assert_eq!(EnvOverlay::var("STRUT_OVERLAY_HIDDEN"), None);
+        assert!(!EnvOverlay::is_set("STRUT_OVERLAY_HIDDEN"));
+        assert_eq!(env::var("STRUT_OVERLAY_CHANGED").as_deref(), Ok("old"));
+        assert_eq!(config.get_string("kept").unwrap(), "kept");
+        assert_eq!(config.get_string("changed").unwrap(), "new");
+        assert_eq!(config.get_string("added").unwrap(), "added");
+        assert!(config.get_string("hidden").is_err());
+    }
+}
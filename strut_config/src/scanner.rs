@@ -1,12 +1,21 @@
+use crate::scanner::file::is_local_name;
 use crate::{ConfigEntry, ConfigFile};
+use config::{Config, File};
 use std::env;
 use std::path::PathBuf;
-use strut_core::Pivot;
+use strut_core::{AppProfile, Pivot};
 
 pub mod dir;
 pub mod entry;
 pub mod file;
 
+/// The key under which the generic config files may declare the inheritance
+/// between profiles.
+pub const PROFILES_KEY: &str = "profiles";
+
+/// The key under which a single profile declares its parent profile.
+pub const INHERITS_KEY: &str = "inherits";
+
 /// A small facade for finding the [`ConfigFile`]s relevant for the current
 /// binary crate.
 pub struct Scanner;
@@ -28,22 +37,76 @@ impl Scanner {
     /// The following file formats are recognized:
     /// - TOML (`.toml`)
     /// - YAML (`.yml`, `.yaml`)
+    /// - JSON (`.json`), with the `json` feature
+    /// - JSON5 (`.json5`), with the `json5` feature
+    /// - RON (`.ron`), with the `ron` feature
+    /// - INI (`.ini`), with the `ini` feature
     ///
     /// ## File Types
     ///
     /// - **Generic config files**: Apply to all profiles.
     ///   - Pattern: `config/{any_name}.{ext}`
     /// - **Profile-specific config files**: Apply only if the file’s profile
-    ///   matches the [active](AppProfile::active) [`AppProfile`].
+    ///   matches any of the [active](AppProfile::active_all) [`AppProfile`]s,
+    ///   or any profile they [inherit](AppProfile::is_or_inherits) from.
     ///   - Patterns:
     ///     - `config/{any_name}.{profile}.{ext}`
     ///     - `config/{profile}/{any_name}.{ext}`
     ///
+    /// - **Replica-specific config files**: Apply only to the replica with the
+    ///   given [index](strut_core::AppReplica::index) (e.g., to run the
+    ///   schedulers on the replica `0` only). Each generic or profile-specific
+    ///   pattern above has a replica-specific counterpart, marked with
+    ///   `replica-{index}` before the extension:
+    ///   - Patterns:
+    ///     - `config/{any_name}.replica-{index}.{ext}`
+    ///     - `config/{any_name}.{profile}.replica-{index}.{ext}`
+    ///     - `config/{profile}/{any_name}.replica-{index}.{ext}`
+    ///
+    /// - **Local config files**: Hold the personal overrides of a developer,
+    ///   and are meant to be excluded from version control. Each generic or
+    ///   profile-specific pattern above has a local counterpart, either marked
+    ///   with `local` before the extension, or placed in the `config/local/`
+    ///   directory (which mirrors the layout of the config directory):
+    ///   - Patterns:
+    ///     - `config/{any_name}.local.{ext}`
+    ///     - `config/{any_name}.{profile}.local.{ext}`
+    ///     - `config/{profile}/{any_name}.local.{ext}`
+    ///     - `config/local/{any_name}.{ext}`
+    ///     - `config/local/{any_name}.{profile}.{ext}`
+    ///     - `config/local/{profile}/{any_name}.{ext}`
+    ///   - Consequently, `local` cannot be used as a profile name in file and
+    ///     directory names (nor can `replica-{index}`).
+    ///   - The local marker goes after the replica marker, e.g.,
+    ///     `config/app.prod.replica-0.local.toml`.
+    ///
+    /// ## Profile Inheritance
+    ///
+    /// Besides the `APP_PROFILE` environment variable (e.g.,
+    /// `APP_PROFILE=canary:prod`), the inheritance between profiles may be
+    /// declared in the generic config files:
+    ///
+    /// ```toml
+    /// [profiles.canary]
+    /// inherits = "prod"
+    /// ```
+    ///
     /// ## Ordering
     ///
+    /// - Non-local files always precede local files, mirroring how the
+    ///   `.env.local` files take precedence over the `.env` files. Within each
+    ///   of these groups:
     /// - Generic files always precede profile-specific files.
-    /// - Within each group (generic and profile-specific), files are ordered
+    /// - Generic files are ordered lexicographically by full path.
+    /// - Profile-specific files are ordered by the
+    ///   [layer](AppProfile::active_layers) of their profile (inherited
+    ///   profiles first, then the active profiles in the given order), and then
     ///   lexicographically by full path.
+    /// - Replica-specific files are merged on top of the generic or
+    ///   profile-specific layer that they belong to.
+    ///
+    /// The resulting order is therefore: generic files, profile-specific files,
+    /// local generic files, local profile-specific files.
     ///
     /// ## Notes
     ///
@@ -57,20 +120,98 @@ impl Scanner {
         // Resolve the config directory
         let config_dir = Self::resolve_config_dir(dir_name);
 
-        // Resolve the config files
-        let mut config_files = ConfigEntry::dir(config_dir) // start with config dir
+        // Resolve the config files of all profiles
+        let all_config_files = ConfigEntry::dir(config_dir) // start with config dir
             .cd() // dive one level in
-            .flat_map(ConfigEntry::cd_capturing_profile) // dive another level in, capturing profile name from directory name
-            .filter(ConfigEntry::applies_to_active_profile) // keep everything associated with active profile
+            .flat_map(Self::cd_top_level) // dive another level in, capturing profile name from directory name
             .filter_map(ConfigEntry::to_config_file) // keep only config files (discard any further nested directories)
             .collect::<Vec<_>>(); // collect into a vector
 
-        // Sort logically in place
-        config_files.sort();
+        // Generic files may declare inheritance, which affects what applies below
+        Self::declare_profile_parents(&all_config_files);
+
+        // Keep everything associated with active profiles and the active replica
+        let mut config_files = all_config_files
+            .into_iter()
+            .filter(ConfigFile::applies_to_active_profile)
+            .filter(ConfigFile::applies_to_active_replica)
+            .collect::<Vec<_>>();
+
+        // Sort logically in place: generics first, then specifics by profile layer (replicas last in
+        // each), then the same for locals
+        let layers = AppProfile::active_layers();
+        config_files.sort_by_cached_key(|config_file| {
+            let layer = config_file
+                .profile()
+                .map(|profile| layers.iter().position(|layer| layer.is(profile)));
+
+            (
+                config_file.is_local(),
+                layer,
+                config_file.replica(),
+                config_file.path().clone(),
+            )
+        });
 
         config_files
     }
 
+    /// Dives into the given top-level entry of the config directory, capturing
+    /// the profile name from the directory name. The `local` directory is the
+    /// exception: it mirrors the layout of the config directory, so its
+    /// entries are not associated with any profile, and its own nested
+    /// directories capture the profile name instead.
+    fn cd_top_level(entry: ConfigEntry) -> Vec<ConfigEntry> {
+        if entry.is_directory() && entry.name().is_some_and(is_local_name) {
+            return entry
+                .cd_forgetting_profile()
+                .flat_map(ConfigEntry::cd_capturing_profile)
+                .collect();
+        }
+
+        entry.cd_capturing_profile().collect()
+    }
+
+    /// [Declares](AppProfile::declare_parent) the inheritance between profiles
+    /// found under the `profiles` key of the given generic config files.
+    fn declare_profile_parents(config_files: &[ConfigFile]) {
+        let builder = config_files
+            .iter()
+            .filter(|config_file| config_file.is_generic())
+            .filter(|config_file| config_file.applies_to_active_replica())
+            .fold(Config::builder(), |builder, config_file| {
+                builder.add_source(
+                    File::from(config_file.path().as_path()).format(config_file.format()),
+                )
+            });
+
+        // Malformed files are reported when the configuration is built for real
+        let Ok(profiles) = builder
+            .build()
+            .and_then(|config| config.get_table(PROFILES_KEY))
+        else {
+            return;
+        };
+
+        for (child, declaration) in profiles {
+            let parent = declaration
+                .into_table()
+                .ok()
+                .and_then(|mut declaration| declaration.remove(INHERITS_KEY))
+                .and_then(|parent| parent.into_string().ok());
+
+            // Invalid profile names are ignored, as they can never be active
+            let Some(Ok(parent)) = parent.map(AppProfile::try_new) else {
+                continue;
+            };
+            let Ok(child) = AppProfile::try_new(child) else {
+                continue;
+            };
+
+            AppProfile::declare_parent(child, parent);
+        }
+    }
+
     /// Resolves the application’s **configuration directory**: where Strut
     /// looks for configuration files.
     ///
@@ -85,7 +226,7 @@ impl Scanner {
     /// If the resolved path is relative, it is interpreted relative to the
     /// [pivot directory](Self::resolve_pivot_dir). Returns an absolute
     /// [`PathBuf`] of the configuration directory.
-    fn resolve_config_dir(path: Option<&str>) -> PathBuf {
+    pub fn resolve_config_dir(path: Option<&str>) -> PathBuf {
         let input_path = env::var("APP_CONFIG_DIR") // environment takes highest priority
             .map(PathBuf::from)
             .ok()
@@ -101,9 +101,11 @@ impl ConfigDir {
     }
 
     /// Reports whether this [`ConfigDir`] [applies](ConfigDir::applies_to) to
-    /// the [active](AppProfile::active) [`AppProfile`].
+    /// any of the [active](AppProfile::active_all) [`AppProfile`]s.
     pub fn applies_to_active_profile(&self) -> bool {
-        self.applies_to(AppProfile::active())
+        AppProfile::active_all()
+            .iter()
+            .any(|profile| self.applies_to(profile))
     }
 
     /// Reports whether this [`ConfigDir`] applies to the given [`AppProfile`].
@@ -111,13 +113,14 @@ impl ConfigDir {
     /// A generic config file (without a profile name in its file name) applies
     /// to any profile by default. A specific config file (with a profile name
     /// in its file name) applies to the given profile if the profile name
-    /// matches.
+    /// matches, or if the given profile
+    /// [inherits](AppProfile::is_or_inherits) from the named profile.
     pub fn applies_to(&self, profile: impl AsRef<AppProfile>) -> bool {
         let given_profile = profile.as_ref();
 
         match *self {
             Self::Generic(_) => true,
-            Self::Specific { ref profile, .. } => given_profile.is(profile),
+            Self::Specific { ref profile, .. } => given_profile.is_or_inherits(profile),
         }
     }
 
@@ -1,5 +1,5 @@
-use crate::scanner::dir::ConfigDir;
 use crate::ConfigFile;
+use crate::scanner::dir::ConfigDir;
 use std::path::PathBuf;
 use strut_core::AppProfile;
 
@@ -74,9 +74,11 @@ impl ConfigEntry {
     }
 
     /// Reports whether this [`ConfigEntry`] [applies](ConfigEntry::applies_to)
-    /// to the [active](AppProfile::active) [`AppProfile`].
+    /// to any of the [active](AppProfile::active_all) [`AppProfile`]s.
     pub fn applies_to_active_profile(&self) -> bool {
-        self.applies_to(AppProfile::active())
+        AppProfile::active_all()
+            .iter()
+            .any(|profile| self.applies_to(profile))
     }
 
     /// Reports whether this [`ConfigEntry`] applies to the given [`AppProfile`].
@@ -1,7 +1,20 @@
 use config::{File, FileFormat, FileSourceFile};
 use std::cmp::Ordering;
-use std::path::PathBuf;
-use strut_core::AppProfile;
+use std::ffi::OsStr;
+use std::path::{Path, PathBuf};
+use strut_core::{AppProfile, AppReplica};
+
+/// The name that marks a config file (e.g., `app.local.toml`) or a config
+/// directory (e.g., `config/local/`) as local.
+const LOCAL_NAME: &str = "local";
+
+/// The prefix of the marker that associates a config file with a replica
+/// index (e.g., `app.replica-0.toml`).
+const REPLICA_PREFIX: &str = "replica-";
+
+/// A function that creates a [`ConfigFile`] of a particular format from the
+/// given path and optional profile name.
+type ConfigFileMaker = fn(PathBuf, Option<String>) -> Option<ConfigFile>;
 
 /// Represents a single config file.
 #[derive(Debug, Eq, PartialEq)]
@@ -29,6 +42,62 @@ pub enum ConfigFile {
         /// The associated profile name.
         profile: String,
     },
+
+    /// A file like `app.json`.
+    #[cfg(feature = "json")]
+    GenericJson(PathBuf),
+
+    /// A file like `app.prod.json`.
+    #[cfg(feature = "json")]
+    SpecificJson {
+        /// The path to the file.
+        path: PathBuf,
+
+        /// The associated profile name.
+        profile: String,
+    },
+
+    /// A file like `app.json5`.
+    #[cfg(feature = "json5")]
+    GenericJson5(PathBuf),
+
+    /// A file like `app.prod.json5`.
+    #[cfg(feature = "json5")]
+    SpecificJson5 {
+        /// The path to the file.
+        path: PathBuf,
+
+        /// The associated profile name.
+        profile: String,
+    },
+
+    /// A file like `app.ron`.
+    #[cfg(feature = "ron")]
+    GenericRon(PathBuf),
+
+    /// A file like `app.prod.ron`.
+    #[cfg(feature = "ron")]
+    SpecificRon {
+        /// The path to the file.
+        path: PathBuf,
+
+        /// The associated profile name.
+        profile: String,
+    },
+
+    /// A file like `app.ini`.
+    #[cfg(feature = "ini")]
+    GenericIni(PathBuf),
+
+    /// A file like `app.prod.ini`.
+    #[cfg(feature = "ini")]
+    SpecificIni {
+        /// The path to the file.
+        path: PathBuf,
+
+        /// The associated profile name.
+        profile: String,
+    },
 }
 
 impl ConfigFile {
@@ -43,24 +112,16 @@ impl ConfigFile {
     /// name.
     pub fn try_make_with_profile(path: PathBuf, known_profile: Option<&str>) -> Option<Self> {
         // Read file name
-        let name = match path.file_name().and_then(std::ffi::OsStr::to_str) {
-            Some(name) => name,
-            None => return None,
-        };
+        let name = path.file_name().and_then(OsStr::to_str)?;
 
-        // Split file name on `.`
-        let chunks = name.split('.').collect::<Vec<_>>();
+        // Split file name on `.`, dropping the local and the replica markers (if any)
+        let (chunks, _, _) = split_file_name(name);
 
         // Match chunk pattern
         match *chunks.as_slice() {
             [_name, extension] => {
-                if is_toml_extension(extension) {
-                    Self::toml_from(path, known_profile)
-                } else if is_yaml_extension(extension) {
-                    Self::yaml_from(path, known_profile)
-                } else {
-                    None
-                }
+                let make = Self::maker_for(extension)?;
+                make(path, known_profile.map(str::to_string))
             }
             [_name, profile, extension] => {
                 // Do we know the profile already?
@@ -72,39 +133,92 @@ impl ConfigFile {
                 }
 
                 // Only take supported extensions
-                if is_toml_extension(extension) {
-                    let profile = profile.to_string();
-                    Self::toml_from(path, Some(profile))
-                } else if is_yaml_extension(extension) {
-                    let profile = profile.to_string();
-                    Self::yaml_from(path, Some(profile))
-                } else {
-                    None
-                }
+                let make = Self::maker_for(extension)?;
+                let profile = profile.to_string();
+                make(path, Some(profile))
             }
             _ => None,
         }
     }
 
-    fn toml_from(path: PathBuf, profile: Option<impl Into<String>>) -> Option<Self> {
+    /// Returns the function that creates a [`ConfigFile`] of the format
+    /// recognized by the given file extension, if the format is supported (and
+    /// its feature is enabled).
+    fn maker_for(extension: &str) -> Option<ConfigFileMaker> {
+        if is_toml_extension(extension) {
+            return Some(Self::toml_from);
+        }
+
+        if is_yaml_extension(extension) {
+            return Some(Self::yaml_from);
+        }
+
+        #[cfg(feature = "json")]
+        if is_json_extension(extension) {
+            return Some(Self::json_from);
+        }
+
+        #[cfg(feature = "json5")]
+        if is_json5_extension(extension) {
+            return Some(Self::json5_from);
+        }
+
+        #[cfg(feature = "ron")]
+        if is_ron_extension(extension) {
+            return Some(Self::ron_from);
+        }
+
+        #[cfg(feature = "ini")]
+        if is_ini_extension(extension) {
+            return Some(Self::ini_from);
+        }
+
+        None
+    }
+
+    fn toml_from(path: PathBuf, profile: Option<String>) -> Option<Self> {
         match profile {
             None => Some(ConfigFile::GenericToml(path)),
-            Some(profile) => {
-                let profile = profile.into();
-
-                Some(ConfigFile::SpecificToml { path, profile })
-            }
+            Some(profile) => Some(ConfigFile::SpecificToml { path, profile }),
         }
     }
 
-    fn yaml_from(path: PathBuf, profile: Option<impl Into<String>>) -> Option<Self> {
+    fn yaml_from(path: PathBuf, profile: Option<String>) -> Option<Self> {
         match profile {
             None => Some(ConfigFile::GenericYaml(path)),
-            Some(profile) => {
-                let profile = profile.into();
+            Some(profile) => Some(ConfigFile::SpecificYaml { path, profile }),
+        }
+    }
 
-                Some(ConfigFile::SpecificYaml { path, profile })
-            }
+    #[cfg(feature = "json")]
+    fn json_from(path: PathBuf, profile: Option<String>) -> Option<Self> {
+        match profile {
+            None => Some(ConfigFile::GenericJson(path)),
+            Some(profile) => Some(ConfigFile::SpecificJson { path, profile }),
+        }
+    }
+
+    #[cfg(feature = "json5")]
+    fn json5_from(path: PathBuf, profile: Option<String>) -> Option<Self> {
+        match profile {
+            None => Some(ConfigFile::GenericJson5(path)),
+            Some(profile) => Some(ConfigFile::SpecificJson5 { path, profile }),
+        }
+    }
+
+    #[cfg(feature = "ron")]
+    fn ron_from(path: PathBuf, profile: Option<String>) -> Option<Self> {
+        match profile {
+            None => Some(ConfigFile::GenericRon(path)),
+            Some(profile) => Some(ConfigFile::SpecificRon { path, profile }),
+        }
+    }
+
+    #[cfg(feature = "ini")]
+    fn ini_from(path: PathBuf, profile: Option<String>) -> Option<Self> {
+        match profile {
+            None => Some(ConfigFile::GenericIni(path)),
+            Some(profile) => Some(ConfigFile::SpecificIni { path, profile }),
         }
     }
 }
@@ -113,10 +227,7 @@ impl ConfigFile {
     /// Reports whether this [`ConfigFile`] is applicable regardless of the
     /// [active](AppProfile::active) [`AppProfile`].
     pub fn is_generic(&self) -> bool {
-        match *self {
-            Self::GenericToml(_) | Self::GenericYaml(_) => true,
-            Self::SpecificToml { .. } | Self::SpecificYaml { .. } => false,
-        }
+        self.profile().is_none()
     }
 
     /// Reports whether this [`ConfigFile`] is applicable only to a particular
@@ -125,6 +236,36 @@ impl ConfigFile {
         !self.is_generic()
     }
 
+    /// Reports whether this [`ConfigFile`] is local, meaning that it holds the
+    /// personal overrides of a developer (and is usually not committed). A
+    /// local file is either marked so in its name (e.g., `app.local.toml` or
+    /// `app.prod.local.toml`), or located in a `local` directory (e.g.,
+    /// `local/app.toml`, `local/app.prod.toml`, or `local/prod/app.toml`).
+    pub fn is_local(&self) -> bool {
+        let path = self.path().as_path();
+
+        // Is the file marked as local in its name?
+        let marked = name_of(Some(path)).is_some_and(|name| split_file_name(name).1);
+
+        // Is the file in the local directory, or in a profile directory within it?
+        let parent = path.parent();
+        let grandparent = parent.and_then(Path::parent);
+        let nested = name_of(parent).is_some_and(is_local_name)
+            || (self.profile().is_some()
+                && name_of(parent) == self.profile()
+                && name_of(grandparent).is_some_and(is_local_name));
+
+        marked || nested
+    }
+
+    /// Returns the replica index that this [`ConfigFile`] is marked with in its
+    /// name (e.g., `0` for `app.replica-0.toml` or `app.prod.replica-0.toml`),
+    /// if any. Such a file applies only to the replica with that
+    /// [index](AppReplica::index).
+    pub fn replica(&self) -> Option<usize> {
+        name_of(Some(self.path())).and_then(|name| split_file_name(name).2)
+    }
+
     /// Returns a reference to the internally held [`PathBuf`].
     pub fn path(&self) -> &PathBuf {
         match *self {
@@ -132,6 +273,22 @@ impl ConfigFile {
             Self::GenericYaml(ref path) => path,
             Self::SpecificToml { ref path, .. } => path,
             Self::SpecificYaml { ref path, .. } => path,
+            #[cfg(feature = "json")]
+            Self::GenericJson(ref path) => path,
+            #[cfg(feature = "json")]
+            Self::SpecificJson { ref path, .. } => path,
+            #[cfg(feature = "json5")]
+            Self::GenericJson5(ref path) => path,
+            #[cfg(feature = "json5")]
+            Self::SpecificJson5 { ref path, .. } => path,
+            #[cfg(feature = "ron")]
+            Self::GenericRon(ref path) => path,
+            #[cfg(feature = "ron")]
+            Self::SpecificRon { ref path, .. } => path,
+            #[cfg(feature = "ini")]
+            Self::GenericIni(ref path) => path,
+            #[cfg(feature = "ini")]
+            Self::SpecificIni { ref path, .. } => path,
         }
     }
 
@@ -143,13 +300,48 @@ impl ConfigFile {
             Self::GenericYaml(_) => None,
             Self::SpecificToml { ref profile, .. } => Some(profile),
             Self::SpecificYaml { ref profile, .. } => Some(profile),
+            #[cfg(feature = "json")]
+            Self::GenericJson(_) => None,
+            #[cfg(feature = "json")]
+            Self::SpecificJson { ref profile, .. } => Some(profile),
+            #[cfg(feature = "json5")]
+            Self::GenericJson5(_) => None,
+            #[cfg(feature = "json5")]
+            Self::SpecificJson5 { ref profile, .. } => Some(profile),
+            #[cfg(feature = "ron")]
+            Self::GenericRon(_) => None,
+            #[cfg(feature = "ron")]
+            Self::SpecificRon { ref profile, .. } => Some(profile),
+            #[cfg(feature = "ini")]
+            Self::GenericIni(_) => None,
+            #[cfg(feature = "ini")]
+            Self::SpecificIni { ref profile, .. } => Some(profile),
         }
     }
 
     /// Reports whether this [`ConfigFile`] [applies](ConfigFile::applies_to) to
-    /// the [active](AppProfile::active) [`AppProfile`].
+    /// any of the [active](AppProfile::active_all) [`AppProfile`]s.
     pub fn applies_to_active_profile(&self) -> bool {
-        self.applies_to(AppProfile::active())
+        AppProfile::active_all()
+            .iter()
+            .any(|profile| self.applies_to(profile))
+    }
+
+    /// Reports whether this [`ConfigFile`] applies to the
+    /// [active](AppReplica::index) replica: either it is not marked with any
+    /// [replica index](ConfigFile::replica), or it is marked with the active
+    /// one.
+    pub fn applies_to_active_replica(&self) -> bool {
+        self.applies_to_replica(AppReplica::index())
+    }
+
+    /// Reports whether this [`ConfigFile`] applies to the replica with the
+    /// given index (or to a replica without an index, if [`None`] is given).
+    pub fn applies_to_replica(&self, index: Option<usize>) -> bool {
+        match self.replica() {
+            None => true,
+            Some(replica) => index == Some(replica),
+        }
     }
 
     /// Reports whether this [`ConfigFile`] applies to the given [`AppProfile`].
@@ -157,15 +349,14 @@ impl ConfigFile {
     /// A generic config file (without a profile name in its file name) applies
     /// to any profile by default. A specific config file (with a profile name
     /// in its file name) applies to the given profile if the profile name
-    /// matches.
+    /// matches, or if the given profile
+    /// [inherits](AppProfile::is_or_inherits) from the named profile.
     pub fn applies_to(&self, profile: impl AsRef<AppProfile>) -> bool {
         let given_profile = profile.as_ref();
 
-        match *self {
-            Self::GenericToml(_) | Self::GenericYaml(_) => true,
-            Self::SpecificToml { ref profile, .. } | Self::SpecificYaml { ref profile, .. } => {
-                given_profile.is(profile)
-            }
+        match self.profile() {
+            None => true,
+            Some(profile) => given_profile.is_or_inherits(profile),
         }
     }
 }
@@ -180,11 +371,27 @@ impl PartialOrd for ConfigFile {
 impl Ord for ConfigFile {
     /// Implements the custom ordering rules for [`ConfigFile`].
     ///
-    /// First rule is that generics always come before specifics (so that
-    /// specifics would override generics). After that, both subgroups are
-    /// ordered by their file path. For specific files, we order by the profile
-    /// name before we order by the path.
+    /// First rule is that [local](ConfigFile::is_local) files always come after
+    /// non-local files (so that local files would override everything else),
+    /// mirroring how the `.env.local` files take precedence over the `.env`
+    /// files. Second rule is that within each of these groups, generics always
+    /// come before specifics (so that specifics would override generics). After
+    /// that, both subgroups are ordered by their [replica](ConfigFile::replica)
+    /// marker (so that replica files would override the rest of their layer),
+    /// and then by their file path. For specific files, we order by the profile
+    /// name before anything else.
+    ///
+    /// The resulting order is therefore: generic files, specific files, local
+    /// generic files, local specific files, where every group ends with its
+    /// replica files.
     fn cmp(&self, other: &Self) -> Ordering {
+        // Local files come last, regardless of anything else
+        match (self.is_local(), other.is_local()) {
+            (false, true) => return Ordering::Less,
+            (true, false) => return Ordering::Greater,
+            _ => { /* either both local or both non-local */ }
+        }
+
         // Are the given two generic?
         let self_generic = self.is_generic();
         let other_generic = other.is_generic();
@@ -200,9 +407,13 @@ impl Ord for ConfigFile {
         let self_path = self.path();
         let other_path = other.path();
 
+        // Replica files come after the rest of their layer
+        let self_key = (self.replica(), self_path);
+        let other_key = (other.replica(), other_path);
+
         // If both are generic, it’s also an easy job
         if self_generic {
-            return self_path.cmp(other_path);
+            return self_key.cmp(&other_key);
         }
 
         // Both are profile-specific: extract profile names
@@ -213,18 +424,62 @@ impl Ord for ConfigFile {
         match (self_profile, other_profile) {
             // Both have profile name
             (Some(self_profile_name), Some(other_profile_name)) => {
-                // Compare profile names first, then paths
+                // Compare profile names first, then replicas and paths
                 match self_profile_name.cmp(other_profile_name) {
-                    Ordering::Equal => self_path.cmp(other_path),
+                    Ordering::Equal => self_key.cmp(&other_key),
                     non_eq => non_eq,
                 }
             }
-            // No profile names: just compare paths
-            _ => self_path.cmp(other_path),
+            // No profile names: just compare replicas and paths
+            _ => self_key.cmp(&other_key),
         }
     }
 }
 
+/// Returns the file name of the given path, if any, and if it is readable.
+fn name_of(path: Option<&Path>) -> Option<&str> {
+    path.and_then(Path::file_name).and_then(OsStr::to_str)
+}
+
+/// Splits the given file name on `.`, dropping the local marker and then the
+/// replica marker that precede the extension (if any). Returns the remaining
+/// chunks, whether the local marker is found, and the replica index from the
+/// replica marker.
+fn split_file_name(name: &str) -> (Vec<&str>, bool, Option<usize>) {
+    let mut chunks = name.split('.').collect::<Vec<_>>();
+
+    let local = chunks.len() > 2 && is_local_name(chunks[chunks.len() - 2]);
+    if local {
+        chunks.remove(chunks.len() - 2);
+    }
+
+    let replica = match chunks.len() > 2 {
+        true => parse_replica_name(chunks[chunks.len() - 2]),
+        false => None,
+    };
+    if replica.is_some() {
+        chunks.remove(chunks.len() - 2);
+    }
+
+    (chunks, local, replica)
+}
+
+/// Parses the replica index from the given replica marker (e.g., `replica-0`).
+fn parse_replica_name(name: &str) -> Option<usize> {
+    let prefix = name.get(..REPLICA_PREFIX.len())?;
+    if !prefix.eq_ignore_ascii_case(REPLICA_PREFIX) {
+        return None;
+    }
+
+    name[REPLICA_PREFIX.len()..].parse().ok()
+}
+
+/// Reports whether the given string slice is the name that marks local config
+/// files and directories.
+pub(crate) fn is_local_name(name: &str) -> bool {
+    name.eq_ignore_ascii_case(LOCAL_NAME)
+}
+
 /// Reports whether the given string slice is a recognized YAML extension.
 fn is_yaml_extension(ext: &str) -> bool {
     ext.eq_ignore_ascii_case("yml") || ext.eq_ignore_ascii_case("yaml")
@@ -235,12 +490,44 @@ fn is_toml_extension(ext: &str) -> bool {
     ext.eq_ignore_ascii_case("toml")
 }
 
+/// Reports whether the given string slice is a recognized JSON extension.
+#[cfg(feature = "json")]
+fn is_json_extension(ext: &str) -> bool {
+    ext.eq_ignore_ascii_case("json")
+}
+
+/// Reports whether the given string slice is a recognized JSON5 extension.
+#[cfg(feature = "json5")]
+fn is_json5_extension(ext: &str) -> bool {
+    ext.eq_ignore_ascii_case("json5")
+}
+
+/// Reports whether the given string slice is a recognized RON extension.
+#[cfg(feature = "ron")]
+fn is_ron_extension(ext: &str) -> bool {
+    ext.eq_ignore_ascii_case("ron")
+}
+
+/// Reports whether the given string slice is a recognized INI extension.
+#[cfg(feature = "ini")]
+fn is_ini_extension(ext: &str) -> bool {
+    ext.eq_ignore_ascii_case("ini")
+}
+
 impl ConfigFile {
     /// Returns the corresponding [`FileFormat`].
-    fn format(&self) -> FileFormat {
+    pub(crate) fn format(&self) -> FileFormat {
         match *self {
             Self::GenericToml(_) | Self::SpecificToml { .. } => FileFormat::Toml,
             Self::GenericYaml(_) | Self::SpecificYaml { .. } => FileFormat::Yaml,
+            #[cfg(feature = "json")]
+            Self::GenericJson(_) | Self::SpecificJson { .. } => FileFormat::Json,
+            #[cfg(feature = "json5")]
+            Self::GenericJson5(_) | Self::SpecificJson5 { .. } => FileFormat::Json5,
+            #[cfg(feature = "ron")]
+            Self::GenericRon(_) | Self::SpecificRon { .. } => FileFormat::Ron,
+            #[cfg(feature = "ini")]
+            Self::GenericIni(_) | Self::SpecificIni { .. } => FileFormat::Ini,
         }
     }
 }
@@ -252,6 +539,22 @@ impl From<ConfigFile> for PathBuf {
             ConfigFile::GenericYaml(path) => path,
             ConfigFile::SpecificToml { path, .. } => path,
             ConfigFile::SpecificYaml { path, .. } => path,
+            #[cfg(feature = "json")]
+            ConfigFile::GenericJson(path) => path,
+            #[cfg(feature = "json")]
+            ConfigFile::SpecificJson { path, .. } => path,
+            #[cfg(feature = "json5")]
+            ConfigFile::GenericJson5(path) => path,
+            #[cfg(feature = "json5")]
+            ConfigFile::SpecificJson5 { path, .. } => path,
+            #[cfg(feature = "ron")]
+            ConfigFile::GenericRon(path) => path,
+            #[cfg(feature = "ron")]
+            ConfigFile::SpecificRon { path, .. } => path,
+            #[cfg(feature = "ini")]
+            ConfigFile::GenericIni(path) => path,
+            #[cfg(feature = "ini")]
+            ConfigFile::SpecificIni { path, .. } => path,
         }
     }
 }
@@ -264,3 +567,141 @@ impl From<ConfigFile> for File<FileSourceFile, FileFormat> {
         File::from(path).format(format)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn try_make_local() {
+        // Given
+        let paths = [
+            ("config/app.local.toml", None),
+            ("config/app.prod.local.yml", None),
+            ("config/prod/app.LOCAL.toml", Some("prod")),
+            ("config/local/app.toml", None),
+            ("config/local/app.prod.toml", None),
+            ("config/local/prod/app.yaml", Some("prod")),
+            ("config/app.toml", None),
+            ("config/prod/app.toml", Some("prod")),
+        ];
+
+        // When
+        let actual_output = paths
+            .iter()
+            .map(|(path, profile)| {
+                let config_file =
+                    ConfigFile::try_make_with_profile(PathBuf::from(path), *profile).unwrap();
+
+                (
+                    config_file.profile().map(str::to_string),
+                    config_file.is_local(),
+                )
+            })
+            .collect::<Vec<_>>();
+
+        // Then
+        assert_eq!(
+            actual_output,
+            [
+                (None, true),
+                (Some("prod".to_string()), true),
+                (Some("prod".to_string()), true),
+                (None, true),
+                (Some("prod".to_string()), true),
+                (Some("prod".to_string()), true),
+                (None, false),
+                (Some("prod".to_string()), false),
+            ],
+        );
+    }
+
+    #[test]
+    fn try_make_replica() {
+        // Given
+        let paths = [
+            ("config/app.replica-0.toml", None),
+            ("config/app.prod.Replica-12.yml", None),
+            ("config/prod/app.replica-3.toml", Some("prod")),
+            ("config/app.replica-1.local.toml", None),
+            ("config/app.replica-x.toml", None),
+            ("config/app.prod.toml", None),
+        ];
+
+        // When
+        let actual_output = paths
+            .iter()
+            .map(|(path, profile)| {
+                let config_file =
+                    ConfigFile::try_make_with_profile(PathBuf::from(path), *profile).unwrap();
+
+                (
+                    config_file.profile().map(str::to_string),
+                    config_file.replica(),
+                    config_file.is_local(),
+                )
+            })
+            .collect::<Vec<_>>();
+
+        // Then
+        assert_eq!(
+            actual_output,
+            [
+                (None, Some(0), false),
+                (Some("prod".to_string()), Some(12), false),
+                (Some("prod".to_string()), Some(3), false),
+                (None, Some(1), true),
+                (Some("replica-x".to_string()), None, false),
+                (Some("prod".to_string()), None, false),
+            ],
+        );
+    }
+
+    #[test]
+    fn applies_to_replica() {
+        // Given
+        let plain = ConfigFile::try_at(PathBuf::from("config/app.toml")).unwrap();
+        let replica = ConfigFile::try_at(PathBuf::from("config/app.replica-0.toml")).unwrap();
+
+        // Then
+        assert!(plain.applies_to_replica(None));
+        assert!(plain.applies_to_replica(Some(0)));
+        assert!(!replica.applies_to_replica(None));
+        assert!(replica.applies_to_replica(Some(0)));
+        assert!(!replica.applies_to_replica(Some(1)));
+    }
+
+    #[test]
+    fn cmp_local_last() {
+        // Given
+        let mut config_files = [
+            "config/local/app.toml",
+            "config/app.replica-0.toml",
+            "config/app.prod.replica-0.toml",
+            "config/app.prod.local.toml",
+            "config/app.local.toml",
+            "config/app.prod.toml",
+            "config/app.toml",
+        ]
+        .map(|path| ConfigFile::try_at(PathBuf::from(path)).unwrap());
+
+        // When
+        config_files.sort();
+
+        // Then
+        assert_eq!(
+            config_files.map(PathBuf::from),
+            [
+                "config/app.toml",
+                "config/app.replica-0.toml",
+                "config/app.prod.toml",
+                "config/app.prod.replica-0.toml",
+                "config/app.local.toml",
+                "config/local/app.toml",
+                "config/app.prod.local.toml",
+            ]
+            .map(PathBuf::from),
+        );
+    }
+}
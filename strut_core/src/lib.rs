@@ -4,7 +4,7 @@
 
 /// Application profile.
 mod profile;
-pub use self::profile::AppProfile;
+pub use self::profile::{AppProfile, ProfileNameError};
 
 /// Application context.
 mod context;
@@ -12,12 +12,12 @@ pub use self::context::AppContext;
 
 /// Application replica facade.
 mod replica;
-pub use self::replica::lifetime_id::{Glued, Hyphenated, LifetimeId, Underscored};
 pub use self::replica::AppReplica;
+pub use self::replica::lifetime_id::{Glued, Hyphenated, LifetimeId, Underscored};
 
 /// Application spindown registry & tokens.
 mod spindown;
-pub use self::spindown::{token::AppSpindownToken, AppSpindown};
+pub use self::spindown::{AppSpindown, registry::SpindownTimeout, token::AppSpindownToken};
 
 /// Implements a [`Pivot`] facade for centralized resolution of the pivot directory
 mod pivot;
@@ -27,6 +27,17 @@ pub use self::pivot::Pivot;
 /// should trigger an event for an external alerting system.
 pub const ALERT_FIELD_NAME: &str = "alert";
 
+/// The environment variables that the Strut family of crates reads directly,
+/// rather than as configuration keys, even though they share the `APP_`
+/// prefix with the environment overrides of the configuration.
+pub const RESERVED_ENV_VARS: &[&str] = &[
+    "APP_PROFILE",
+    "APP_CONFIG_DIR",
+    "APP_REPLICA_INDEX",
+    "APP_CONFIG_SECRET_KEY",
+    "APP_CONFIG_SECRET_KEY_FILE",
+];
+
 /// [Terminates](AppContext::terminate) the global [`AppContext`] and waits for
 /// [`AppSpindown`] to complete.
 ///
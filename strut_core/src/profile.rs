@@ -2,10 +2,11 @@ use crate::profile::name::Name;
 use std::env;
 use std::fmt::{Display, Formatter};
 use std::ops::Deref;
-use std::sync::OnceLock;
+use std::sync::{OnceLock, RwLock};
 
-/// Implements the stack-allocated string for storing custom profile names.
+/// Implements the validated string for storing custom profile names.
 mod name;
+pub use self::name::ProfileNameError;
 
 /// The string that is recognized as the [**production**](AppProfile::Prod)
 /// profile.
@@ -18,6 +19,24 @@ pub const APP_PROFILE_DEV: &str = "dev";
 /// The string that is recognized as the [**test**](AppProfile::Test) profile.
 pub const APP_PROFILE_TEST: &str = "test";
 
+/// The separator between multiple active profiles, e.g., `prod,eu`.
+pub const APP_PROFILE_SEPARATOR: char = ',';
+
+/// The separator between a profile and the profile it inherits from, e.g.,
+/// `canary:prod`.
+pub const APP_PROFILE_PARENT_SEPARATOR: char = ':';
+
+/// The parsed profile specification: the list of profiles, and the list of
+/// child-parent pairs.
+type ParsedSpec = (Vec<AppProfile>, Vec<(AppProfile, AppProfile)>);
+
+/// The statically stored [active](AppProfile::active_all) [`AppProfile`]s.
+static APP_PROFILES: OnceLock<Vec<AppProfile>> = OnceLock::new();
+
+/// The statically stored [inheritance](AppProfile::declare_parent) between
+/// profiles, as pairs of a child profile and its parent profile.
+static APP_PROFILE_PARENTS: RwLock<Vec<(AppProfile, AppProfile)>> = RwLock::new(Vec::new());
+
 /// Represents the runtime profile of the application. The profile affects
 /// primarily which set of configuration files is applied, and the application
 /// is free to implement any profile-specific logic.
@@ -29,9 +48,8 @@ pub const APP_PROFILE_TEST: &str = "test";
 /// - [**Test**](AppProfile::Test) profile.
 ///
 /// Then, there are [**custom profiles**](AppProfile::Custom), which can take
-/// any lowercase ASCII-only [name](Name) within the limit of
-/// [`NAME_MAX_LEN`](name::NAME_MAX_LEN) characters. The custom profile names
-/// are always forced to lowercase.
+/// any [name](Name) made of ASCII letters, digits, dashes, and underscores.
+/// The custom profile names are always forced to lowercase.
 ///
 /// This enumeration defines the
 /// [**active runtime profile**](AppProfile::active), which is lazily discerned
@@ -60,6 +78,25 @@ pub const APP_PROFILE_TEST: &str = "test";
 /// };
 /// ```
 ///
+/// ## Multiple profiles and inheritance
+///
+/// More than one profile may be active at once, and a profile may inherit from
+/// another profile. Both are expressed in the `APP_PROFILE` environment
+/// variable:
+///
+/// - `APP_PROFILE=prod,eu` activates the profiles `prod` and `eu`, with `eu`
+///   taking precedence over `prod`.
+/// - `APP_PROFILE=canary:prod` activates the profile `canary`, which inherits
+///   from `prod`: everything that applies to `prod` also applies to `canary`,
+///   unless `canary` overrides it.
+/// - `APP_PROFILE=canary:prod,eu` combines both.
+///
+/// Inheritance may also be [declared](AppProfile::declare_parent)
+/// programmatically (e.g., from the configuration files). The
+/// [`active_is`](AppProfile::active_is) family of methods takes both the
+/// multiple profiles and the inheritance into account, while
+/// [`active`](AppProfile::active) returns only the first active profile.
+///
 /// ## Implicit detection
 ///
 /// On the surface it looks like the three [`AppProfile`]s:
@@ -102,7 +139,7 @@ pub const APP_PROFILE_TEST: &str = "test";
 /// for the active profile, and instead rely on the special `APP_PROFILE`
 /// environment variable, falling back on the [`dev`](AppProfile::Dev) profile
 /// as the default.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum AppProfile {
     /// The **production** profile, used for live, customer-facing deployments.
     /// Typically configured with optimized performance settings, reduced
@@ -136,11 +173,11 @@ pub enum AppProfile {
     ///
     /// Examples from across the industry include `"preprod"`, `"qa"`, `"uat"`,
     /// `"staging"`, `"sandbox"`, `"demo"`, `"canary"`, `"perf"`, `"local"`,
-    /// `"ci"`, `"nightly"`, `"hotfix"`, etc.
+    /// `"ci"`, `"nightly"`, `"hotfix"`, `"staging-eu"`, `"performance"`, etc.
     ///
-    /// The name of a custom profile is limited to
-    /// [`NAME_MAX_LEN`](name::NAME_MAX_LEN) ASCII lowercase characters. All
-    /// examples above fit into that limit.
+    /// The name of a custom profile may be of any length, and is made of ASCII
+    /// lowercase letters, digits, dashes, and underscores. Names with any
+    /// other characters are [rejected](ProfileNameError).
     ///
     /// If your application needs any env-specific runtime configuration in a
     /// custom environment, it is advised to run the compiled binary with the
@@ -151,63 +188,237 @@ pub enum AppProfile {
 }
 
 impl AppProfile {
-    /// Returns the active runtime [`AppProfile`], lazily
+    /// Returns the first active runtime [`AppProfile`], lazily
     /// [discerned](AppProfile::discern).
+    ///
+    /// If more than one profile is active, see
+    /// [`active_all`](AppProfile::active_all).
     pub fn active() -> &'static AppProfile {
-        static APP_PROFILE: OnceLock<AppProfile> = OnceLock::new();
+        &Self::active_all()[0]
+    }
+
+    /// Returns all active runtime [`AppProfile`]s, lazily
+    /// [discerned](AppProfile::discern), in the order of increasing precedence.
+    /// The returned slice is never empty.
+    ///
+    /// The profiles that the active profiles inherit from are not included:
+    /// see [`active_layers`](AppProfile::active_layers).
+    pub fn active_all() -> &'static [AppProfile] {
+        APP_PROFILES.get_or_init(Self::discern)
+    }
+
+    /// Returns all active runtime [`AppProfile`]s along with the profiles they
+    /// [inherit](AppProfile::ancestry) from, in the order of increasing
+    /// precedence: every inherited profile precedes the profile that inherits
+    /// from it. Each profile appears only once, at its first position.
+    ///
+    /// For example, with `APP_PROFILE=canary:prod,eu`, the layers are `prod`,
+    /// `canary`, `eu`.
+    pub fn active_layers() -> Vec<AppProfile> {
+        let mut layers: Vec<AppProfile> = Vec::new();
+
+        for profile in Self::active_all() {
+            for layer in profile.ancestry().into_iter().rev() {
+                if !layers.contains(&layer) {
+                    layers.push(layer);
+                }
+            }
+        }
+
+        layers
+    }
+
+    /// Explicitly sets the active runtime [`AppProfile`], bypassing the
+    /// [discerning](AppProfile::discern) logic. This is useful when the profile
+    /// is chosen by other means than the environment, e.g., by a command-line
+    /// argument.
+    ///
+    /// The active profile can only be set once, before it is
+    /// [accessed](AppProfile::active) for the first time. Otherwise, the given
+    /// profile is returned back as an error.
+    pub fn set_active(profile: impl Into<AppProfile>) -> Result<(), AppProfile> {
+        APP_PROFILES
+            .set(vec![profile.into()])
+            .map_err(|mut profiles| profiles.remove(0))
+    }
+
+    /// Same as [`set_active`](AppProfile::set_active), but parses the given
+    /// specification the same way as the `APP_PROFILE` environment variable,
+    /// allowing for multiple profiles and inheritance (e.g.,
+    /// `canary:prod,eu`).
+    ///
+    /// The inheritance from the specification is only
+    /// [declared](AppProfile::declare_parent) if the active profiles are set
+    /// successfully. Otherwise, the parsed profiles are returned back as an
+    /// error.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if the specification contains an [invalid](ProfileNameError)
+    /// profile name. Use [`validate_spec`](AppProfile::validate_spec) to check
+    /// the specification beforehand.
+    pub fn set_active_spec(spec: impl AsRef<str>) -> Result<(), Vec<AppProfile>> {
+        let (profiles, parents) =
+            Self::parse_spec(spec.as_ref()).unwrap_or_else(|error| panic!("{}", error));
+
+        APP_PROFILES.set(profiles)?;
+
+        for (child, parent) in parents {
+            Self::declare_parent(child, parent);
+        }
+
+        Ok(())
+    }
+
+    /// Declares that the `child` profile inherits from the `parent` profile,
+    /// replacing any parent declared for the `child` before.
+    ///
+    /// Inheritance may be declared at any time, and is taken into account by
+    /// all subsequent checks of the active profile. A profile cannot inherit
+    /// from itself.
+    pub fn declare_parent(child: impl Into<AppProfile>, parent: impl Into<AppProfile>) {
+        let child = child.into();
+        let parent = parent.into();
+
+        if child == parent {
+            return;
+        }
 
-        APP_PROFILE.get_or_init(Self::discern)
+        let mut parents = APP_PROFILE_PARENTS
+            .write()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+
+        match parents.iter_mut().find(|(known, _)| *known == child) {
+            Some((_, known_parent)) => *known_parent = parent,
+            None => parents.push((child, parent)),
+        }
     }
 
     /// Constructs a new [`AppProfile`] with the given name.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if the given name is [invalid](ProfileNameError). Use
+    /// [`try_new`](AppProfile::try_new) to handle invalid names gracefully.
     pub fn new(name: impl AsRef<str>) -> Self {
-        let name = Name::new(name);
+        Self::try_new(name).unwrap_or_else(|error| panic!("{}", error))
+    }
 
-        match name.as_str() {
-            "prod" => Self::Prod,
-            "dev" => Self::Dev,
-            "test" => Self::Test,
+    /// Constructs a new [`AppProfile`] with the given name, or returns an
+    /// error if the name is [invalid](ProfileNameError).
+    pub fn try_new(name: impl AsRef<str>) -> Result<Self, ProfileNameError> {
+        let name = Name::try_new(name)?;
+
+        let profile = match name.as_str() {
+            APP_PROFILE_PROD => Self::Prod,
+            APP_PROFILE_DEV => Self::Dev,
+            APP_PROFILE_TEST => Self::Test,
             _ => Self::Custom(name),
-        }
+        };
+
+        Ok(profile)
+    }
+
+    /// Checks the given specification (e.g., `canary:prod,eu`) for
+    /// [invalid](ProfileNameError) profile names, without activating or
+    /// declaring anything.
+    pub fn validate_spec(spec: impl AsRef<str>) -> Result<(), ProfileNameError> {
+        Self::parse_spec(spec.as_ref()).map(|_| ())
     }
 
-    /// Reads the active runtime [`AppProfile`] from the `APP_PROFILE`
-    /// environment variable. If it is not set, delegates to
-    /// [`AppProfile::default`].
-    fn discern() -> Self {
+    /// Reads the active runtime [`AppProfile`]s from the `APP_PROFILE`
+    /// environment variable, declaring any inheritance found in it. If it is
+    /// not set, delegates to [`AppProfile::default`].
+    ///
+    /// Panics if the environment variable contains an
+    /// [invalid](ProfileNameError) profile name: running with a profile other
+    /// than the intended one is never the right outcome.
+    fn discern() -> Vec<Self> {
         // Detect if profile is set explicitly
-        if let Ok(profile) = env::var("APP_PROFILE") {
-            return Self::new(profile);
+        if let Ok(spec) = env::var("APP_PROFILE") {
+            let (profiles, parents) = Self::parse_spec(&spec).unwrap_or_else(|error| {
+                panic!("the APP_PROFILE environment variable is invalid: {}", error)
+            });
+
+            for (child, parent) in parents {
+                Self::declare_parent(child, parent);
+            }
+
+            return profiles;
         }
 
         // Otherwise, return the default
-        Self::default()
+        vec![Self::default()]
+    }
+
+    /// Parses the given profile specification (e.g., `canary:prod,eu`) into
+    /// the list of profiles and the list of child-parent pairs. The list of
+    /// profiles is never empty: it falls back to [`AppProfile::default`].
+    fn parse_spec(spec: &str) -> Result<ParsedSpec, ProfileNameError> {
+        let mut profiles = Vec::new();
+        let mut parents = Vec::new();
+
+        for entry in spec.split(APP_PROFILE_SEPARATOR) {
+            let chain = entry
+                .split(APP_PROFILE_PARENT_SEPARATOR)
+                .map(str::trim)
+                .filter(|name| !name.is_empty())
+                .map(Self::try_new)
+                .collect::<Result<Vec<_>, _>>()?;
+
+            // The first profile in the chain is the active one
+            let Some(profile) = chain.first() else {
+                continue;
+            };
+
+            if !profiles.contains(profile) {
+                profiles.push(profile.clone());
+            }
+
+            // Every next profile in the chain is the parent of the previous one
+            parents.extend(
+                chain
+                    .windows(2)
+                    .map(|pair| (pair[0].clone(), pair[1].clone())),
+            );
+        }
+
+        if profiles.is_empty() {
+            profiles.push(Self::default());
+        }
+
+        Ok((profiles, parents))
     }
 }
 
 impl AppProfile {
-    /// Reports whether the [`active`](AppProfile::active) profile is the
-    /// [**production**](AppProfile::Prod) profile.
+    /// Reports whether any of the [active](AppProfile::active_all) profiles
+    /// is or inherits from the [**production**](AppProfile::Prod) profile.
     pub fn active_is_prod() -> bool {
-        Self::active().is_prod()
+        Self::active_is(APP_PROFILE_PROD)
     }
 
-    /// Reports whether the [`active`](AppProfile::active) profile is the
-    /// [**development**](AppProfile::Dev) profile.
+    /// Reports whether any of the [active](AppProfile::active_all) profiles
+    /// is or inherits from the [**development**](AppProfile::Dev) profile.
     pub fn active_is_dev() -> bool {
-        Self::active().is_dev()
+        Self::active_is(APP_PROFILE_DEV)
     }
 
-    /// Reports whether the [`active`](AppProfile::active) profile is the
-    /// [**test**](AppProfile::Test) profile.
+    /// Reports whether any of the [active](AppProfile::active_all) profiles
+    /// is or inherits from the [**test**](AppProfile::Test) profile.
     pub fn active_is_test() -> bool {
-        Self::active().is_test()
+        Self::active_is(APP_PROFILE_TEST)
     }
 
-    /// Reports whether the [`active`](AppProfile::active) profile is the
-    /// given profile name.
+    /// Reports whether any of the [active](AppProfile::active_all) profiles
+    /// [is or inherits](AppProfile::is_or_inherits) from the given profile
+    /// name.
     pub fn active_is(given: impl AsRef<str>) -> bool {
-        Self::active().is(given)
+        let given = given.as_ref();
+
+        Self::active_all()
+            .iter()
+            .any(|profile| profile.is_or_inherits(given))
     }
 }
 
@@ -232,10 +443,45 @@ impl AppProfile {
 
     /// Reports whether this [`AppProfile`] matches the given profile name.
     ///
-    /// Before comparing, forces the given name into the same restrictions that
-    /// are [applied](Name::new) to a profile name normally.
+    /// The given name is trimmed and compared case-insensitively, the same
+    /// way it would be [normalized](AppProfile::try_new) into a profile name.
     pub fn is(&self, given: impl AsRef<str>) -> bool {
-        self.as_str() == Name::new(given).as_str()
+        self.as_str().eq_ignore_ascii_case(given.as_ref().trim())
+    }
+
+    /// Reports whether this [`AppProfile`] matches the given profile name, or
+    /// inherits (directly or transitively) from a profile that does.
+    pub fn is_or_inherits(&self, given: impl AsRef<str>) -> bool {
+        let given = given.as_ref();
+
+        self.ancestry().iter().any(|profile| profile.is(given))
+    }
+
+    /// Returns the profile that this [`AppProfile`]
+    /// [inherits](AppProfile::declare_parent) from, if any.
+    pub fn parent(&self) -> Option<AppProfile> {
+        APP_PROFILE_PARENTS
+            .read()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+            .iter()
+            .find(|(child, _)| child == self)
+            .map(|(_, parent)| parent.clone())
+    }
+
+    /// Returns this [`AppProfile`] followed by its [parent](AppProfile::parent),
+    /// the parent of its parent, and so on. Cyclic inheritance is cut short at
+    /// the first repeated profile.
+    pub fn ancestry(&self) -> Vec<AppProfile> {
+        let mut ancestry = vec![self.clone()];
+
+        while let Some(parent) = ancestry.last().and_then(AppProfile::parent) {
+            if ancestry.contains(&parent) {
+                break;
+            }
+            ancestry.push(parent);
+        }
+
+        ancestry
     }
 
     /// Exposes a view on this [`AppProfile`] as a string slice.
@@ -1,80 +1,84 @@
-use std::any::type_name;
+use std::error::Error;
 use std::fmt::{Debug, Display, Formatter};
 use std::ops::Deref;
+use std::sync::Arc;
 
-/// The maximum number of bytes (ASCII characters) allowed for a
-/// [custom](crate::AppProfile::Custom) [`AppProfile`](crate::AppProfile) name.
-///
-/// This is limited quite severely to minimize how much space something as simple
-/// as an application profile takes. Additionally, keeping the profile string at
-/// 7 bytes allows for a nice layout of the underlying [`Name`] struct.
-pub const NAME_MAX_LEN: usize = 7;
-
-/// A compact, stack-allocated ASCII string to represent a custom
+/// A cheaply cloneable, validated string to represent a custom
 /// [`AppProfile`](crate::AppProfile) name.
 ///
-/// This type is designed for strings that are:
+/// A valid name is:
 ///
-/// - ASCII alphanumeric (other characters are ignored).
-/// - No longer than [`NAME_MAX_LEN`] characters (extra characters are truncated).
-/// - Lowercase (uppercase characters are converted).
-#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+/// - Non-empty, of any length.
+/// - Made of ASCII alphanumeric characters, dashes (`-`), and underscores
+///   (`_`).
+///
+/// The name is trimmed and forced to lowercase, but is otherwise kept intact:
+/// names like `staging-eu` and `stagingeu` remain distinct profiles. Names that
+/// contain any other characters are [rejected](ProfileNameError) rather than
+/// silently altered, so that two different names never end up as the same
+/// profile.
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct Name {
-    buf: [u8; NAME_MAX_LEN],
-    len: u8,
+    inner: Arc<str>,
+}
+
+/// Represents the reasons why a string cannot be used as a
+/// [custom](crate::AppProfile::Custom) [`AppProfile`](crate::AppProfile) name.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ProfileNameError {
+    /// The name is empty (or consists only of whitespace).
+    Empty,
+
+    /// The name contains a character that is not allowed.
+    InvalidCharacter {
+        /// The name, as given.
+        name: String,
+
+        /// The first character that is not allowed.
+        character: char,
+    },
 }
 
 impl Name {
-    /// Creates a new [`Name`] from an ASCII string slice. Retains only ASCII
-    /// alphanumeric characters from the input. Truncates the remaining input to
-    /// [`NAME_MAX_LEN`] bytes to ensure it fits within the fixed-size buffer.
-    /// Every taken character is forced to lowercase. Performs no heap
-    /// allocations.
-    pub(crate) fn new(input: impl AsRef<str>) -> Self {
-        // Initialize storage variables
-        let mut buf = [0u8; NAME_MAX_LEN];
-        let mut len = 0usize;
-
-        // Take one input ASCII character at a time while staying within length limit
-        for mut b in input
-            .as_ref()
-            .bytes()
-            .filter(u8::is_ascii_alphanumeric)
-            .take(NAME_MAX_LEN)
-        {
-            b.make_ascii_lowercase(); // force input to lowercase
-            buf[len] = b;
-            len += 1;
+    /// Creates a new [`Name`] from the given string slice, after trimming it
+    /// and forcing it to lowercase.
+    ///
+    /// Returns an error if the input is empty, or if it contains any characters
+    /// other than ASCII alphanumerics, dashes, and underscores.
+    pub(crate) fn try_new(input: impl AsRef<str>) -> Result<Self, ProfileNameError> {
+        let input = input.as_ref().trim();
+
+        if input.is_empty() {
+            return Err(ProfileNameError::Empty);
         }
 
-        Name {
-            buf,
-            len: len as u8,
+        if let Some(character) = input.chars().find(|c| !is_allowed(*c)) {
+            return Err(ProfileNameError::InvalidCharacter {
+                name: input.to_string(),
+                character,
+            });
         }
+
+        Ok(Name {
+            inner: Arc::from(input.to_ascii_lowercase()),
+        })
     }
 
     /// Exposes a view into this [`Name`] as a string slice.
-    ///
-    /// Guaranteed to return a valid string reference, as the
-    /// [constructor](Name::new) enforces ASCII-only characters, and ASCII is a
-    /// valid sub-set of UTF-8.
-    ///
-    /// This method does not allocate or perform any decoding; it simply returns
-    /// a `&str` view into the internal buffer up to the stored length.
     pub fn as_str(&self) -> &str {
-        std::str::from_utf8(&self.buf[..self.len as usize]).expect(concat!(
-            "it should be possible to view the internal buffer as a &str because",
-            " the constructor of this struct always interprets the input string",
-            " as a sequence of valid UTF-8 characters",
-        ))
+        &self.inner
     }
 }
 
+/// Reports whether the given character is allowed in a [`Name`].
+fn is_allowed(c: char) -> bool {
+    c.is_ascii_alphanumeric() || c == '-' || c == '_'
+}
+
 impl Debug for Name {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct(type_name::<Self>())
-            .field("buf", &self.as_str())
-            .finish()
+        Debug::fmt(self.as_str(), f)
     }
 }
 
@@ -97,3 +101,18 @@ impl Deref for Name {
         self.as_str()
     }
 }
+
+impl Display for ProfileNameError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Empty => f.write_str("the profile name is empty"),
+            Self::InvalidCharacter { name, character } => write!(
+                f,
+                "the profile name '{}' contains the character {:?}, while only ASCII letters, digits, '-', and '_' are allowed",
+                name, character,
+            ),
+        }
+    }
+}
+
+impl Error for ProfileNameError {}
@@ -4,6 +4,10 @@ use std::sync::OnceLock;
 mod index;
 pub mod lifetime_id;
 
+/// The statically stored [index](AppReplica::index) of this application’s
+/// replica.
+static INDEX: OnceLock<Option<usize>> = OnceLock::new();
+
 /// Exposes the ways for the application to introspect its own runtime
 /// replica, primarily via a [numerical index](AppReplica::index) (injected via
 /// the environment at runtime).
@@ -22,11 +26,21 @@ impl AppReplica {
     /// valid `usize` integer, this method will return [`None`]. Otherwise, no
     /// validation is performed on the value.
     pub fn index() -> Option<usize> {
-        static INDEX: OnceLock<Option<usize>> = OnceLock::new();
-
         INDEX.get_or_init(index::discern).clone()
     }
 
+    /// Explicitly sets the index of this application’s replica, bypassing the
+    /// `APP_REPLICA_INDEX` environment variable. This is useful when the index
+    /// is chosen by other means than the environment, e.g., by a command-line
+    /// argument.
+    ///
+    /// The index can only be set once, before it is
+    /// [accessed](AppReplica::index) for the first time. Otherwise, the given
+    /// index is returned back as an error.
+    pub fn set_index(index: usize) -> Result<(), usize> {
+        INDEX.set(Some(index)).map_err(|_| index)
+    }
+
     /// Returns a pseudo-randomized [`LifetimeId`] of this application’s replica
     /// that is stable throughout a single runtime. Lazily generates the value
     /// on the first call.
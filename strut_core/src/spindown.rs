@@ -1,10 +1,10 @@
-use self::registry::SpindownRegistry;
+use self::registry::{SpindownRegistry, SpindownTimeout};
 use crate::AppSpindownToken;
 use parking_lot::Mutex;
 use std::sync::OnceLock;
 use std::time::Duration;
 
-mod registry;
+pub mod registry;
 pub mod token;
 
 // Global singleton spindown registry
@@ -106,6 +106,20 @@ impl AppSpindown {
         let _ = registry.spun_down().await;
     }
 
+    /// Same as [`completed`](Self::completed), but reports the outcome: the
+    /// count of workloads that were spun down, or a [`SpindownTimeout`] if some
+    /// of them did not complete in time.
+    ///
+    /// This is useful where a lingering workload should fail loudly, e.g., in
+    /// tests.
+    pub async fn try_completed() -> Result<usize, SpindownTimeout> {
+        // Retrieve global registry
+        let registry = Self::global_registry();
+
+        // Repeatedly await all workloads
+        registry.spun_down().await
+    }
+
     /// Retrieves the global (singleton) [`SpindownRegistry`], lazily
     /// initialized.
     fn global_registry() -> &'static SpindownRegistry {
@@ -1,6 +1,6 @@
 use crate::AppSpindownToken;
-use futures::stream::FuturesUnordered;
 use futures::StreamExt;
+use futures::stream::FuturesUnordered;
 use parking_lot::Mutex;
 use scopeguard::defer;
 use std::error::Error;
@@ -197,17 +197,34 @@ enum SpindownState {
     TimedOut,
 }
 
-/// Little marker for spindown timeout.
+/// Reports that some workloads registered with
+/// [`AppSpindown`](crate::AppSpindown) did not signal completion within the
+/// spindown timeout.
 #[derive(Debug, Clone, PartialEq, Eq)]
-#[allow(dead_code)]
-pub(crate) struct SpindownTimeout {
+pub struct SpindownTimeout {
     spun_down: usize,
     timed_out: usize,
 }
 
+impl SpindownTimeout {
+    /// Reports the count of workloads that completed in time.
+    pub fn spun_down(&self) -> usize {
+        self.spun_down
+    }
+
+    /// Reports the count of workloads that did not complete in time.
+    pub fn timed_out(&self) -> usize {
+        self.timed_out
+    }
+}
+
 impl Display for SpindownTimeout {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        f.write_str("failed to fully spin down all workloads within the timeout: {} completed, {} timed out")
+        write!(
+            f,
+            "failed to fully spin down all workloads within the timeout: {} completed, {} timed out",
+            self.spun_down, self.timed_out,
+        )
     }
 }
 
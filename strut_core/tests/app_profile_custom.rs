@@ -9,6 +9,6 @@ mod tests {
         unsafe { std::env::set_var("APP_PROFILE", "CUSTOM_PROFILE") }
 
         // Then
-        assert_eq!(AppProfile::active().as_str(), "customp");
+        assert_eq!(AppProfile::active().as_str(), "custom_profile");
     }
 }
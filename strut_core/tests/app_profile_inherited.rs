@@ -0,0 +1,40 @@
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use strut_core::AppProfile;
+
+    #[test]
+    fn inherited() {
+        // Given
+        unsafe { std::env::set_var("APP_PROFILE", "canary:staging,eu") }
+        AppProfile::declare_parent("staging", "prod");
+        AppProfile::declare_parent("prod", "canary");
+
+        // When
+        let layers = AppProfile::active_layers();
+
+        // Then
+        assert_eq!(AppProfile::active().as_str(), "canary");
+        assert_eq!(
+            layers,
+            [
+                AppProfile::Prod,
+                AppProfile::new("staging"),
+                AppProfile::new("canary"),
+                AppProfile::new("eu"),
+            ],
+        );
+        assert!(AppProfile::active_is_prod());
+        assert!(AppProfile::active_is("staging"));
+        assert!(AppProfile::new("canary").is_or_inherits("prod"));
+        assert!(!AppProfile::new("eu").is_or_inherits("prod"));
+        assert_eq!(
+            AppProfile::Prod.ancestry(),
+            [
+                AppProfile::Prod,
+                AppProfile::new("canary"),
+                AppProfile::new("staging"),
+            ],
+        );
+    }
+}
@@ -0,0 +1,22 @@
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use strut_core::AppProfile;
+
+    #[test]
+    fn multiple() {
+        // When
+        unsafe { std::env::set_var("APP_PROFILE", " prod , eu,,prod ") }
+
+        // Then
+        assert!(matches!(AppProfile::active(), AppProfile::Prod));
+        assert_eq!(
+            AppProfile::active_all(),
+            [AppProfile::Prod, AppProfile::new("eu")],
+        );
+        assert!(AppProfile::active_is_prod());
+        assert!(AppProfile::active_is("eu"));
+        assert!(!AppProfile::active_is("us"));
+        assert!(!AppProfile::active_is_dev());
+    }
+}
@@ -0,0 +1,74 @@
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use strut_core::{AppProfile, ProfileNameError};
+
+    #[test]
+    fn valid() {
+        // When
+        let names = [
+            "performance",
+            " Staging-EU ",
+            "stagingeu",
+            "blue_green_2",
+            "PROD",
+        ]
+        .map(|name| AppProfile::try_new(name).unwrap());
+
+        // Then
+        assert_eq!(
+            names.iter().map(AppProfile::as_str).collect::<Vec<_>>(),
+            [
+                "performance",
+                "staging-eu",
+                "stagingeu",
+                "blue_green_2",
+                "prod"
+            ],
+        );
+        assert_eq!(names[4], AppProfile::Prod);
+        assert_ne!(names[1], names[2]);
+        assert!(names[1].is("STAGING-eu"));
+        assert!(!names[1].is("stagingeu"));
+    }
+
+    #[test]
+    fn invalid() {
+        // When
+        let errors = ["", "   ", "staging.eu", "staging eu", "prød"]
+            .map(|name| AppProfile::try_new(name).unwrap_err());
+
+        // Then
+        assert_eq!(
+            errors,
+            [
+                ProfileNameError::Empty,
+                ProfileNameError::Empty,
+                ProfileNameError::InvalidCharacter {
+                    name: "staging.eu".to_string(),
+                    character: '.',
+                },
+                ProfileNameError::InvalidCharacter {
+                    name: "staging eu".to_string(),
+                    character: ' ',
+                },
+                ProfileNameError::InvalidCharacter {
+                    name: "prød".to_string(),
+                    character: 'ø',
+                },
+            ],
+        );
+        assert_eq!(
+            errors[2].to_string(),
+            "the profile name 'staging.eu' contains the character '.', while only ASCII letters, digits, '-', and '_' are allowed",
+        );
+        assert!(AppProfile::validate_spec("canary:prod, eu").is_ok());
+        assert_eq!(
+            AppProfile::validate_spec("canary:,eu west"),
+            Err(ProfileNameError::InvalidCharacter {
+                name: "eu west".to_string(),
+                character: ' ',
+            }),
+        );
+    }
+}
@@ -1,6 +1,6 @@
 use pretty_assertions::assert_eq;
-use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
 use strut_core::AppContext;
 
 /// Helper struct for testing [`AppContext`].
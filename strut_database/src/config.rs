@@ -3,6 +3,7 @@ use serde::{Deserialize, Deserializer};
 use serde_value::Value;
 use std::collections::BTreeMap;
 use std::fmt::Formatter;
+use strut_deserialize::{ConfigSchema, Schema};
 use strut_factory::impl_deserialize_field;
 
 /// Represents the application-level configuration section that covers everything
@@ -294,6 +295,94 @@ const _: () = {
         postgres_handles | postgres | pg | postgre_sql | postgresql,
         sqlite_handles | sqlite,
     );
+
+    impl ConfigSchema for DatabaseConfig {
+        fn config_schema() -> Schema {
+            // The default handle may also be given inline, among the other keys,
+            // or as a string URL in place of the whole section
+            let (map, url) = match default_handle_schema() {
+                Some((handle, handle_map)) => (
+                    handle_map.with_field(DatabaseConfigField::default_handle.keys(), handle),
+                    Some(String::config_schema()),
+                ),
+                None => (Schema::object(), None),
+            };
+
+            #[cfg(feature = "mysql")]
+            let map = map.with_field(
+                DatabaseConfigField::mysql_handles.keys(),
+                crate::MySqlHandleCollection::config_schema(),
+            );
+
+            #[cfg(feature = "postgres")]
+            let map = map.with_field(
+                DatabaseConfigField::postgres_handles.keys(),
+                crate::PostgresHandleCollection::config_schema(),
+            );
+
+            #[cfg(feature = "sqlite")]
+            let map = map.with_field(
+                DatabaseConfigField::sqlite_handles.keys(),
+                crate::SqliteHandleCollection::config_schema(),
+            );
+
+            Schema::any_of(url.into_iter().chain([map]))
+                .with_description("The database configuration.")
+        }
+    }
+
+    /// Returns the [`Schema`] of the default [`MySqlHandle`](crate::MySqlHandle)
+    /// along with the [`Schema`] of its map representation.
+    #[cfg(any(
+        feature = "default-mysql",
+        all(feature = "mysql", not(feature = "postgres"), not(feature = "sqlite")),
+    ))]
+    fn default_handle_schema() -> Option<(Schema, Schema)> {
+        Some((
+            crate::MySqlHandle::config_schema(),
+            crate::repr::handle::mysql::map_schema(),
+        ))
+    }
+
+    /// Returns the [`Schema`] of the default
+    /// [`PostgresHandle`](crate::PostgresHandle) along with the [`Schema`] of
+    /// its map representation.
+    #[cfg(any(
+        feature = "default-postgres",
+        all(feature = "postgres", not(feature = "mysql"), not(feature = "sqlite")),
+    ))]
+    fn default_handle_schema() -> Option<(Schema, Schema)> {
+        Some((
+            crate::PostgresHandle::config_schema(),
+            crate::repr::handle::postgres::map_schema(),
+        ))
+    }
+
+    /// Returns the [`Schema`] of the default [`SqliteHandle`](crate::SqliteHandle)
+    /// along with the [`Schema`] of its map representation.
+    #[cfg(any(
+        feature = "default-sqlite",
+        all(feature = "sqlite", not(feature = "mysql"), not(feature = "postgres")),
+    ))]
+    fn default_handle_schema() -> Option<(Schema, Schema)> {
+        Some((
+            crate::SqliteHandle::config_schema(),
+            crate::repr::handle::sqlite::map_schema(),
+        ))
+    }
+
+    /// Returns nothing, as the default handle is not enabled.
+    #[cfg(not(any(
+        feature = "default-mysql",
+        feature = "default-postgres",
+        feature = "default-sqlite",
+        all(feature = "mysql", not(feature = "postgres"), not(feature = "sqlite")),
+        all(feature = "postgres", not(feature = "mysql"), not(feature = "sqlite")),
+        all(feature = "sqlite", not(feature = "mysql"), not(feature = "postgres")),
+    )))]
+    fn default_handle_schema() -> Option<(Schema, Schema)> {
+        None
+    }
 };
 
 #[cfg(test)]
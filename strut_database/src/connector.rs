@@ -1,8 +1,8 @@
 use crate::repr::handle::Handle;
 use sqlx_core::database::Database;
 use sqlx_core::pool::Pool;
-use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
 use strut_core::{AppContext, AppSpindown, AppSpindownToken};
 use tracing::info;
 
@@ -51,7 +51,6 @@ pub use strut_sync::Gate;
 /// Re-exports the public API of `sqlx` for convenience.
 pub use sqlx;
 
-
 /// Re-exports the `strut_shutdown` function to facilitate stand-alone usage of
 /// this crate.
 ///
@@ -2,8 +2,8 @@ use sqlx::Error as SqlxError;
 use sqlx_core::database::Database;
 use sqlx_core::migrate::{Migrate, MigrateError, Migrator};
 use sqlx_core::pool::Pool;
-use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
 use strut_core::{AppContext, AppSpindown, AppSpindownToken};
 use strut_sync::{Gate, Latch};
 use strut_util::Backoff;
@@ -3,6 +3,7 @@ use serde::{Deserialize, Deserializer};
 use sqlx_core::net::tls::CertificateInput;
 use std::fmt::Formatter;
 use std::path::PathBuf;
+use strut_deserialize::{ConfigSchema, Schema};
 
 /// Closely replicates the `sqlx` crate’s [`CertificateInput`] enum, providing
 /// the [deserialization](serde::de::Deserialize) capability via the original’s
@@ -43,6 +44,12 @@ const _: () = {
     }
 };
 
+impl ConfigSchema for ProxyCertificateInput {
+    fn config_schema() -> Schema {
+        Schema::string().with_description("An inline PEM-encoded certificate, or a path to a file.")
+    }
+}
+
 /// Deserialize implementation.
 const _: () = {
     impl<'de> Deserialize<'de> for ProxyCertificateInput {
@@ -1,12 +1,12 @@
 use crate::repr::cert::ProxyCertificateInput;
-use crate::repr::handle::mysql::ssl::ProxyMySqlSslMode;
 use crate::repr::handle::Handle;
+use crate::repr::handle::mysql::ssl::ProxyMySqlSslMode;
 use crate::repr::log::ProxyLogSettings;
 use crate::repr::pool::ProxyPoolOptions;
 use serde::de::{DeserializeSeed, Error, MapAccess, Visitor};
 use serde::{Deserialize, Deserializer};
-use sqlx::mysql::{MySqlConnectOptions, MySqlSslMode};
 use sqlx::MySql;
+use sqlx::mysql::{MySqlConnectOptions, MySqlSslMode};
 use sqlx_core::connection::{ConnectOptions, Connection};
 use sqlx_core::database::Database;
 use sqlx_core::pool::PoolOptions;
@@ -15,7 +15,7 @@ use std::fmt::{Debug, Formatter};
 use std::path::PathBuf;
 use std::str::FromStr;
 use std::sync::Arc;
-use strut_deserialize::{Slug, SlugMap};
+use strut_deserialize::{ConfigSchema, Schema, Slug, SlugMap};
 use strut_factory::impl_deserialize_field;
 
 pub mod ssl;
@@ -576,6 +576,92 @@ impl_deserialize_field!(
     pool_options | pool,
 );
 
+impl ConfigSchema for MySqlHandleCollection {
+    fn config_schema() -> Schema {
+        Schema::map(MySqlHandle::config_schema())
+            .with_description("A map of named MySQL connection handles.")
+    }
+}
+
+impl ConfigSchema for MySqlHandle {
+    fn config_schema() -> Schema {
+        Schema::any_of([String::config_schema(), map_schema()])
+            .with_description("A MySQL connection handle, or just the MySQL URL.")
+    }
+}
+
+/// Returns the [`Schema`] of the map representation of a [`MySqlHandle`].
+pub(crate) fn map_schema() -> Schema {
+    Schema::object()
+        .with_field(MySqlHandleField::name.keys(), String::config_schema())
+        .with_field(MySqlHandleField::url.keys(), String::config_schema())
+        .with_field(MySqlHandleField::host.keys(), String::config_schema())
+        .with_field(MySqlHandleField::port.keys(), u16::config_schema())
+        .with_field(
+            MySqlHandleField::socket.keys(),
+            Schema::nullable(PathBuf::config_schema()),
+        )
+        .with_field(MySqlHandleField::username.keys(), String::config_schema())
+        .with_field(
+            MySqlHandleField::password.keys(),
+            Schema::nullable(String::config_schema()),
+        )
+        .with_field(
+            MySqlHandleField::database.keys(),
+            Schema::nullable(String::config_schema()),
+        )
+        .with_field(
+            MySqlHandleField::ssl_mode.keys(),
+            ProxyMySqlSslMode::config_schema(),
+        )
+        .with_field(
+            MySqlHandleField::ssl_ca.keys(),
+            Schema::nullable(ProxyCertificateInput::config_schema()),
+        )
+        .with_field(
+            MySqlHandleField::ssl_client_cert.keys(),
+            Schema::nullable(ProxyCertificateInput::config_schema()),
+        )
+        .with_field(
+            MySqlHandleField::ssl_client_key.keys(),
+            Schema::nullable(ProxyCertificateInput::config_schema()),
+        )
+        .with_field(
+            MySqlHandleField::statement_cache_capacity.keys(),
+            usize::config_schema(),
+        )
+        .with_field(MySqlHandleField::charset.keys(), String::config_schema())
+        .with_field(
+            MySqlHandleField::collation.keys(),
+            Schema::nullable(String::config_schema()),
+        )
+        .with_field(
+            MySqlHandleField::log_settings.keys(),
+            ProxyLogSettings::config_schema(),
+        )
+        .with_field(
+            MySqlHandleField::pipes_as_concat.keys(),
+            bool::config_schema(),
+        )
+        .with_field(
+            MySqlHandleField::enable_cleartext_plugin.keys(),
+            bool::config_schema(),
+        )
+        .with_field(
+            MySqlHandleField::no_engine_substitution.keys(),
+            bool::config_schema(),
+        )
+        .with_field(
+            MySqlHandleField::timezone.keys(),
+            Schema::nullable(String::config_schema()),
+        )
+        .with_field(MySqlHandleField::set_names.keys(), bool::config_schema())
+        .with_field(
+            MySqlHandleField::pool_options.keys(),
+            ProxyPoolOptions::<MySql>::config_schema(),
+        )
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
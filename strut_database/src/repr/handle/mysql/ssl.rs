@@ -1,9 +1,9 @@
 use sqlx::mysql::MySqlSslMode;
-use strut_factory::Deserialize as StrutDeserialize;
+use strut_factory::{ConfigSchema, Deserialize as StrutDeserialize};
 
 /// Closely replicates the `sqlx` crate’s [`MySqlSslMode`] enum, providing the
 /// [deserialization](serde::de::Deserialize) capability.
-#[derive(Debug, StrutDeserialize)]
+#[derive(Debug, StrutDeserialize, ConfigSchema)]
 #[strut(eq_fn = strut_deserialize::Slug::eq_as_slugs)]
 pub(crate) enum ProxyMySqlSslMode {
     /// The equivalent of [`MySqlSslMode::Disabled`].
@@ -1,12 +1,12 @@
 use crate::repr::cert::ProxyCertificateInput;
-use crate::repr::handle::postgres::ssl::ProxyPgSslMode;
 use crate::repr::handle::Handle;
+use crate::repr::handle::postgres::ssl::ProxyPgSslMode;
 use crate::repr::log::ProxyLogSettings;
 use crate::repr::pool::ProxyPoolOptions;
 use serde::de::{DeserializeSeed, Error, MapAccess, Visitor};
 use serde::{Deserialize, Deserializer};
-use sqlx::postgres::{PgConnectOptions, PgSslMode};
 use sqlx::Postgres;
+use sqlx::postgres::{PgConnectOptions, PgSslMode};
 use sqlx_core::connection::{ConnectOptions, Connection};
 use sqlx_core::database::Database;
 use sqlx_core::pool::PoolOptions;
@@ -15,7 +15,7 @@ use std::fmt::{Debug, Formatter};
 use std::path::PathBuf;
 use std::str::FromStr;
 use std::sync::Arc;
-use strut_deserialize::{Slug, SlugMap};
+use strut_deserialize::{ConfigSchema, Schema, Slug, SlugMap};
 use strut_factory::impl_deserialize_field;
 
 pub mod ssl;
@@ -550,6 +550,85 @@ impl_deserialize_field!(
     pool_options | pool,
 );
 
+impl ConfigSchema for PostgresHandleCollection {
+    fn config_schema() -> Schema {
+        Schema::map(PostgresHandle::config_schema())
+            .with_description("A map of named PostgreSQL connection handles.")
+    }
+}
+
+impl ConfigSchema for PostgresHandle {
+    fn config_schema() -> Schema {
+        Schema::any_of([String::config_schema(), map_schema()])
+            .with_description("A PostgreSQL connection handle, or just the PostgreSQL URL.")
+    }
+}
+
+/// Returns the [`Schema`] of the map representation of a [`PostgresHandle`].
+pub(crate) fn map_schema() -> Schema {
+    Schema::object()
+        .with_field(PostgresHandleField::name.keys(), String::config_schema())
+        .with_field(PostgresHandleField::url.keys(), String::config_schema())
+        .with_field(PostgresHandleField::host.keys(), String::config_schema())
+        .with_field(PostgresHandleField::port.keys(), u16::config_schema())
+        .with_field(
+            PostgresHandleField::socket.keys(),
+            Schema::nullable(PathBuf::config_schema()),
+        )
+        .with_field(
+            PostgresHandleField::username.keys(),
+            String::config_schema(),
+        )
+        .with_field(
+            PostgresHandleField::password.keys(),
+            Schema::nullable(String::config_schema()),
+        )
+        .with_field(
+            PostgresHandleField::database.keys(),
+            Schema::nullable(String::config_schema()),
+        )
+        .with_field(
+            PostgresHandleField::ssl_mode.keys(),
+            ProxyPgSslMode::config_schema(),
+        )
+        .with_field(
+            PostgresHandleField::ssl_root_cert.keys(),
+            Schema::nullable(ProxyCertificateInput::config_schema()),
+        )
+        .with_field(
+            PostgresHandleField::ssl_client_cert.keys(),
+            Schema::nullable(ProxyCertificateInput::config_schema()),
+        )
+        .with_field(
+            PostgresHandleField::ssl_client_key.keys(),
+            Schema::nullable(ProxyCertificateInput::config_schema()),
+        )
+        .with_field(
+            PostgresHandleField::statement_cache_capacity.keys(),
+            usize::config_schema(),
+        )
+        .with_field(
+            PostgresHandleField::application_name.keys(),
+            Schema::nullable(String::config_schema()),
+        )
+        .with_field(
+            PostgresHandleField::log_settings.keys(),
+            ProxyLogSettings::config_schema(),
+        )
+        .with_field(
+            PostgresHandleField::extra_float_digits.keys(),
+            Schema::nullable(i8::config_schema()),
+        )
+        .with_field(
+            PostgresHandleField::options.keys(),
+            Schema::nullable(BTreeMap::<String, String>::config_schema()),
+        )
+        .with_field(
+            PostgresHandleField::pool_options.keys(),
+            ProxyPoolOptions::<Postgres>::config_schema(),
+        )
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
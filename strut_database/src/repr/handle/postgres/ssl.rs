@@ -1,9 +1,9 @@
 use sqlx::postgres::PgSslMode;
-use strut_factory::Deserialize as StrutDeserialize;
+use strut_factory::{ConfigSchema, Deserialize as StrutDeserialize};
 
 /// Closely replicates the `sqlx` crate’s [`PgSslMode`] enum, providing the
 /// [deserialization](serde::de::Deserialize) capability.
-#[derive(Debug, StrutDeserialize)]
+#[derive(Debug, StrutDeserialize, ConfigSchema)]
 #[strut(eq_fn = strut_deserialize::Slug::eq_as_slugs)]
 pub(crate) enum ProxyPgSslMode {
     /// The equivalent of [`PgSslMode::Disable`].
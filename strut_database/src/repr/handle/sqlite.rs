@@ -1,12 +1,12 @@
-use crate::repr::handle::sqlite::optimize::ProxyOptimizeOnClose;
 use crate::repr::handle::Handle;
+use crate::repr::handle::sqlite::optimize::ProxyOptimizeOnClose;
 use crate::repr::log::ProxyLogSettings;
 use crate::repr::pool::ProxyPoolOptions;
 use humantime::parse_duration;
 use serde::de::{DeserializeSeed, Error, IgnoredAny, MapAccess, Visitor};
 use serde::{Deserialize, Deserializer};
-use sqlx::sqlite::SqliteConnectOptions;
 use sqlx::Sqlite;
+use sqlx::sqlite::SqliteConnectOptions;
 use sqlx_core::connection::{ConnectOptions, Connection};
 use sqlx_core::database::Database;
 use sqlx_core::pool::PoolOptions;
@@ -15,7 +15,8 @@ use std::fmt::{Debug, Formatter};
 use std::path::PathBuf;
 use std::str::FromStr;
 use std::sync::Arc;
-use strut_deserialize::{Slug, SlugMap};
+use std::time::Duration;
+use strut_deserialize::{ConfigSchema, Schema, Slug, SlugMap};
 use strut_factory::impl_deserialize_field;
 
 pub mod optimize;
@@ -541,6 +542,84 @@ impl_deserialize_field!(
     pool_options | pool,
 );
 
+impl ConfigSchema for SqliteHandleCollection {
+    fn config_schema() -> Schema {
+        Schema::map(SqliteHandle::config_schema())
+            .with_description("A map of named SQLite connection handles.")
+    }
+}
+
+impl ConfigSchema for SqliteHandle {
+    fn config_schema() -> Schema {
+        Schema::any_of([String::config_schema(), map_schema()])
+            .with_description("A SQLite connection handle, or just the SQLite URL.")
+    }
+}
+
+/// Returns the [`Schema`] of the map representation of a [`SqliteHandle`].
+pub(crate) fn map_schema() -> Schema {
+    Schema::object()
+        .with_field(SqliteHandleField::url.keys(), String::config_schema())
+        .with_field(SqliteHandleField::name.keys(), String::config_schema())
+        .with_field(SqliteHandleField::filename.keys(), PathBuf::config_schema())
+        .with_field(SqliteHandleField::in_memory.keys(), bool::config_schema())
+        .with_field(SqliteHandleField::read_only.keys(), bool::config_schema())
+        .with_field(
+            SqliteHandleField::create_if_missing.keys(),
+            bool::config_schema(),
+        )
+        .with_field(
+            SqliteHandleField::shared_cache.keys(),
+            bool::config_schema(),
+        )
+        .with_field(
+            SqliteHandleField::statement_cache_capacity.keys(),
+            usize::config_schema(),
+        )
+        .with_field(
+            SqliteHandleField::busy_timeout.keys(),
+            Duration::config_schema(),
+        )
+        .with_field(
+            SqliteHandleField::log_settings.keys(),
+            ProxyLogSettings::config_schema(),
+        )
+        .with_field(SqliteHandleField::immutable.keys(), bool::config_schema())
+        .with_field(
+            SqliteHandleField::vfs.keys(),
+            Schema::nullable(String::config_schema()),
+        )
+        .with_field(
+            SqliteHandleField::pragmas.keys(),
+            BTreeMap::<String, String>::config_schema(),
+        )
+        .with_field(
+            SqliteHandleField::extensions.keys(),
+            Schema::map(Schema::nullable(String::config_schema())),
+        )
+        .with_field(
+            SqliteHandleField::command_channel_size.keys(),
+            usize::config_schema(),
+        )
+        .with_field(
+            SqliteHandleField::row_channel_size.keys(),
+            usize::config_schema(),
+        )
+        .with_field(SqliteHandleField::serialized.keys(), bool::config_schema())
+        .with_field(
+            SqliteHandleField::thread_name_prefix.keys(),
+            String::config_schema(),
+        )
+        .with_field(
+            SqliteHandleField::optimize_on_close.keys(),
+            ProxyOptimizeOnClose::config_schema(),
+        )
+        .with_field(
+            SqliteHandleField::pool_options.keys(),
+            ProxyPoolOptions::<Sqlite>::config_schema(),
+        )
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1,6 +1,7 @@
 use serde::de::{Error, MapAccess, Visitor};
 use serde::{Deserialize, Deserializer};
 use std::fmt::Formatter;
+use strut_deserialize::{ConfigSchema, Schema};
 use strut_factory::impl_deserialize_field;
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -124,6 +125,22 @@ const _: () = {
         enabled | is_enabled,
         analysis_limit | limit,
     );
+
+    impl ConfigSchema for ProxyOptimizeOnClose {
+        fn config_schema() -> Schema {
+            let map = Schema::object()
+                .with_field(
+                    ProxyOptimizeOnCloseField::enabled.keys(),
+                    bool::config_schema(),
+                )
+                .with_field(
+                    ProxyOptimizeOnCloseField::analysis_limit.keys(),
+                    Schema::nullable(u32::config_schema()),
+                );
+
+            Schema::any_of([bool::config_schema(), u32::config_schema(), map])
+        }
+    }
 };
 
 #[cfg(test)]
@@ -5,7 +5,8 @@ use serde::{Deserialize, Deserializer};
 use sqlx_core::connection::LogSettings;
 use std::fmt::Formatter;
 use std::time::Duration;
-use strut_factory::{Deserialize as StrutDeserialize, impl_deserialize_field};
+use strut_deserialize::{ConfigSchema, Schema};
+use strut_factory::{ConfigSchema, Deserialize as StrutDeserialize, impl_deserialize_field};
 
 /// Closely replicates the `sqlx` crate’s [`LogSettings`] struct, providing
 /// the [deserialization](serde::de::Deserialize) capability.
@@ -18,7 +19,7 @@ pub(crate) struct ProxyLogSettings {
 
 /// Closely replicates the `log` crate’s [`LevelFilter`] enum, providing
 /// the more human-friendly [deserialization](Deserialize).
-#[derive(Debug, StrutDeserialize)]
+#[derive(Debug, StrutDeserialize, ConfigSchema)]
 pub(crate) enum ProxyLevelFilter {
     #[strut(alias = "no")]
     Off,
@@ -141,4 +142,23 @@ const _: () = {
         slow_statements_level,
         slow_statements_duration,
     );
+
+    impl ConfigSchema for ProxyLogSettings {
+        fn config_schema() -> Schema {
+            Schema::object()
+                .with_description("The statement logging settings.")
+                .with_field(
+                    ProxyLogSettingsField::statements_level.keys(),
+                    ProxyLevelFilter::config_schema(),
+                )
+                .with_field(
+                    ProxyLogSettingsField::slow_statements_level.keys(),
+                    ProxyLevelFilter::config_schema(),
+                )
+                .with_field(
+                    ProxyLogSettingsField::slow_statements_duration.keys(),
+                    Duration::config_schema(),
+                )
+        }
+    }
 };
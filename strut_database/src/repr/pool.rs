@@ -8,6 +8,7 @@ use sqlx_core::pool::PoolOptions;
 use std::fmt::Formatter;
 use std::marker::PhantomData;
 use std::time::Duration;
+use strut_deserialize::{ConfigSchema, Schema};
 use strut_factory::impl_deserialize_field;
 
 pub struct ProxyPoolOptions<DB>
@@ -200,4 +201,50 @@ const _: () = {
         max_lifetime,
         idle_timeout,
     );
+
+    impl<DB> ConfigSchema for ProxyPoolOptions<DB>
+    where
+        DB: Database,
+    {
+        fn config_schema() -> Schema {
+            Schema::object()
+                .with_description("The connection pool options.")
+                .with_field(
+                    ProxyPoolOptionsField::min_connections.keys(),
+                    u32::config_schema(),
+                )
+                .with_field(
+                    ProxyPoolOptionsField::max_connections.keys(),
+                    u32::config_schema(),
+                )
+                .with_field(
+                    ProxyPoolOptionsField::test_before_acquire.keys(),
+                    bool::config_schema(),
+                )
+                .with_field(
+                    ProxyPoolOptionsField::acquire_time_level.keys(),
+                    ProxyLevelFilter::config_schema(),
+                )
+                .with_field(
+                    ProxyPoolOptionsField::acquire_slow_level.keys(),
+                    ProxyLevelFilter::config_schema(),
+                )
+                .with_field(
+                    ProxyPoolOptionsField::acquire_slow_threshold.keys(),
+                    Duration::config_schema(),
+                )
+                .with_field(
+                    ProxyPoolOptionsField::acquire_timeout.keys(),
+                    Duration::config_schema(),
+                )
+                .with_field(
+                    ProxyPoolOptionsField::max_lifetime.keys(),
+                    Schema::nullable(Duration::config_schema()),
+                )
+                .with_field(
+                    ProxyPoolOptionsField::idle_timeout.keys(),
+                    Schema::nullable(Duration::config_schema()),
+                )
+        }
+    }
 };
@@ -4,10 +4,14 @@
 
 /// Slug-related utilities
 mod slug;
-pub use self::slug::map::SlugMap;
 pub use self::slug::Slug;
+pub use self::slug::map::SlugMap;
 
 /// Helper enum for deserializing collections that can optionally be represented
 /// by a single member
 mod one_or_many;
 pub use self::one_or_many::OneOrMany;
+
+/// JSON Schema of configuration values
+mod schema;
+pub use self::schema::{ConfigSchema, JSON_SCHEMA_DIALECT, Schema};
@@ -0,0 +1,359 @@
+use crate::{OneOrMany, SlugMap};
+use serde::{Serialize, Serializer};
+use serde_json::{Map, Value, json};
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+use std::num::{NonZeroU8, NonZeroU16, NonZeroU32, NonZeroU64, NonZeroUsize};
+use std::path::PathBuf;
+use std::time::Duration;
+
+/// The dialect of JSON Schema produced by [`Schema::into_document`].
+pub const JSON_SCHEMA_DIALECT: &str = "https://json-schema.org/draft/2020-12/schema";
+
+/// The pattern of a human-readable duration (as accepted by the `humantime`
+/// crate), e.g., `"1m 30s"` or `"250ms"`.
+const DURATION_PATTERN: &str = r"^\s*([0-9]+\s*[a-zA-Zµ]+\s*)+$";
+
+/// A fragment of [JSON Schema](https://json-schema.org) that describes an
+/// acceptable value of a configuration section.
+///
+/// The Strut ecosystem implements [`Deserialize`](serde::Deserialize) manually
+/// for most configuration types, so a [`Schema`] cannot be derived from the
+/// types themselves. Instead, each type describes its own schema by
+/// implementing [`ConfigSchema`], composing the [`Schema`] from the same key
+/// aliases and choices that its deserialization recognizes.
+///
+/// Keys and choices are matched leniently during deserialization (e.g., as
+/// [slugs](crate::Slug)), while a [`Schema`] lists only their canonical
+/// spelling. This is intentional: editors use the schema to suggest and
+/// validate values, and the canonical spelling is what they should suggest.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Schema {
+    inner: Map<String, Value>,
+}
+
+/// Describes the [`Schema`] of a configuration value.
+pub trait ConfigSchema {
+    /// Returns the [`Schema`] of values of this type.
+    fn config_schema() -> Schema;
+}
+
+impl Schema {
+    /// Creates a [`Schema`] that accepts any value.
+    pub fn any() -> Self {
+        Self { inner: Map::new() }
+    }
+
+    /// Creates a [`Schema`] that accepts a boolean.
+    pub fn boolean() -> Self {
+        Self::of_type("boolean")
+    }
+
+    /// Creates a [`Schema`] that accepts an integer.
+    pub fn integer() -> Self {
+        Self::of_type("integer")
+    }
+
+    /// Creates a [`Schema`] that accepts a non-negative integer.
+    pub fn unsigned() -> Self {
+        Self::of_type("integer").with("minimum", 0)
+    }
+
+    /// Creates a [`Schema`] that accepts a positive integer.
+    pub fn positive() -> Self {
+        Self::of_type("integer").with("minimum", 1)
+    }
+
+    /// Creates a [`Schema`] that accepts any number.
+    pub fn number() -> Self {
+        Self::of_type("number")
+    }
+
+    /// Creates a [`Schema`] that accepts a string.
+    pub fn string() -> Self {
+        Self::of_type("string")
+    }
+
+    /// Creates a [`Schema`] that accepts a human-readable duration string,
+    /// e.g., `"1m 30s"`.
+    pub fn duration() -> Self {
+        Self::string()
+            .with("pattern", DURATION_PATTERN)
+            .with_description("A human-readable duration, e.g., \"1m 30s\" or \"250ms\".")
+    }
+
+    /// Creates a [`Schema`] that accepts one of the given string choices.
+    pub fn choice<I, S>(choices: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        let choices = choices
+            .into_iter()
+            .map(|choice| Value::String(choice.into()))
+            .collect::<Vec<_>>();
+
+        Self::string().with("enum", choices)
+    }
+
+    /// Creates a [`Schema`] that accepts a map with a fixed set of keys, which
+    /// may be added with [`with_field`](Schema::with_field). Unknown keys are
+    /// allowed.
+    pub fn object() -> Self {
+        Self::of_type("object").with("properties", Map::new())
+    }
+
+    /// Creates a [`Schema`] that accepts a map with arbitrary keys, where every
+    /// value matches the given [`Schema`].
+    pub fn map(values: Schema) -> Self {
+        Self::of_type("object").with("additionalProperties", values)
+    }
+
+    /// Creates a [`Schema`] that accepts a list, where every element matches
+    /// the given [`Schema`].
+    pub fn array(items: Schema) -> Self {
+        Self::of_type("array").with("items", items)
+    }
+
+    /// Creates a [`Schema`] that accepts a value matching any of the given
+    /// [`Schema`]s.
+    pub fn any_of(schemas: impl IntoIterator<Item = Schema>) -> Self {
+        let schemas = schemas.into_iter().map(Value::from).collect::<Vec<_>>();
+
+        Self::any().with("anyOf", schemas)
+    }
+
+    /// Creates a [`Schema`] that accepts either a value matching the given
+    /// [`Schema`] or a list of such values (see [`OneOrMany`]).
+    pub fn one_or_many(item: Schema) -> Self {
+        Self::any_of([item.clone(), Self::array(item)])
+    }
+
+    /// Creates a [`Schema`] that accepts either a value matching the given
+    /// [`Schema`] or `null`.
+    pub fn nullable(schema: Schema) -> Self {
+        Self::any_of([schema, Self::of_type("null")])
+    }
+
+    /// Creates a [`Schema`] that accepts a value of the given JSON type.
+    fn of_type(name: &str) -> Self {
+        Self::any().with("type", name)
+    }
+}
+
+impl Schema {
+    /// Adds a field to this object [`Schema`]. The first of the given keys is
+    /// the canonical name of the field, and the remaining keys are its aliases,
+    /// which are all accepted with the same [`Schema`].
+    ///
+    /// Calling this method on a [`Schema`] that is not an
+    /// [object](Schema::object) turns it into one.
+    pub fn with_field(mut self, keys: &[&str], schema: Schema) -> Self {
+        let properties = self
+            .inner
+            .entry("properties")
+            .or_insert_with(|| Value::Object(Map::new()));
+
+        if !properties.is_object() {
+            *properties = Value::Object(Map::new());
+        }
+
+        if let Value::Object(properties) = properties {
+            for key in keys {
+                properties.insert(key.to_string(), Value::from(schema.clone()));
+            }
+        }
+
+        self
+    }
+
+    /// Adds a human-readable description to this [`Schema`].
+    pub fn with_description(self, description: impl Into<String>) -> Self {
+        self.with("description", description.into())
+    }
+
+    /// Adds a title to this [`Schema`].
+    pub fn with_title(self, title: impl Into<String>) -> Self {
+        self.with("title", title.into())
+    }
+
+    /// Adds the given default value to this [`Schema`]. Values that cannot be
+    /// represented as JSON are skipped.
+    pub fn with_default(self, value: impl Serialize) -> Self {
+        match serde_json::to_value(value) {
+            Ok(value) => self.with("default", value),
+            Err(_) => self,
+        }
+    }
+
+    /// Sets the given keyword of this [`Schema`] to the given value.
+    fn with(mut self, keyword: &str, value: impl Into<Value>) -> Self {
+        self.inner.insert(keyword.to_string(), value.into());
+
+        self
+    }
+}
+
+impl Schema {
+    /// Exposes this [`Schema`] as a JSON object.
+    pub fn as_json(&self) -> &Map<String, Value> {
+        &self.inner
+    }
+
+    /// Turns this [`Schema`] into a standalone JSON Schema document, declaring
+    /// the [dialect](JSON_SCHEMA_DIALECT).
+    pub fn into_document(self) -> Value {
+        let mut document = Map::with_capacity(self.inner.len() + 1);
+        document.insert("$schema".to_string(), json!(JSON_SCHEMA_DIALECT));
+        document.extend(self.inner);
+
+        Value::Object(document)
+    }
+}
+
+impl From<Schema> for Value {
+    fn from(schema: Schema) -> Self {
+        Value::Object(schema.inner)
+    }
+}
+
+impl Serialize for Schema {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.inner.serialize(serializer)
+    }
+}
+
+macro_rules! impl_config_schema {
+    ($constructor:ident: $($ty:ty),+ $(,)?) => {
+        $(
+            impl ConfigSchema for $ty {
+                fn config_schema() -> Schema {
+                    Schema::$constructor()
+                }
+            }
+        )+
+    };
+}
+
+impl_config_schema!(boolean: bool);
+impl_config_schema!(integer: i8, i16, i32, i64, isize);
+impl_config_schema!(unsigned: u8, u16, u32, u64, usize);
+impl_config_schema!(positive: NonZeroU8, NonZeroU16, NonZeroU32, NonZeroU64, NonZeroUsize);
+impl_config_schema!(number: f32, f64);
+impl_config_schema!(string: String, Box<str>, PathBuf);
+impl_config_schema!(duration: Duration);
+
+impl<T: ConfigSchema> ConfigSchema for Option<T> {
+    fn config_schema() -> Schema {
+        T::config_schema()
+    }
+}
+
+impl<T: ConfigSchema> ConfigSchema for Vec<T> {
+    fn config_schema() -> Schema {
+        Schema::array(T::config_schema())
+    }
+}
+
+impl<T: ConfigSchema> ConfigSchema for BTreeSet<T> {
+    fn config_schema() -> Schema {
+        Schema::array(T::config_schema())
+    }
+}
+
+impl<T: ConfigSchema, S> ConfigSchema for HashSet<T, S> {
+    fn config_schema() -> Schema {
+        Schema::array(T::config_schema())
+    }
+}
+
+impl<T: ConfigSchema> ConfigSchema for OneOrMany<T> {
+    fn config_schema() -> Schema {
+        Schema::one_or_many(T::config_schema())
+    }
+}
+
+impl<V: ConfigSchema> ConfigSchema for BTreeMap<String, V> {
+    fn config_schema() -> Schema {
+        Schema::map(V::config_schema())
+    }
+}
+
+impl<V: ConfigSchema, S> ConfigSchema for HashMap<String, V, S> {
+    fn config_schema() -> Schema {
+        Schema::map(V::config_schema())
+    }
+}
+
+impl<V: ConfigSchema> ConfigSchema for SlugMap<V> {
+    fn config_schema() -> Schema {
+        Schema::map(V::config_schema())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn compose_object() {
+        // Given
+        let schema = Schema::object()
+            .with_description("a map of things")
+            .with_field(&["name"], String::config_schema())
+            .with_field(&["port", "p"], u16::config_schema().with_default(5672))
+            .with_field(&["kind"], Schema::choice(["direct", "fanout"]))
+            .with_field(&["keys"], OneOrMany::<String>::config_schema());
+
+        // When
+        let actual_output = Value::from(schema);
+
+        // Then
+        assert_eq!(
+            actual_output,
+            json!({
+                "type": "object",
+                "description": "a map of things",
+                "properties": {
+                    "name": { "type": "string" },
+                    "port": { "type": "integer", "minimum": 0, "default": 5672 },
+                    "p": { "type": "integer", "minimum": 0, "default": 5672 },
+                    "kind": { "type": "string", "enum": ["direct", "fanout"] },
+                    "keys": {
+                        "anyOf": [
+                            { "type": "string" },
+                            { "type": "array", "items": { "type": "string" } },
+                        ],
+                    },
+                },
+            }),
+        );
+    }
+
+    #[test]
+    fn compose_document() {
+        // Given
+        let schema = BTreeMap::<String, Duration>::config_schema().with_title("Timeouts");
+
+        // When
+        let actual_output = schema.into_document();
+
+        // Then
+        assert_eq!(
+            actual_output,
+            json!({
+                "$schema": JSON_SCHEMA_DIALECT,
+                "type": "object",
+                "title": "Timeouts",
+                "additionalProperties": {
+                    "type": "string",
+                    "pattern": DURATION_PATTERN,
+                    "description": "A human-readable duration, e.g., \"1m 30s\" or \"250ms\".",
+                },
+            }),
+        );
+    }
+}
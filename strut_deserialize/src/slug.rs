@@ -118,6 +118,42 @@ impl Slug {
             }
         }
     }
+
+    /// Computes the edit (Levenshtein) distance between two [`Slug`]s without
+    /// allocating any [`Slug`]s: the number of single-character insertions,
+    /// deletions, and substitutions that turn one slug into the other.
+    ///
+    /// Only the ASCII alphanumeric characters of both slugs are compared
+    /// (case-insensitively), so equivalent slugs are at the distance of zero.
+    /// This is intended for suggesting the closest known key for a mistyped
+    /// one.
+    pub fn distance_as_slugs(a: &str, b: &str) -> usize {
+        let a = a
+            .chars()
+            .filter(|&c| c.is_ascii_alphanumeric())
+            .map(|c| c.to_ascii_lowercase());
+        let b = b
+            .chars()
+            .filter(|&c| c.is_ascii_alphanumeric())
+            .map(|c| c.to_ascii_lowercase())
+            .collect::<Vec<_>>();
+
+        // Keep a single row of the distance matrix
+        let mut row = (0..=b.len()).collect::<Vec<_>>();
+
+        for (i, c1) in a.enumerate() {
+            let mut diagonal = row[0];
+            row[0] = i + 1;
+
+            for (j, &c2) in b.iter().enumerate() {
+                let substitution = diagonal + usize::from(c1 != c2);
+                diagonal = row[j + 1];
+                row[j + 1] = substitution.min(row[j] + 1).min(diagonal + 1);
+            }
+        }
+
+        row[b.len()]
+    }
 }
 
 impl Slug {
@@ -411,6 +447,25 @@ mod tests {
         assert_eq_and_cmp("ab", "A", Ordering::Greater);
     }
 
+    #[test]
+    fn distance() {
+        assert_eq!(Slug::distance_as_slugs("", ""), 0);
+        assert_eq!(Slug::distance_as_slugs("abc", ""), 3);
+        assert_eq!(Slug::distance_as_slugs("", "abc"), 3);
+        assert_eq!(Slug::distance_as_slugs("abc", "A_B_C"), 0);
+        assert_eq!(
+            Slug::distance_as_slugs("prefech_count", "prefetch_count"),
+            1
+        );
+        assert_eq!(
+            Slug::distance_as_slugs("max_conections", "MaxConnections"),
+            1
+        );
+        assert_eq!(Slug::distance_as_slugs("hots", "host"), 2);
+        assert_eq!(Slug::distance_as_slugs("kitten", "sitting"), 3);
+        assert_eq!(Slug::distance_as_slugs("name", "kind"), 4);
+    }
+
     fn assert_eq_and_cmp(a: &str, b: &str, ordering: Ordering) {
         assert_eq(a, b, ordering == Ordering::Equal);
         assert_cmp(a, b, ordering);
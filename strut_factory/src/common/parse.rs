@@ -2,7 +2,7 @@ use crate::common::error::HelpRenderWithTokens;
 use proc_macro2::TokenStream;
 use syn::parse::{Parse, Parser};
 use syn::punctuated::Punctuated;
-use syn::{parse2, Error as SynError, Meta, Token};
+use syn::{Error as SynError, Meta, Token, parse2};
 
 /// A shorthand for a sequence of comma-delimited attribute arguments
 pub type Args = Punctuated<Meta, Token![,]>;
@@ -62,6 +62,24 @@ pub(crate) fn config_choice(input: DeriveInput) -> SynResult<TokenStream> {
     Ok(expanded)
 }
 
+/// Generates an `impl ConfigSchema` block for the given `item`, which must be a
+/// unit-only enum. The schema lists the aliases of all variants as choices.
+pub(crate) fn config_schema(input: DeriveInput) -> SynResult<TokenStream> {
+    let Mapping { name, variants, .. } = require_supported_enum(&input)?;
+
+    let choices = variants.iter().flat_map(Variant::ordered_aliases);
+
+    let expanded = quote! {
+        impl ::strut_deserialize::ConfigSchema for #name {
+            fn config_schema() -> ::strut_deserialize::Schema {
+                ::strut_deserialize::Schema::choice([#(#choices),*])
+            }
+        }
+    };
+
+    Ok(expanded)
+}
+
 /// Composes the body of the `visit_str` method inside the implementation of
 /// the `serde::de::Visitor` trait on the annotated type.
 fn compose_visit_str_body(
@@ -256,7 +274,7 @@ fn extract_variant(variant: &SynVariant) -> Variant {
 
     // Primary alias: snake_case version of the variant's name
     let primary_alias = variant_name.to_string().to_case(Case::Snake);
-    aliases.insert(primary_alias);
+    aliases.insert(primary_alias.clone());
 
     /*
     A lot to unpack here:
@@ -284,6 +302,7 @@ fn extract_variant(variant: &SynVariant) -> Variant {
 
     Variant {
         name: variant_name,
+        primary_alias,
         aliases,
     }
 }
@@ -342,5 +361,17 @@ impl Mapping {
 /// pieces needed for the implementation.
 struct Variant {
     name: Ident,
+    primary_alias: String,
     aliases: BTreeSet<String>,
 }
+
+impl Variant {
+    /// Lists the aliases of this variant, starting with the primary alias.
+    fn ordered_aliases(&self) -> impl Iterator<Item = &String> {
+        std::iter::once(&self.primary_alias).chain(
+            self.aliases
+                .iter()
+                .filter(|alias| **alias != self.primary_alias),
+        )
+    }
+}
@@ -17,6 +17,16 @@ pub fn impl_deserialize_field(input: TokenStream) -> TokenStream {
         .map(|variant| &variant.primary)
         .collect();
     let primary_names: Vec<_> = primary_idents.iter().map(|v| v.to_string()).collect();
+    let all_keys: Vec<Vec<String>> = input
+        .variants
+        .iter()
+        .map(|variant| {
+            std::iter::once(&variant.primary)
+                .chain(variant.aliases.iter())
+                .map(|key| key.to_string())
+                .collect()
+        })
+        .collect();
     let from_str_arms = make_from_str_arms(&input.variants, key_eq_function);
 
     let expanded = quote! {
@@ -42,6 +52,16 @@ pub fn impl_deserialize_field(input: TokenStream) -> TokenStream {
                 }
             }
 
+            /// Lists the keys recognized for the field variant: the primary name followed by
+            /// the aliases. The special `__ignore` variant has no keys.
+            #[allow(dead_code)]
+            fn keys(&self) -> &'static [&'static str] {
+                match self {
+                    #(Self::#primary_idents => &[#(#all_keys),*],)*
+                    Self::__ignore => &[],
+                }
+            }
+
             /// Polls the `next_value` from the given `MapAccess` reference and puts it into the
             /// given [`Option`]. If the [`Option`] is already [`Some`], returns an appropriate
             /// Serde error (duplicate field).
@@ -1,20 +1,25 @@
-use crate::common::parse::{parse_valid_item, require_empty_args};
+use crate::common::error::HelpRenderWithTokens;
+use crate::common::parse::{Args, parse_args, parse_valid_item};
 use proc_macro2::{Span, TokenStream, TokenTree};
-use quote::{quote, quote_spanned, ToTokens};
+use quote::{ToTokens, quote, quote_spanned};
 use syn::parse::{Parse, ParseStream};
 use syn::token::Brace;
-use syn::{braced, Attribute, Error as SynError, ReturnType, Signature, Token, Visibility};
+use syn::{
+    Attribute, Error as SynError, Expr, ExprLit, LitBool, LitInt, LitStr, Meta, ReturnType,
+    Signature, Token, Visibility, braced, parse2,
+};
 
 /// Implements the [`main`](crate::main) procedural macro.
 pub fn main(attr: TokenStream, item: TokenStream) -> Result<TokenStream, TokenStream> {
-    // Parse the attribute arguments (to make sure there are none)
-    require_empty_args(attr, &item)?;
+    // Parse the attribute arguments
+    let args = parse_args(attr, &item)?;
+    let main_args = MainArgs::from_args(&args).map_err(|error| error.render_with(item.clone()))?;
 
     // Parse the item, which is expected to be an `async fn main` function
     let parsed_fn = parse_valid_item(&item, validate_parsed_fn)?;
 
     // Compose the output
-    let expanded = expand_parsed_fn(parsed_fn);
+    let expanded = expand_parsed_fn(parsed_fn, main_args);
 
     Ok(TokenStream::from(expanded))
 }
@@ -45,22 +50,17 @@ fn validate_parsed_fn(parsed_fn: &MainFunction) -> Result<(), SynError> {
         ));
     }
 
-    // Ensure the function doesn’t have a return type
-    if !matches!(parsed_fn.signature.output, ReturnType::Default) {
-        return Err(SynError::new_spanned(
-            &parsed_fn.signature.output,
-            "this attribute is only allowed on the `main` function that doesn’t declare a return type",
-        ));
-    }
-
     Ok(())
 }
 
 // Transforms the parsed main function into the final result.
-fn expand_parsed_fn(mut parsed_fn: MainFunction) -> TokenStream {
+fn expand_parsed_fn(mut parsed_fn: MainFunction, main_args: MainArgs) -> TokenStream {
     // Remove the `async` keyword
     parsed_fn.signature.asyncness = None;
 
+    // Remove the return type: the Strut app exits the process by itself
+    let output = std::mem::replace(&mut parsed_fn.signature.output, ReturnType::Default);
+
     // Find the last statement
     let (_last_statement_start, last_statement_end) = parsed_fn.last_statement();
 
@@ -69,18 +69,213 @@ fn expand_parsed_fn(mut parsed_fn: MainFunction) -> TokenStream {
     let body_content = parsed_fn.body();
 
     // The body definition is the original body transformed to an async block and assigned to a variable
-    let body_definition = quote! {
-        let #body_identifier = async #body_content;
+    let body_definition = match output {
+        // Without a return type, the async block needs no hints
+        ReturnType::Default => quote! {
+            let #body_identifier = async #body_content;
+        },
+        // With a return type, the async block’s output is pinned to it (this helps the `?` operator)
+        ReturnType::Type(_, output_type) => quote! {
+            fn __strut_main_body<F>(body: F) -> F
+            where
+                F: ::core::future::Future<Output = #output_type>,
+            {
+                body
+            }
+            let #body_identifier = __strut_main_body(async #body_content);
+        },
     };
 
-    // The last block of code is simply the invocation of the Strut app against the async body
-    let last_block = quote_spanned! { last_statement_end =>
-        strut::App::boot(#body_identifier);
+    // The last block of code is the invocation of the Strut app against the async body
+    let last_block = if main_args.is_empty() {
+        quote_spanned! { last_statement_end =>
+            strut::App::boot(#body_identifier);
+        }
+    } else {
+        let customizations = main_args.to_launchpad_calls();
+
+        quote_spanned! { last_statement_end =>
+            strut::App::launchpad(#body_identifier)
+                #customizations
+                .boot();
+        }
     };
 
     parsed_fn.into_tokens(body_definition, last_block)
 }
 
+/// Represents the arguments given to the [`main`](crate::main) attribute, each
+/// of which customizes the [`Launchpad`](../strut/struct.Launchpad.html).
+#[derive(Default)]
+struct MainArgs {
+    config_dir: Option<LitStr>,
+    env: Option<LitBool>,
+    env_prefix: Option<LitStr>,
+    env_separator: Option<LitStr>,
+    cli: Option<LitBool>,
+    runtime: Option<LitStr>,
+    worker_threads: Option<LitInt>,
+}
+
+impl MainArgs {
+    /// Interprets and validates the given attribute [`Args`].
+    fn from_args(args: &Args) -> Result<Self, SynError> {
+        let mut main_args = Self::default();
+
+        for arg in args {
+            let Meta::NameValue(name_value) = arg else {
+                return Err(SynError::new_spanned(
+                    arg,
+                    "expected an argument in the form of `key = value`",
+                ));
+            };
+
+            let key = name_value.path.get_ident().map(ToString::to_string);
+            let value = &name_value.value;
+
+            match key.as_deref() {
+                Some("config_dir") => Self::set(&mut main_args.config_dir, arg, value)?,
+                Some("env") => Self::set(&mut main_args.env, arg, value)?,
+                Some("env_prefix") => Self::set(&mut main_args.env_prefix, arg, value)?,
+                Some("env_separator") => Self::set(&mut main_args.env_separator, arg, value)?,
+                Some("cli") => Self::set(&mut main_args.cli, arg, value)?,
+                Some("runtime") => Self::set(&mut main_args.runtime, arg, value)?,
+                Some("worker_threads") => Self::set(&mut main_args.worker_threads, arg, value)?,
+                _ => {
+                    return Err(SynError::new_spanned(
+                        &name_value.path,
+                        "unknown argument, expected one of: `config_dir`, `env`, `env_prefix`, `env_separator`, `cli`, `runtime`, `worker_threads`",
+                    ));
+                }
+            }
+        }
+
+        main_args.validate()?;
+
+        Ok(main_args)
+    }
+
+    /// Stores the given `value` into the given `slot`, making sure the argument
+    /// is not repeated and the value is the literal of the expected type.
+    fn set<T>(slot: &mut Option<T>, arg: &Meta, value: &Expr) -> Result<(), SynError>
+    where
+        T: Parse,
+    {
+        if slot.is_some() {
+            return Err(SynError::new_spanned(arg, "duplicate argument"));
+        }
+
+        let parsed = match value {
+            Expr::Lit(ExprLit { lit, .. }) => parse2::<T>(lit.to_token_stream()).ok(),
+            _ => None,
+        };
+
+        match parsed {
+            Some(parsed) => {
+                *slot = Some(parsed);
+                Ok(())
+            }
+            None => Err(SynError::new_spanned(
+                value,
+                "unexpected value for this argument",
+            )),
+        }
+    }
+
+    /// Validates the values of the arguments, as well as their combination.
+    fn validate(&self) -> Result<(), SynError> {
+        // Ensure the string values are not blank
+        for lit in [&self.config_dir, &self.env_prefix, &self.env_separator]
+            .into_iter()
+            .flatten()
+        {
+            if lit.value().trim().is_empty() {
+                return Err(SynError::new_spanned(lit, "this value must not be blank"));
+            }
+        }
+
+        // Ensure the runtime flavor is recognized
+        if let Some(runtime) = &self.runtime {
+            if !matches!(runtime.value().as_str(), "current_thread" | "multi_thread") {
+                return Err(SynError::new_spanned(
+                    runtime,
+                    "unknown runtime flavor, expected `current_thread` or `multi_thread`",
+                ));
+            }
+        }
+
+        // Ensure the number of worker threads makes sense
+        if let Some(worker_threads) = &self.worker_threads {
+            if !matches!(worker_threads.base10_parse::<usize>(), Ok(1..)) {
+                return Err(SynError::new_spanned(
+                    worker_threads,
+                    "the number of worker threads must be a positive integer",
+                ));
+            }
+
+            if matches!(&self.runtime, Some(runtime) if runtime.value() == "current_thread") {
+                return Err(SynError::new_spanned(
+                    worker_threads,
+                    "the number of worker threads cannot be set for the `current_thread` runtime",
+                ));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Reports whether no arguments were given.
+    fn is_empty(&self) -> bool {
+        self.config_dir.is_none()
+            && self.env.is_none()
+            && self.env_prefix.is_none()
+            && self.env_separator.is_none()
+            && self.cli.is_none()
+            && self.runtime.is_none()
+            && self.worker_threads.is_none()
+    }
+
+    /// Composes the chain of method calls on the `Launchpad` that implement the
+    /// given arguments.
+    fn to_launchpad_calls(&self) -> TokenStream {
+        let mut calls = TokenStream::new();
+
+        if let Some(config_dir) = &self.config_dir {
+            calls.extend(quote! { .with_config_dir(#config_dir) });
+        }
+
+        if let Some(env) = &self.env {
+            calls.extend(quote! { .with_env(#env) });
+        }
+
+        if let Some(env_prefix) = &self.env_prefix {
+            calls.extend(quote! { .with_env_prefix(#env_prefix) });
+        }
+
+        if let Some(env_separator) = &self.env_separator {
+            calls.extend(quote! { .with_env_separator(#env_separator) });
+        }
+
+        if let Some(cli) = &self.cli {
+            calls.extend(quote! { .with_cli(#cli) });
+        }
+
+        if let Some(runtime) = &self.runtime {
+            let flavor = match runtime.value().as_str() {
+                "current_thread" => quote! { strut::RuntimeFlavor::CurrentThread },
+                _ => quote! { strut::RuntimeFlavor::MultiThread },
+            };
+            calls.extend(quote! { .with_runtime_flavor(#flavor) });
+        }
+
+        if let Some(worker_threads) = &self.worker_threads {
+            calls.extend(quote! { .with_worker_threads(#worker_threads) });
+        }
+
+        calls
+    }
+}
+
 /// Represent the original `async fn main`, as written by the caller.
 struct MainFunction {
     outer_attributes: Vec<Attribute>,
@@ -39,6 +39,10 @@ mod config {
 /// [`Launchpad`](../strut/struct.Launchpad.html) instead.
 ///
 /// This macro can only be used on an `async fn main() { /* ... */ }` definition.
+/// The function may declare a return type that implements
+/// [`MainOutput`](../strut/trait.MainOutput.html), e.g., a `Result`: if the
+/// function fails, the application exits with a non-zero code after the
+/// graceful shutdown.
 ///
 /// ## Example
 ///
@@ -59,6 +63,46 @@ mod config {
 /// }
 /// ```
 ///
+/// ## Arguments
+///
+/// The attribute optionally takes `key = value` arguments, which customize the
+/// startup the same way as the corresponding methods of the
+/// [`Launchpad`](../strut/struct.Launchpad.html):
+///
+/// - `config_dir = "..."`: the name of the configuration directory.
+/// - `env = false`: whether to apply the overrides from environment variables.
+/// - `env_prefix = "..."`: the prefix of the environment variables.
+/// - `env_separator = "..."`: the separator used in the environment variables.
+/// - `cli = true`: whether to interpret the command-line arguments.
+/// - `runtime = "current_thread"`: the flavor of the Tokio runtime, either
+///   `"current_thread"` or `"multi_thread"`.
+/// - `worker_threads = 4`: the number of worker threads of a multi-thread
+///   runtime.
+///
+/// ```ignore
+/// #[strut::main(config_dir = "settings", env_prefix = "BILLING", worker_threads = 4)]
+/// async fn main() {
+///     println!("Hello, world!");
+/// }
+/// ```
+///
+/// The invocation above is equivalent to:
+///
+/// ```ignore
+/// fn main() {
+///     strut::App::launchpad(async {
+///         println!("Hello, world!");
+///     })
+///     .with_config_dir("settings")
+///     .with_env_prefix("BILLING")
+///     .with_worker_threads(4)
+///     .boot();
+/// }
+/// ```
+///
+/// Unknown or repeated keys, as well as values of unexpected type, are rejected
+/// at compile time.
+///
 /// ## Negative examples
 ///
 /// The following invocations do not compile.
@@ -84,11 +128,11 @@ mod config {
 /// fn main() {}
 /// ```
 ///
-/// The function must define no return type (not even a union type):
+/// The arguments must be known:
 ///
 /// ```compile_fail
-/// #[strut::main]
-/// fn main() -> () {}
+/// #[strut::main(threads = 4)]
+/// async fn main() {}
 /// ```
 #[proc_macro_attribute]
 pub fn main(attr: TokenStream, item: TokenStream) -> TokenStream {
@@ -148,6 +192,44 @@ pub fn config_choice(attr: TokenStream) -> TokenStream {
         .into()
 }
 
+/// Derives the `ConfigSchema` trait of the `strut_deserialize` crate for a
+/// unit-only enum, describing it as a choice of strings.
+///
+/// The choices are the same aliases that are recognized by
+/// [`Deserialize`](derive@Deserialize) (which is usually derived alongside):
+/// every variant’s name converted to `snake_case`, followed by the variant’s
+/// extra aliases given with `#[strut(alias = "...")]`.
+///
+/// ## Example
+///
+/// ```rust
+/// use strut_deserialize::{ConfigSchema, Schema};
+/// use strut_factory::{ConfigSchema, Deserialize as StrutDeserialize};
+///
+/// #[derive(StrutDeserialize, ConfigSchema)]
+/// #[strut(eq_fn = strut_deserialize::Slug::eq_as_slugs)]
+/// enum SomeValue {
+///     #[strut(alias = "alpha", alias = "first")]
+///     AlphaFirst,
+///     BravoSecond,
+/// }
+///
+/// assert_eq!(
+///     SomeValue::config_schema(),
+///     Schema::choice(["alpha_first", "alpha", "first", "bravo_second"]),
+/// );
+/// ```
+#[proc_macro_derive(ConfigSchema, attributes(strut))]
+pub fn config_schema(attr: TokenStream) -> TokenStream {
+    // Parse input
+    let input = syn::parse_macro_input!(attr as syn::DeriveInput);
+
+    // Delegate
+    config::choice::config_schema(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
 /// The Strut ecosystem often forgoes deriving the
 /// [`Deserialize`](serde::Deserialize) implementation, and instead implements
 /// that trait manually in order to provide enhance flexibility and developer
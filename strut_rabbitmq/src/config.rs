@@ -4,6 +4,7 @@ use serde::{Deserialize, Deserializer};
 use serde_value::Value;
 use std::collections::BTreeMap;
 use std::fmt::Formatter;
+use strut_deserialize::{ConfigSchema, Schema};
 use strut_factory::impl_deserialize_field;
 
 /// Represents the application-level configuration section that covers everything
@@ -122,6 +123,30 @@ const _: () = {
         ingress | inbound | incoming | subscriber | subscribers,
         egress | outbound | outgoing | publisher | publishers,
     );
+
+    impl ConfigSchema for RabbitMqConfig {
+        fn config_schema() -> Schema {
+            // The default handle may also be given inline, among the other keys
+            Handle::config_schema()
+                .with_description("The RabbitMQ configuration.")
+                .with_field(
+                    RabbitMqConfigField::default_handle.keys(),
+                    Handle::config_schema(),
+                )
+                .with_field(
+                    RabbitMqConfigField::extra_handles.keys(),
+                    HandleCollection::config_schema(),
+                )
+                .with_field(
+                    RabbitMqConfigField::ingress.keys(),
+                    IngressLandscape::config_schema(),
+                )
+                .with_field(
+                    RabbitMqConfigField::egress.keys(),
+                    EgressLandscape::config_schema(),
+                )
+        }
+    }
 };
 
 #[cfg(test)]
@@ -1,17 +1,17 @@
 use crate::Handle;
-use futures::stream::FuturesUnordered;
 use futures::StreamExt;
+use futures::stream::FuturesUnordered;
 use lapin::{Channel, Connection, ConnectionProperties, Error as LapinError};
 use secure_string::SecureString;
-use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
 use std::time::Duration;
 use strut_core::{AppContext, AppSpindown, AppSpindownToken};
 use strut_sync::{Conduit, Retriever};
 use strut_util::Backoff;
 use thiserror::Error;
 use tokio::select;
-use tokio::sync::{oneshot, Mutex as AsyncMutex};
+use tokio::sync::{Mutex as AsyncMutex, oneshot};
 use tokio::task::JoinHandle;
 use tracing::{info, warn};
 
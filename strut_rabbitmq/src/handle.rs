@@ -1,4 +1,4 @@
-use percent_encoding::{utf8_percent_encode, AsciiSet, CONTROLS};
+use percent_encoding::{AsciiSet, CONTROLS, utf8_percent_encode};
 use secure_string::SecureString;
 use serde::de::{DeserializeSeed, Error, MapAccess, Visitor};
 use serde::{Deserialize, Deserializer};
@@ -7,7 +7,7 @@ use std::borrow::Cow;
 use std::collections::HashMap;
 use std::fmt::{Debug, Display, Formatter};
 use std::sync::Arc;
-use strut_deserialize::{Slug, SlugMap};
+use strut_deserialize::{ConfigSchema, Schema, Slug, SlugMap};
 use strut_factory::impl_deserialize_field;
 use strut_util::BackoffConfig;
 
@@ -438,6 +438,26 @@ const _: () = {
         password,
         vhost,
     );
+
+    impl ConfigSchema for HandleCollection {
+        fn config_schema() -> Schema {
+            Schema::map(Handle::config_schema())
+                .with_description("A map of named RabbitMQ connection handles.")
+        }
+    }
+
+    impl ConfigSchema for Handle {
+        fn config_schema() -> Schema {
+            Schema::object()
+                .with_description("A RabbitMQ connection handle.")
+                .with_field(HandleField::name.keys(), String::config_schema())
+                .with_field(HandleField::host.keys(), String::config_schema())
+                .with_field(HandleField::port.keys(), u16::config_schema())
+                .with_field(HandleField::user.keys(), String::config_schema())
+                .with_field(HandleField::password.keys(), String::config_schema())
+                .with_field(HandleField::vhost.keys(), String::config_schema())
+        }
+    }
 };
 
 #[cfg(test)]
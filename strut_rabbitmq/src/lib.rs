@@ -35,7 +35,7 @@ mod transport {
 
 // Re-export inbound types
 pub use self::transport::inbound::decoder::{Decoder, NoopDecoder, StringDecoder};
-pub use self::transport::inbound::envelope::{stack::EnvelopeStack, Envelope};
+pub use self::transport::inbound::envelope::{Envelope, stack::EnvelopeStack};
 pub use self::transport::inbound::subscriber::{
     DeclarationError, StringSubscriber, Subscriber, UndecodedSubscriber,
 };
@@ -48,11 +48,11 @@ pub use self::transport::inbound::subscriber::JsonSubscriber;
 
 // Re-export outbound types
 pub use self::transport::outbound::dispatch::{Dispatch, DispatchBuilder};
+pub use self::transport::outbound::publisher::Publisher;
 pub use self::transport::outbound::publisher::api::{
     BatchPublishingError, BatchPublishingResult, PublishingError, PublishingFailure,
     PublishingResult,
 };
-pub use self::transport::outbound::publisher::Publisher;
 
 // Re-export [`NonEmpty`] as it is part of this crate’s API.
 pub use nonempty::NonEmpty;
@@ -60,6 +60,7 @@ pub use nonempty::NonEmpty;
 /// Exposes convenience layers around `lapin` types.
 pub mod util {
     mod amqp_properties;
+    pub use self::amqp_properties::RetrievePushMap;
     pub use self::amqp_properties::push::{
         PushAppId, PushClusterId, PushContentEncoding, PushContentType, PushCorrelationId,
         PushExpiration, PushHeader, PushKind, PushMessageId, PushReplyTo, PushUserId,
@@ -69,7 +70,6 @@ pub mod util {
         RetrieveCorrelationId, RetrieveExpiration, RetrieveHeader, RetrieveKind, RetrieveMessageId,
         RetrieveReplyTo, RetrieveUserId,
     };
-    pub use self::amqp_properties::RetrievePushMap;
 
     mod amqp_value;
     pub use self::amqp_value::IsEmpty;
@@ -96,8 +96,8 @@ mod repr {
 pub use self::repr::delivery::{DeliveryMode, FinalizationKind};
 pub use self::repr::egress::ConfirmationLevel;
 pub use self::repr::ingress::exchange::{
-    ExchangeKind, EXCHANGE_AMQ_DIRECT, EXCHANGE_AMQ_FANOUT, EXCHANGE_AMQ_HEADERS,
-    EXCHANGE_AMQ_MATCH, EXCHANGE_AMQ_TOPIC, EXCHANGE_DEFAULT,
+    EXCHANGE_AMQ_DIRECT, EXCHANGE_AMQ_FANOUT, EXCHANGE_AMQ_HEADERS, EXCHANGE_AMQ_MATCH,
+    EXCHANGE_AMQ_TOPIC, EXCHANGE_DEFAULT, ExchangeKind,
 };
 pub use self::repr::ingress::header::Header;
 pub use self::repr::ingress::queue::{QueueKind, QueueRenamingBehavior};
@@ -1,11 +1,13 @@
 use crate::transport::inbound::delivery::{abandon_delivery, backwash_delivery, complete_delivery};
 use lapin::acker::Acker;
 use lapin::types::ShortShortUInt;
-use strut_factory::Deserialize as StrutDeserialize;
+use strut_factory::{ConfigSchema, Deserialize as StrutDeserialize};
 
 /// Defines whether RabbitMQ persists the messages to disk, which affects
 /// whether such messages are able to survive a broker restart.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, StrutDeserialize)]
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, StrutDeserialize, ConfigSchema,
+)]
 #[strut(eq_fn = strut_deserialize::Slug::eq_as_slugs)]
 pub enum DeliveryMode {
     /// Delivery mode `1`: non-persistent (transient): messages sent with this
@@ -21,7 +23,9 @@ pub enum DeliveryMode {
 /// Relevant only when the inbound messages are set to be
 /// [manually](crate::AckingBehavior::Manual) acknowledged on the
 /// [`Ingress`](crate::Ingress).
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, StrutDeserialize)]
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, StrutDeserialize, ConfigSchema,
+)]
 #[strut(eq_fn = strut_deserialize::Slug::eq_as_slugs)]
 pub enum FinalizationKind {
     /// Positively acknowledge the message.
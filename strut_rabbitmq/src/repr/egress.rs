@@ -1,4 +1,4 @@
-use strut_factory::Deserialize as StrutDeserialize;
+use strut_factory::{ConfigSchema, Deserialize as StrutDeserialize};
 
 /// Defines the extent to which the message [`Publisher`](crate::Publisher)
 /// should confirm successful sending.
@@ -10,7 +10,9 @@ use strut_factory::Deserialize as StrutDeserialize;
 /// performed against the RabbitMQ broker asynchronously, and the publishing
 /// implicitly switches to at-least-once publishing guarantee, which means that
 /// some of the messages may be published multiple times.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, StrutDeserialize)]
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, StrutDeserialize, ConfigSchema,
+)]
 #[strut(eq_fn = strut_deserialize::Slug::eq_as_slugs)]
 pub enum ConfirmationLevel {
     /// Ensures network transmission.
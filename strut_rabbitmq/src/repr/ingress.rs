@@ -1,4 +1,4 @@
-use strut_factory::Deserialize as StrutDeserialize;
+use strut_factory::{ConfigSchema, Deserialize as StrutDeserialize};
 
 pub mod exchange;
 pub mod header;
@@ -9,7 +9,9 @@ pub mod queue;
 /// If transactional message handling is required, it is highly recommended to
 /// use the [manual](AckingBehavior::Manual) mode, as otherwise it is quite
 /// possible to lose messages.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, StrutDeserialize)]
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, StrutDeserialize, ConfigSchema,
+)]
 #[strut(eq_fn = strut_deserialize::Slug::eq_as_slugs)]
 pub enum AckingBehavior {
     /// Messages must be explicitly acknowledged by the application logic.
@@ -45,7 +47,9 @@ pub enum AckingBehavior {
 /// Defines the matching behavior for the
 /// [`Headers`](ExchangeKind::Headers) kind of exchange. Irrelevant for
 /// all other kinds of exchanges.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, StrutDeserialize)]
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, StrutDeserialize, ConfigSchema,
+)]
 #[strut(eq_fn = strut_deserialize::Slug::eq_as_slugs)]
 pub enum HeadersMatchingBehavior {
     /// All headers must match (`x-match: all`).
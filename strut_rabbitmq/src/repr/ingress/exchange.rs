@@ -1,6 +1,6 @@
 use lapin::ExchangeKind as LapinExchangeKind;
 use std::fmt::{Display, Formatter};
-use strut_factory::Deserialize as StrutDeserialize;
+use strut_factory::{ConfigSchema, Deserialize as StrutDeserialize};
 
 /// Name of the RabbitMQ built-in default exchange
 pub const EXCHANGE_DEFAULT: &str = "";
@@ -21,7 +21,9 @@ pub const EXCHANGE_AMQ_MATCH: &str = "amq.match";
 pub const EXCHANGE_AMQ_TOPIC: &str = "amq.topic";
 
 /// Represents the supported kinds of RabbitMQ exchanges.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, StrutDeserialize)]
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, StrutDeserialize, ConfigSchema,
+)]
 #[strut(eq_fn = strut_deserialize::Slug::eq_as_slugs)]
 pub enum ExchangeKind {
     /// A **direct** exchange delivers messages to queues based on the message
@@ -3,6 +3,7 @@ use lapin::types::AMQPValue;
 use serde::de::{Error, Visitor};
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use std::fmt::Formatter;
+use strut_deserialize::{ConfigSchema, Schema};
 
 /// Represents the value assigned to a RabbitMQ header, specifically the binding
 /// header. This enumeration is used for deserializing
@@ -44,6 +45,16 @@ impl Serialize for Header {
     }
 }
 
+impl ConfigSchema for Header {
+    fn config_schema() -> Schema {
+        Schema::any_of([
+            bool::config_schema(),
+            i64::config_schema(),
+            String::config_schema(),
+        ])
+    }
+}
+
 impl<'de> Deserialize<'de> for Header {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
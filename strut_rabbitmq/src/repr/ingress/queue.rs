@@ -1,8 +1,10 @@
-use strut_factory::Deserialize as StrutDeserialize;
+use strut_factory::{ConfigSchema, Deserialize as StrutDeserialize};
 
 /// Defines a RabbitMQ queue kind, which is currently limited to either **classic**
 /// or **quorum** queues. See the RabbitMQ documentation for details.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, StrutDeserialize)]
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, StrutDeserialize, ConfigSchema,
+)]
 #[strut(eq_fn = strut_deserialize::Slug::eq_as_slugs)]
 pub enum QueueKind {
     /// Classic queues are stored on a single node in the RabbitMQ cluster and
@@ -22,7 +24,9 @@ pub enum QueueKind {
 ///
 /// When a suffix is added, it is separated from the preceding queue name with a
 /// full-stop `.` character. A suffix is never added to an empty queue name.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, StrutDeserialize)]
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, StrutDeserialize, ConfigSchema,
+)]
 #[strut(eq_fn = strut_deserialize::Slug::eq_as_slugs)]
 pub enum QueueRenamingBehavior {
     /// Leaves the queue name unchanged (doesn’t add any suffix).
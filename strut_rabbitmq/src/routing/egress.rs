@@ -4,7 +4,7 @@ use serde::{Deserialize, Deserializer};
 use std::collections::HashMap;
 use std::fmt::Formatter;
 use std::sync::Arc;
-use strut_deserialize::{Slug, SlugMap};
+use strut_deserialize::{ConfigSchema, Schema, Slug, SlugMap};
 use strut_factory::impl_deserialize_field;
 use thiserror::Error;
 
@@ -493,6 +493,30 @@ const _: () = {
         confirmation | confirmation_level,
         force_durable,
     );
+
+    impl ConfigSchema for EgressLandscape {
+        fn config_schema() -> Schema {
+            let egress = Schema::any_of([String::config_schema(), Egress::config_schema()])
+                .with_description("A RabbitMQ egress, or just the routing key.");
+
+            Schema::map(egress).with_description("A map of named RabbitMQ egresses.")
+        }
+    }
+
+    impl ConfigSchema for Egress {
+        fn config_schema() -> Schema {
+            Schema::object()
+                .with_description("A RabbitMQ egress.")
+                .with_field(EgressField::name.keys(), String::config_schema())
+                .with_field(EgressField::exchange.keys(), String::config_schema())
+                .with_field(EgressField::routing_key.keys(), String::config_schema())
+                .with_field(
+                    EgressField::confirmation.keys(),
+                    ConfirmationLevel::config_schema(),
+                )
+                .with_field(EgressField::force_durable.keys(), bool::config_schema())
+        }
+    }
 };
 
 #[cfg(test)]
@@ -1,10 +1,11 @@
 use crate::{
-    ExchangeKind, EXCHANGE_AMQ_DIRECT, EXCHANGE_AMQ_FANOUT, EXCHANGE_AMQ_HEADERS,
-    EXCHANGE_AMQ_MATCH, EXCHANGE_AMQ_TOPIC, EXCHANGE_DEFAULT,
+    EXCHANGE_AMQ_DIRECT, EXCHANGE_AMQ_FANOUT, EXCHANGE_AMQ_HEADERS, EXCHANGE_AMQ_MATCH,
+    EXCHANGE_AMQ_TOPIC, EXCHANGE_DEFAULT, ExchangeKind,
 };
 use serde::de::{Error, MapAccess, Visitor};
 use serde::{Deserialize, Deserializer};
 use std::fmt::{Display, Formatter};
+use strut_deserialize::{ConfigSchema, Schema};
 use strut_factory::impl_deserialize_field;
 use thiserror::Error;
 
@@ -549,6 +550,19 @@ const _: () = {
         durable,
         auto_delete,
     );
+
+    impl ConfigSchema for Exchange {
+        fn config_schema() -> Schema {
+            let map = Schema::object()
+                .with_field(ExchangeField::name.keys(), String::config_schema())
+                .with_field(ExchangeField::kind.keys(), ExchangeKind::config_schema())
+                .with_field(ExchangeField::durable.keys(), bool::config_schema())
+                .with_field(ExchangeField::auto_delete.keys(), bool::config_schema());
+
+            Schema::any_of([String::config_schema(), map])
+                .with_description("A RabbitMQ exchange, or just the exchange name.")
+        }
+    }
 };
 
 #[cfg(test)]
@@ -10,7 +10,7 @@ use std::fmt::Formatter;
 use std::num::{NonZeroU16, NonZeroUsize};
 use std::sync::Arc;
 use std::time::Duration;
-use strut_deserialize::{OneOrMany, Slug, SlugMap};
+use strut_deserialize::{ConfigSchema, OneOrMany, Schema, Slug, SlugMap};
 use strut_factory::impl_deserialize_field;
 use thiserror::Error;
 
@@ -1014,6 +1014,60 @@ const _: () = {
         binding_headers | binding_header,
         headers_behavior | header_behavior,
     );
+
+    impl ConfigSchema for IngressLandscape {
+        fn config_schema() -> Schema {
+            let ingress = Schema::any_of([String::config_schema(), Ingress::config_schema()])
+                .with_description("A RabbitMQ ingress, or just the queue name.");
+
+            Schema::map(ingress).with_description("A map of named RabbitMQ ingresses.")
+        }
+    }
+
+    impl ConfigSchema for Ingress {
+        fn config_schema() -> Schema {
+            Schema::object()
+                .with_description("A RabbitMQ ingress.")
+                .with_field(IngressField::name.keys(), String::config_schema())
+                .with_field(IngressField::exchange.keys(), Exchange::config_schema())
+                .with_field(IngressField::queue.keys(), Queue::config_schema())
+                .with_field(IngressField::durable.keys(), bool::config_schema())
+                .with_field(IngressField::exclusive.keys(), bool::config_schema())
+                .with_field(IngressField::auto_delete.keys(), bool::config_schema())
+                .with_field(
+                    IngressField::batch_size.keys(),
+                    NonZeroUsize::config_schema(),
+                )
+                .with_field(
+                    IngressField::batch_timeout.keys(),
+                    Duration::config_schema(),
+                )
+                .with_field(
+                    IngressField::prefetch_count.keys(),
+                    NonZeroU16::config_schema(),
+                )
+                .with_field(
+                    IngressField::acking_behavior.keys(),
+                    AckingBehavior::config_schema(),
+                )
+                .with_field(
+                    IngressField::gibberish_behavior.keys(),
+                    FinalizationKind::config_schema(),
+                )
+                .with_field(
+                    IngressField::binding_keys.keys(),
+                    OneOrMany::<String>::config_schema(),
+                )
+                .with_field(
+                    IngressField::binding_headers.keys(),
+                    HashMap::<String, Header>::config_schema(),
+                )
+                .with_field(
+                    IngressField::headers_behavior.keys(),
+                    HeadersMatchingBehavior::config_schema(),
+                )
+        }
+    }
 };
 
 #[cfg(test)]
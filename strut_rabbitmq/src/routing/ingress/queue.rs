@@ -5,6 +5,7 @@ use std::borrow::Cow;
 use std::fmt::Formatter;
 use std::sync::Arc;
 use strut_core::AppReplica;
+use strut_deserialize::{ConfigSchema, Schema};
 use strut_factory::impl_deserialize_field;
 
 /// Defines of a RabbitMQ queue to be declared from the consuming side.
@@ -177,6 +178,21 @@ const _: () = {
         kind,
         rename | renaming | renaming_behavior,
     );
+
+    impl ConfigSchema for Queue {
+        fn config_schema() -> Schema {
+            let map = Schema::object()
+                .with_field(QueueField::name.keys(), String::config_schema())
+                .with_field(QueueField::kind.keys(), QueueKind::config_schema())
+                .with_field(
+                    QueueField::rename.keys(),
+                    QueueRenamingBehavior::config_schema(),
+                );
+
+            Schema::any_of([String::config_schema(), map])
+                .with_description("A RabbitMQ queue, or just the queue name.")
+        }
+    }
 };
 
 #[cfg(test)]
@@ -16,8 +16,8 @@ use lapin::{
     Result as LapinResult,
 };
 use nonempty::NonEmpty;
-use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
 use strut_util::Backoff;
 use thiserror::Error;
 use tokio::select;
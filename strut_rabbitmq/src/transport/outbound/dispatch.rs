@@ -1,9 +1,9 @@
+use crate::DeliveryMode;
 use crate::util::{
     Attempt, Morph, Push, PushAppId, PushClusterId, PushContentEncoding, PushContentType,
     PushCorrelationId, PushExpiration, PushKind, PushMessageId, PushReplyTo, PushUserId,
     RetrievePushMap,
 };
-use crate::DeliveryMode;
 use lapin::protocol::basic::AMQPProperties;
 use lapin::types::{AMQPValue, FieldTable, ShortString};
 use std::borrow::Cow;
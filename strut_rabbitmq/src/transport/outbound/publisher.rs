@@ -5,12 +5,12 @@ use crate::transport::outbound::publisher::inner::{
 };
 use crate::util::RetrievePushMap;
 use crate::{Connector, DeliveryMode, Dispatch, Egress, Gateway, Handle};
-use lapin::options::{BasicPublishOptions, ConfirmSelectOptions};
 use lapin::Channel;
+use lapin::options::{BasicPublishOptions, ConfirmSelectOptions};
 use nonempty::NonEmpty;
 use std::collections::VecDeque;
-use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
 use tokio::sync::{Mutex as AsyncMutex, MutexGuard};
 use tracing::error;
 
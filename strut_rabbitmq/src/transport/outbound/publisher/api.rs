@@ -1,7 +1,7 @@
+use crate::Dispatch;
 use crate::transport::outbound::publisher::inner::{
     NotConfirmed, NotTransmitted, PartlyConfirmedBatch,
 };
-use crate::Dispatch;
 use nonempty::NonEmpty;
 use std::fmt::{Display, Formatter};
 use thiserror::Error;
@@ -1,9 +1,9 @@
 use crate::Dispatch;
+use lapin::Error as LapinError;
 use lapin::message::BasicReturnMessage as LapinReturn;
 use lapin::publisher_confirm::{
     Confirmation as LapinConfirm, PublisherConfirm as LapinFutureConfirm,
 };
-use lapin::Error as LapinError;
 use nonempty::NonEmpty;
 use std::fmt::{Display, Formatter};
 use thiserror::Error;
@@ -1,6 +1,6 @@
-use crate::util::field_table::Attempt;
-use crate::util::{PushHeader, HEADER_ATTEMPT};
 use crate::DeliveryMode;
+use crate::util::field_table::Attempt;
+use crate::util::{HEADER_ATTEMPT, PushHeader};
 use lapin::protocol::basic::AMQPProperties;
 use lapin::types::FieldTable;
 use std::time::{SystemTime, UNIX_EPOCH};
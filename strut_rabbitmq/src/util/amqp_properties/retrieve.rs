@@ -1,5 +1,5 @@
-use crate::util::field_table::retrieve::Retrieve;
 use crate::util::Coerce;
+use crate::util::field_table::retrieve::Retrieve;
 use lapin::protocol::basic::AMQPProperties;
 use lapin::types::{AMQPValue, ShortString};
 
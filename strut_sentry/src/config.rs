@@ -4,6 +4,7 @@ use serde::de::{Error, IgnoredAny, MapAccess, Visitor};
 use serde::{Deserialize, Deserializer};
 use std::fmt::Formatter;
 use std::time::Duration;
+use strut_deserialize::{ConfigSchema, Schema};
 use strut_factory::impl_deserialize_field;
 
 /// Represents the application-level configuration section that covers everything
@@ -233,6 +234,34 @@ const _: () = {
         attach_stacktrace,
         shutdown_timeout,
     );
+
+    impl ConfigSchema for SentryConfig {
+        fn config_schema() -> Schema {
+            let map = Schema::object()
+                .with_field(SentryConfigField::dsn.keys(), String::config_schema())
+                .with_field(SentryConfigField::debug.keys(), bool::config_schema())
+                .with_field(SentryConfigField::sample_rate.keys(), f32::config_schema())
+                .with_field(
+                    SentryConfigField::traces_sample_rate.keys(),
+                    f32::config_schema(),
+                )
+                .with_field(
+                    SentryConfigField::max_breadcrumbs.keys(),
+                    usize::config_schema(),
+                )
+                .with_field(
+                    SentryConfigField::attach_stacktrace.keys(),
+                    bool::config_schema(),
+                )
+                .with_field(
+                    SentryConfigField::shutdown_timeout.keys(),
+                    Duration::config_schema(),
+                );
+
+            Schema::any_of([String::config_schema(), map])
+                .with_description("The Sentry integration configuration, or just the Sentry DSN.")
+        }
+    }
 };
 
 #[cfg(test)]
@@ -2,7 +2,7 @@ use std::future::pending;
 use std::sync::Arc;
 use std::time::Duration;
 use tokio::select;
-use tokio::sync::{mpsc, oneshot, Mutex as AsyncMutex, Notify};
+use tokio::sync::{Mutex as AsyncMutex, Notify, mpsc, oneshot};
 
 /// A conduit for a simplified request-response communication between asynchronous
 /// tasks that allows an **owner task** to listen for requests for a resource `T` from
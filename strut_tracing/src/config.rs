@@ -3,6 +3,7 @@ use serde::de::{MapAccess, Visitor};
 use serde::{Deserialize, Deserializer};
 use std::collections::BTreeMap;
 use std::fmt::Formatter;
+use strut_deserialize::{ConfigSchema, Schema};
 use strut_factory::impl_deserialize_field;
 
 pub mod flavor;
@@ -310,6 +311,53 @@ const _: () = {
         flatten_json | flat_json | with_flat_json,
         targets | custom_targets | target_verbosity,
     );
+
+    impl ConfigSchema for TracingConfig {
+        fn config_schema() -> Schema {
+            Schema::object()
+                .with_description("The tracing (logging) configuration.")
+                .with_field(
+                    TracingConfigField::verbosity.keys(),
+                    Verbosity::config_schema(),
+                )
+                .with_field(
+                    TracingConfigField::flavor.keys(),
+                    FormatFlavor::config_schema(),
+                )
+                .with_field(TracingConfigField::color.keys(), bool::config_schema())
+                .with_field(
+                    TracingConfigField::show_timestamp.keys(),
+                    bool::config_schema(),
+                )
+                .with_field(
+                    TracingConfigField::show_target.keys(),
+                    bool::config_schema(),
+                )
+                .with_field(TracingConfigField::show_file.keys(), bool::config_schema())
+                .with_field(
+                    TracingConfigField::show_line_number.keys(),
+                    bool::config_schema(),
+                )
+                .with_field(TracingConfigField::show_level.keys(), bool::config_schema())
+                .with_field(
+                    TracingConfigField::show_thread_id.keys(),
+                    bool::config_schema(),
+                )
+                .with_field(
+                    TracingConfigField::show_thread_name.keys(),
+                    bool::config_schema(),
+                )
+                .with_field(
+                    TracingConfigField::targets.keys(),
+                    BTreeMap::<String, Verbosity>::config_schema(),
+                )
+                // Accepted (and ignored) even without the `json` feature
+                .with_field(
+                    TracingConfigField::flatten_json.keys(),
+                    bool::config_schema(),
+                )
+        }
+    }
 };
 
 #[cfg(test)]
@@ -317,6 +365,7 @@ mod tests {
     use crate::{FormatFlavor, TracingConfig, Verbosity};
     use pretty_assertions::assert_eq;
     use std::collections::BTreeMap;
+    use strut_deserialize::ConfigSchema;
 
     #[test]
     fn from_empty() {
@@ -393,4 +442,26 @@ targets:
         // Then
         assert_eq!(expected_output, actual_output);
     }
+
+    #[test]
+    fn config_schema() {
+        // When
+        let schema = serde_json::Value::from(TracingConfig::config_schema());
+
+        // Then
+        assert_eq!(
+            schema["properties"]["level"],
+            schema["properties"]["verbosity"]
+        );
+        assert_eq!(
+            schema["properties"]["verbosity"]["enum"],
+            serde_json::json!([
+                "off", "no", "error", "err", "warn", "warning", "info", "debug", "trace",
+            ]),
+        );
+        assert_eq!(
+            schema["properties"]["targets"]["additionalProperties"],
+            schema["properties"]["verbosity"],
+        );
+    }
 }
@@ -1,10 +1,12 @@
-use strut_factory::Deserialize as StrutDeserialize;
+use strut_factory::{ConfigSchema, Deserialize as StrutDeserialize};
 
 /// Represents a particular preset of configuration for the
 /// [event formatter](tracing_subscriber::fmt::format::Format) used by the
 /// [formatted `Subscriber`](tracing_subscriber::fmt::Subscriber) of the
 /// `tracing_subscriber` crate.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, StrutDeserialize)]
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, StrutDeserialize, ConfigSchema,
+)]
 #[strut(eq_fn = strut_deserialize::Slug::eq_as_slugs)]
 pub enum FormatFlavor {
     /// Uses the default [`Full`](tracing_subscriber::fmt::format::Full) event formatting.
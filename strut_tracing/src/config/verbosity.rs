@@ -1,4 +1,4 @@
-use strut_factory::Deserialize as StrutDeserialize;
+use strut_factory::{ConfigSchema, Deserialize as StrutDeserialize};
 use tracing_core::LevelFilter as TracingLevelFilter;
 
 /// A thin abstraction around the `tracing` crate’s
@@ -10,7 +10,9 @@ use tracing_core::LevelFilter as TracingLevelFilter;
 ///
 /// Conversely, a verbosity level is “lower” if it is less verbose. In this
 /// sense, [`Warn`](Verbosity::Warn) is lower than [`Info`](Verbosity::Info).
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, StrutDeserialize)]
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, StrutDeserialize, ConfigSchema,
+)]
 #[strut(eq_fn = strut_deserialize::Slug::eq_as_slugs)]
 pub enum Verbosity {
     /// Log **nothing**.
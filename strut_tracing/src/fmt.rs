@@ -1,15 +1,15 @@
 use crate::{FormatFlavor, TracingConfig, Verbosity};
 use std::collections::BTreeMap;
 use tracing_core::Subscriber;
+use tracing_subscriber::Layer;
 use tracing_subscriber::filter::Targets;
+use tracing_subscriber::fmt::Layer as FmtLayer;
 use tracing_subscriber::fmt::format::{
     Compact, DefaultFields, Format as EventFormatter, Format, Pretty,
 };
-use tracing_subscriber::fmt::Layer as FmtLayer;
-use tracing_subscriber::fmt::{layer as make_fmt_layer, FormatFields};
+use tracing_subscriber::fmt::{FormatFields, layer as make_fmt_layer};
 use tracing_subscriber::layer::Filter;
 use tracing_subscriber::registry::LookupSpan;
-use tracing_subscriber::Layer;
 
 /// Creates a [formatted `Layer`](FmtLayer) based on the given
 /// [config](TracingConfig).
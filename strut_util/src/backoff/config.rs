@@ -2,6 +2,7 @@ use serde::de::{MapAccess, Visitor};
 use serde::{Deserialize, Deserializer};
 use std::fmt::Formatter;
 use std::time::Duration;
+use strut_deserialize::{ConfigSchema, Schema};
 use strut_factory::impl_deserialize_field;
 
 /// Defines a collection of fine-tune parameters for an
@@ -160,4 +161,33 @@ const _: () = {
         multiplier,
         max_elapsed_time,
     );
+
+    impl ConfigSchema for BackoffConfig {
+        fn config_schema() -> Schema {
+            Schema::object()
+                .with_description("The exponential backoff configuration.")
+                .with_field(
+                    BackoffConfigField::initial_interval.keys(),
+                    duration_schema(),
+                )
+                .with_field(BackoffConfigField::max_interval.keys(), duration_schema())
+                .with_field(
+                    BackoffConfigField::randomization_factor.keys(),
+                    f64::config_schema(),
+                )
+                .with_field(BackoffConfigField::multiplier.keys(), f64::config_schema())
+                .with_field(
+                    BackoffConfigField::max_elapsed_time.keys(),
+                    duration_schema(),
+                )
+        }
+    }
+
+    /// The intervals are deserialized with the `serde`’s own representation of
+    /// a [`Duration`].
+    fn duration_schema() -> Schema {
+        Schema::object()
+            .with_field(&["secs"], u64::config_schema())
+            .with_field(&["nanos"], u32::config_schema())
+    }
 };
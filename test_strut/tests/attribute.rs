@@ -36,14 +36,28 @@ mod tests {
         );
     }
 
+    #[test]
+    fn unknown_arg() {
+        let output = Harness::fail("cases/attribute/unknown_arg");
+        let stderr = str::from_utf8(output.stderr.as_slice()).unwrap();
+
+        assert_contains!(stderr, "unknown argument, expected one of");
+    }
+
+    #[test]
+    fn with_args() {
+        Harness::pass_with_env(
+            "cases/attribute/with_args",
+            &[("BILLING__NAME", "billing-service")],
+        );
+    }
+
     #[test]
     fn return_type() {
         let output = Harness::fail("cases/attribute/return_type");
         let stderr = str::from_utf8(output.stderr.as_slice()).unwrap();
 
-        assert_contains!(
-            stderr,
-            "this attribute is only allowed on the `main` function that doesn’t declare a return type",
-        );
+        assert_eq!(output.status.code(), Some(1));
+        assert_contains!(stderr, "Error: Parse(ParseIntError");
     }
 }
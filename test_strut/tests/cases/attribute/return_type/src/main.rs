@@ -1,4 +1,22 @@
+use std::num::ParseIntError;
+
+#[derive(Debug)]
+enum AppError {
+    #[allow(dead_code)]
+    Parse(ParseIntError),
+}
+
+impl From<ParseIntError> for AppError {
+    fn from(error: ParseIntError) -> Self {
+        Self::Parse(error)
+    }
+}
+
 #[strut::main]
-async fn main() -> Result<(), ()> {
+async fn main() -> Result<(), AppError> {
+    let value: i32 = "forty-two".parse()?;
+
+    println!("{}", value);
+
     Ok(())
 }
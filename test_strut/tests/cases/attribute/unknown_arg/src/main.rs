@@ -0,0 +1,2 @@
+#[strut::main(config_dir = "settings", threads = 4)]
+async fn main() {}
@@ -0,0 +1,15 @@
+use strut::AppConfig;
+
+#[strut::main(
+    config_dir = "settings",
+    env_prefix = "BILLING",
+    env_separator = "__",
+    runtime = "multi_thread",
+    worker_threads = 2
+)]
+async fn main() {
+    let handle = strut::tokio::runtime::Handle::current();
+
+    println!("{}", AppConfig::get().name());
+    println!("{}", handle.metrics().num_workers());
+}
@@ -0,0 +1,9 @@
+use strut::App;
+
+fn main() {
+    App::launchpad(async_main()).with_config_report(true).boot();
+}
+
+async fn async_main() {
+    println!("Running");
+}
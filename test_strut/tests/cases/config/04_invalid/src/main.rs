@@ -0,0 +1,18 @@
+use serde::Deserialize;
+use strut::App;
+
+#[derive(Default, Deserialize)]
+#[allow(dead_code)]
+struct BillingConfig {
+    currency: String,
+}
+
+fn main() {
+    App::launchpad(async_main())
+        .with_config_section::<BillingConfig>("billing")
+        .boot();
+}
+
+async fn async_main() {
+    println!("Should not run");
+}
@@ -0,0 +1,36 @@
+use serde::Deserialize;
+use strut::{App, AppConfig};
+
+#[derive(Debug, Default, Deserialize)]
+struct BillingConfig {
+    currency: String,
+    retries: usize,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct ShippingConfig {
+    #[serde(default)]
+    carrier: String,
+}
+
+fn main() {
+    AppConfig::register::<ShippingConfig>("shipping");
+
+    App::launchpad(async_main())
+        .with_config_section::<BillingConfig>("billing")
+        .boot();
+}
+
+async fn async_main() {
+    let billing: &'static BillingConfig = AppConfig::registered("billing");
+    let shipping: &'static ShippingConfig = AppConfig::registered("shipping");
+
+    // Registered sections are deserialized once and shared
+    assert!(std::ptr::eq(
+        billing,
+        AppConfig::registered::<BillingConfig>("billing")
+    ));
+
+    println!("{} {}", billing.currency, billing.retries);
+    println!("{:?}", shipping.carrier);
+}
@@ -0,0 +1,42 @@
+use serde::Deserialize;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+use strut::{App, AppLiveConfig};
+
+#[derive(Debug, Default, Deserialize)]
+struct GreetingConfig {
+    text: String,
+}
+
+fn main() {
+    // Keep the changing config files out of the source tree
+    let config_dir = std::env::temp_dir().join(format!("strut-watch-{}", std::process::id()));
+    std::fs::create_dir_all(&config_dir).unwrap();
+    write_greeting(&config_dir, "hello");
+    unsafe { std::env::set_var("APP_CONFIG_DIR", &config_dir) };
+
+    App::launchpad(async_main(config_dir.clone()))
+        .with_config_watch_debounce(Duration::from_millis(100))
+        .boot();
+
+    std::fs::remove_dir_all(config_dir).unwrap();
+}
+
+async fn async_main(config_dir: PathBuf) {
+    let mut greeting = AppLiveConfig::subscribe::<GreetingConfig>("greeting");
+    println!("{}", greeting.borrow_and_update().text);
+
+    write_greeting(&config_dir, "goodbye");
+
+    strut::tokio::time::timeout(Duration::from_secs(10), greeting.changed())
+        .await
+        .expect("the change should be noticed")
+        .unwrap();
+    println!("{}", greeting.borrow_and_update().text);
+}
+
+fn write_greeting(config_dir: &Path, text: &str) {
+    let content = format!("[greeting]\ntext = \"{}\"\n", text);
+
+    std::fs::write(config_dir.join("app.toml"), content).unwrap();
+}
@@ -0,0 +1,31 @@
+use serde::Deserialize;
+use strut::{App, AppConfig, AppProfile};
+
+#[derive(Debug, Default, Deserialize)]
+struct BillingConfig {
+    currency: String,
+    retries: usize,
+    region: String,
+    endpoint: String,
+}
+
+fn main() {
+    App::launchpad(async_main())
+        .with_config_section::<BillingConfig>("billing")
+        .boot();
+}
+
+async fn async_main() {
+    let billing: &'static BillingConfig = AppConfig::registered("billing");
+
+    println!(
+        "{} {} {} {}",
+        billing.currency, billing.retries, billing.region, billing.endpoint,
+    );
+    println!(
+        "{} {} {}",
+        AppProfile::active(),
+        AppProfile::active_is_prod(),
+        AppProfile::active_is("staging"),
+    );
+}
@@ -0,0 +1,51 @@
+use config::{File, FileFormat};
+use serde::{Deserialize, Serialize};
+use strut::{App, AppConfig, AppLiveConfig};
+
+#[derive(Debug, Default, Deserialize)]
+struct BillingConfig {
+    currency: String,
+    retries: usize,
+    region: String,
+    endpoint: String,
+}
+
+#[derive(Serialize)]
+struct Defaults {
+    billing: BillingDefaults,
+}
+
+#[derive(Serialize)]
+struct BillingDefaults {
+    currency: &'static str,
+    region: &'static str,
+}
+
+fn main() {
+    App::launchpad(async_main())
+        .with_config_section::<BillingConfig>("billing")
+        .with_config_defaults(Defaults {
+            billing: BillingDefaults {
+                currency: "GBP",
+                region: "us",
+            },
+        })
+        .with_config_source(File::from_str(
+            "billing:\n  currency: EUR\n  endpoint: https://billing.example.com\n",
+            FileFormat::Yaml,
+        ))
+        .with_config_override("billing.retries", 7)
+        .boot();
+}
+
+async fn async_main() {
+    let initial: &'static BillingConfig = AppConfig::registered("billing");
+    let live = AppLiveConfig::registered::<BillingConfig>("billing");
+
+    for billing in [initial, &*live] {
+        println!(
+            "{} {} {} {}",
+            billing.currency, billing.retries, billing.region, billing.endpoint,
+        );
+    }
+}
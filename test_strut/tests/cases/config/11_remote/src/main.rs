@@ -0,0 +1,62 @@
+use config::FileFormat;
+use serde::Deserialize;
+use std::io::{Read, Write};
+use std::net::TcpListener;
+use std::time::Duration;
+use strut::{App, AppConfig, AppLiveConfig, HttpConfigProvider, RemoteConfig};
+
+#[derive(Debug, Default, Deserialize)]
+struct BillingConfig {
+    currency: String,
+}
+
+fn main() {
+    let port = serve_config();
+    let provider = HttpConfigProvider::new(
+        format!("http://127.0.0.1:{}/billing.toml", port),
+        FileFormat::Toml,
+    );
+    let remote_config = RemoteConfig::new(provider)
+        .with_poll_interval(Some(Duration::from_millis(100)))
+        .with_cache_path(None::<&str>);
+
+    App::launchpad(async_main())
+        .with_config_section::<BillingConfig>("billing")
+        .with_remote_config(remote_config)
+        .boot();
+}
+
+async fn async_main() {
+    let initial: &'static BillingConfig = AppConfig::registered("billing");
+    println!("{}", initial.currency);
+
+    let mut live = AppLiveConfig::subscribe::<BillingConfig>("billing");
+    while live.borrow_and_update().currency == initial.currency {
+        live.changed().await.unwrap();
+    }
+    println!("{}", live.borrow().currency);
+}
+
+/// Serves the first version of the document once, and the second one ever
+/// after.
+fn serve_config() -> u16 {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let port = listener.local_addr().unwrap().port();
+
+    std::thread::spawn(move || {
+        for (index, stream) in listener.incoming().enumerate() {
+            let mut stream = stream.unwrap();
+            let mut buffer = [0u8; 1024];
+            let _ = stream.read(&mut buffer).unwrap();
+
+            let currency = if index == 0 { "USD" } else { "EUR" };
+            let response = format!(
+                "HTTP/1.1 200 OK\r\n\r\n[billing]\ncurrency = '{}'\n",
+                currency,
+            );
+            stream.write_all(response.as_bytes()).unwrap();
+        }
+    });
+
+    port
+}
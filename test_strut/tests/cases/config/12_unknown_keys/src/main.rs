@@ -0,0 +1,20 @@
+use serde::Deserialize;
+use strut::{App, UnknownKeyPolicy};
+
+#[derive(Default, Deserialize)]
+#[allow(dead_code)]
+struct BillingConfig {
+    currency: String,
+}
+
+fn main() {
+    App::launchpad(async_main())
+        .with_config_section::<BillingConfig>("billing")
+        .with_unknown_keys(UnknownKeyPolicy::Deny)
+        .with_unknown_keys_in("runtime", UnknownKeyPolicy::Warn)
+        .boot();
+}
+
+async fn async_main() {
+    println!("Should not run");
+}
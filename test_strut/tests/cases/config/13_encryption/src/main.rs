@@ -0,0 +1,20 @@
+use serde::Deserialize;
+use strut::{App, AppConfig};
+
+#[derive(Debug, Default, Deserialize)]
+struct BillingConfig {
+    currency: String,
+    api_token: String,
+}
+
+fn main() {
+    App::launchpad(async_main())
+        .with_config_section::<BillingConfig>("billing")
+        .boot();
+}
+
+async fn async_main() {
+    let billing: &'static BillingConfig = AppConfig::registered("billing");
+
+    println!("{} {}", billing.currency, billing.api_token);
+}
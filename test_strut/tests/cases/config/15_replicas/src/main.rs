@@ -0,0 +1,25 @@
+use serde::Deserialize;
+use strut::{App, AppConfig, AppReplica};
+
+#[derive(Debug, Default, Deserialize)]
+struct BillingConfig {
+    scheduler: bool,
+    retries: usize,
+}
+
+fn main() {
+    App::launchpad(async_main())
+        .with_config_section::<BillingConfig>("billing")
+        .boot();
+}
+
+async fn async_main() {
+    let billing: &'static BillingConfig = AppConfig::registered("billing");
+
+    println!(
+        "{:?} {} {}",
+        AppReplica::index(),
+        billing.scheduler,
+        billing.retries,
+    );
+}
@@ -0,0 +1,26 @@
+use serde::Deserialize;
+use strut::{App, AppConfig};
+
+#[derive(Debug, Default, Deserialize)]
+struct BillingConfig {
+    currency: String,
+}
+
+fn main() {
+    App::launchpad(async_main())
+        .with_config_section::<BillingConfig>("billing")
+        .with_env_schema(true)
+        .boot();
+}
+
+async fn async_main() {
+    let runtime = AppConfig::get().runtime();
+    let billing: &'static BillingConfig = AppConfig::registered("billing");
+
+    println!(
+        "{:?} {:?} {}",
+        runtime.max_blocking_threads(),
+        runtime.thread_name(),
+        billing.currency,
+    );
+}
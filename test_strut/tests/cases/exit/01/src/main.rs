@@ -0,0 +1,31 @@
+use strut::{App, AppSpindown};
+
+#[derive(Debug)]
+enum AppError {
+    Config,
+    #[allow(dead_code)]
+    Network,
+}
+
+fn main() {
+    App::launchpad(async_main())
+        .with_exit_code(|error: &AppError| match error {
+            AppError::Config => 78,
+            AppError::Network => 69,
+        })
+        .boot();
+
+    unreachable!("the application should exit with a non-zero code");
+}
+
+async fn async_main() -> Result<(), AppError> {
+    // Register a worker that reports on the spindown
+    let token = AppSpindown::register("worker");
+    strut::tokio::spawn(async move {
+        strut::AppContext::terminated().await;
+        println!("Shutting down");
+        drop(token);
+    });
+
+    Err(AppError::Config)
+}
@@ -36,4 +36,160 @@ mod tests {
         assert_contains!(stdout, "password_a");
         assert_contains!(stdout, "password_b");
     }
+
+    #[test]
+    fn case_03_report() {
+        let output = Harness::pass_with_env(
+            "cases/config/03_report",
+            &[
+                ("APP_PROFILE", "prod"),
+                ("APP_SENTRY_DSN", "https://key@sentry.io/1"),
+            ],
+        );
+        let stderr = str::from_utf8(output.stderr.as_slice()).unwrap();
+
+        assert_contains!(
+            stderr,
+            "database.mysql.main.url = \"mysql://root:<redacted>@localhost:3306/main\"  # config/app.toml",
+        );
+        assert_contains!(
+            stderr,
+            "name = \"billing\"  # config/prod/app.yml, overrides config/app.toml",
+        );
+        assert_contains!(stderr, "sentry.dsn = <redacted>  # $APP_SENTRY_DSN");
+    }
+
+    #[test]
+    fn case_04_invalid() {
+        let output = Harness::fail("cases/config/04_invalid");
+        let stderr = str::from_utf8(output.stderr.as_slice()).unwrap();
+
+        assert_eq!(output.status.code(), Some(78));
+        assert_contains!(
+            stderr,
+            "The application configuration is invalid (2 errors):"
+        );
+        assert_contains!(stderr, "for key `runtime.flavor`");
+        assert_contains!(
+            stderr,
+            "missing configuration field \"currency\" for key `billing`"
+        );
+        assert_contains!(stderr, "config/app.yml");
+    }
+
+    #[test]
+    fn case_05_registered() {
+        Harness::pass("cases/config/05_registered");
+    }
+
+    #[test]
+    fn case_06_watch() {
+        Harness::pass("cases/config/06_watch");
+    }
+
+    #[test]
+    fn case_07_interpolation() {
+        Harness::pass_with_env(
+            "cases/config/07_interpolation",
+            &[("BILLING_CURRENCY", "EUR")],
+        );
+    }
+
+    #[test]
+    fn case_08_formats() {
+        Harness::pass_with_env("cases/config/08_formats", &[("APP_PROFILE", "prod")]);
+    }
+
+    #[test]
+    fn case_09_profiles() {
+        Harness::pass_with_env(
+            "cases/config/09_profiles",
+            &[("APP_PROFILE", "canary:staging,eu-west")],
+        );
+    }
+
+    #[test]
+    fn case_10_sources() {
+        Harness::pass_with_env(
+            "cases/config/10_sources",
+            &[("APP_BILLING_ENDPOINT", "https://billing.eu.example.com")],
+        );
+    }
+
+    #[test]
+    fn case_11_remote() {
+        Harness::pass("cases/config/11_remote");
+    }
+
+    #[test]
+    fn case_12_unknown_keys() {
+        let output = Harness::fail_with_env(
+            "cases/config/12_unknown_keys",
+            &[("APP_BILLING_RETRY_COUNT", "3")],
+        );
+        let stderr = str::from_utf8(output.stderr.as_slice()).unwrap();
+
+        assert_eq!(output.status.code(), Some(78));
+        assert_contains!(
+            stderr,
+            "warning: unknown field `stack_sise` (did you mean `stack_size`?) for key `runtime.stack_sise`",
+        );
+        assert_contains!(
+            stderr,
+            "The application configuration is invalid (1 error):",
+        );
+        assert_contains!(
+            stderr,
+            "unknown field `tracng` (did you mean `tracing`?) for key `tracng`",
+        );
+        assert_contains!(stderr, "config/app.yml");
+    }
+
+    #[test]
+    fn case_13_encryption() {
+        Harness::pass_with_env(
+            "cases/config/13_encryption",
+            &[
+                ("APP_PROFILE", "prod"),
+                (
+                    "APP_CONFIG_SECRET_KEY",
+                    "AAECAwQFBgcICQoLDA0ODxAREhMUFRYXGBkaGxwdHh8=",
+                ),
+            ],
+        );
+    }
+
+    #[test]
+    fn case_14_local() {
+        Harness::pass_with_env("cases/config/14_local", &[("APP_PROFILE", "prod")]);
+    }
+
+    #[test]
+    fn case_15_replicas() {
+        Harness::pass_with_env(
+            "cases/config/15_replicas",
+            &[("APP_PROFILE", "prod"), ("APP_REPLICA_INDEX", "0")],
+        );
+    }
+
+    #[test]
+    fn case_16_env_schema() {
+        let output = Harness::pass_with_env(
+            "cases/config/16_env_schema",
+            &[
+                ("APP_RUNTIME_MAX_BLOCKING_THREADS", "3"),
+                ("APP_RUNTIME_THREAD_NAME", "billing-worker"),
+                ("APP_BILLING_CURRENCY", "EUR"),
+                ("APP_RUNTME_THREAD_NAME", "typo"),
+                ("APP_PROFILE", "prod"),
+            ],
+        );
+        let stderr = str::from_utf8(output.stderr.as_slice()).unwrap();
+
+        assert_contains!(
+            stderr,
+            "warning: the environment variable `APP_RUNTME_THREAD_NAME` matches no configuration key",
+        );
+        assert!(!stderr.contains("`APP_PROFILE`"));
+    }
 }
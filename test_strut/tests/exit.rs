@@ -0,0 +1,14 @@
+#[cfg(test)]
+mod tests {
+    use assertables::assert_contains;
+    use test_util::Harness;
+
+    #[test]
+    fn case_01() {
+        let output = Harness::fail("cases/exit/01");
+        let stderr = str::from_utf8(output.stderr.as_slice()).unwrap();
+
+        assert_eq!(output.status.code(), Some(78));
+        assert_contains!(stderr, "Error: Config");
+    }
+}
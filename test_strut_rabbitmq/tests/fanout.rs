@@ -3,13 +3,13 @@ mod common;
 #[cfg(test)]
 mod tests {
     use crate::common::multiset::multiset;
-    use crate::common::names::{random_token, ExQu, HIT, MISS};
-    use crate::common::publisher::{prepare_publisher, TestPublisher};
-    use crate::common::subscriber::{prepare_subscriber, PayloadSubscriber};
+    use crate::common::names::{ExQu, HIT, MISS, random_token};
+    use crate::common::publisher::{TestPublisher, prepare_publisher};
+    use crate::common::subscriber::{PayloadSubscriber, prepare_subscriber};
     use crate::common::util::non_zero;
     use pretty_assertions::assert_eq;
     use std::any::type_name_of_val;
-    use strut_rabbitmq::{strut_shutdown, ConfirmationLevel, Exchange, ExchangeKind};
+    use strut_rabbitmq::{ConfirmationLevel, Exchange, ExchangeKind, strut_shutdown};
 
     #[tokio::test]
     #[ignore]
@@ -4,14 +4,14 @@ mod common;
 mod tests {
     use crate::common::multiset::Dropbox;
     use crate::common::names::ExQu;
-    use crate::common::publisher::{prepare_publisher, TestPublisher};
+    use crate::common::publisher::{TestPublisher, prepare_publisher};
     use crate::common::subscriber::{
-        prepare_subscriber_with, MessageIdSubscriber, RoutingKeySubscriber,
+        MessageIdSubscriber, RoutingKeySubscriber, prepare_subscriber_with,
     };
     use crate::common::util::non_zero;
     use std::any::type_name_of_val;
     use std::sync::atomic::AtomicUsize;
-    use strut_rabbitmq::{strut_shutdown, ConfirmationLevel, Dispatch, Exchange, ExchangeKind};
+    use strut_rabbitmq::{ConfirmationLevel, Dispatch, Exchange, ExchangeKind, strut_shutdown};
 
     const MESSAGE_PERIOD: usize = 50; // 50 different routing keys / message IDs
     const MESSAGE_COUNT: usize = 2500; // 50 repeated messages for each routing key / message ID
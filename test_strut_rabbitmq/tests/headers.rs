@@ -2,18 +2,18 @@ mod common;
 
 #[cfg(test)]
 mod tests {
-    use crate::common::multiset::{multiset, Dropbox, Multiset};
-    use crate::common::names::{random_token, random_u32, ExQu, HEADER_KEY_A, HEADER_KEY_B};
-    use crate::common::publisher::{prepare_publisher, TestPublisher};
+    use crate::common::multiset::{Dropbox, Multiset, multiset};
+    use crate::common::names::{ExQu, HEADER_KEY_A, HEADER_KEY_B, random_token, random_u32};
+    use crate::common::publisher::{TestPublisher, prepare_publisher};
     use crate::common::subscriber::{
-        prepare_subscriber, prepare_subscriber_with, PayloadSubscriber,
+        PayloadSubscriber, prepare_subscriber, prepare_subscriber_with,
     };
     use crate::common::util::non_zero;
     use pretty_assertions::assert_eq;
     use std::any::type_name_of_val;
     use strut_rabbitmq::{
-        strut_shutdown, ConfirmationLevel, Dispatch, Exchange, ExchangeKind,
-        HeadersMatchingBehavior,
+        ConfirmationLevel, Dispatch, Exchange, ExchangeKind, HeadersMatchingBehavior,
+        strut_shutdown,
     };
 
     #[tokio::test]
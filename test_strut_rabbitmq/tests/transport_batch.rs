@@ -4,12 +4,12 @@ mod common;
 mod tests {
     use crate::common::multiset::multiset;
     use crate::common::names::{mangle, random_token};
-    use crate::common::publisher::{prepare_publisher, TestPublisher};
-    use crate::common::subscriber::{prepare_subscriber, PayloadSubscriber};
+    use crate::common::publisher::{TestPublisher, prepare_publisher};
+    use crate::common::subscriber::{PayloadSubscriber, prepare_subscriber};
     use crate::common::util::non_zero;
     use pretty_assertions::assert_eq;
     use std::any::type_name_of_val;
-    use strut_rabbitmq::{strut_shutdown, Exchange};
+    use strut_rabbitmq::{Exchange, strut_shutdown};
 
     #[tokio::test]
     #[ignore]